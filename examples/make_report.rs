@@ -65,7 +65,7 @@ fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
             println!("- [{}]({})", payload.issue.title, payload.issue.html_url);
         }
     }
-    println!("");
+    println!();
     println!("## Pull Request Event");
     for event in pull_request_events {
         if let Payload::PullRequestEventPayload(payload) = event.payload.unwrap() {
@@ -75,7 +75,7 @@ fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
             );
         }
     }
-    println!("");
+    println!();
     println!("## Pull Request Review Comment Event");
     for event in pull_request_review_comment_events {
         if let Payload::PullRequestReviewCommentEventPayload(payload) = event.payload.unwrap() {
@@ -85,14 +85,14 @@ fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
             );
         }
     }
-    println!("");
+    println!();
     println!("## Issue Comment Event");
     for event in issue_comment_events {
         if let Payload::IssueCommentEventPayload(payload) = event.payload.unwrap() {
             println!("- [{}]({})", payload.issue.title, payload.comment.html_url);
         }
     }
-    println!("");
+    println!();
     println!("## Commit Comment Event");
     for event in commit_comment_events {
         if let Payload::CommitCommentEventPayload(payload) = event.payload.unwrap() {
@@ -102,6 +102,6 @@ fn main() -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
             );
         }
     }
-    println!("");
+    println!();
     Ok(())
 }
@@ -0,0 +1,393 @@
+//! A changelog generator from merged pull requests
+//!
+//! Pull requests are grouped into sections by their labels, and authors are credited.
+use crate::model::PullRequest;
+use crate::Client;
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt::Write;
+
+/// Rules to group merged pull requests into sections.
+///
+/// ```
+/// use ghrs::changelog::ChangelogRules;
+///
+/// let rules = ChangelogRules::new()
+///     .section("Breaking Changes", &["breaking"])
+///     .section("Features", &["feature", "enhancement"])
+///     .section("Bug Fixes", &["bug"])
+///     .exclude_labels(&["skip-changelog"]);
+/// let changelog = rules.build(&[], |_| false);
+/// assert!(changelog.sections.is_empty());
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChangelogRules {
+    sections: Vec<(String, Vec<String>)>,
+    default_section: Option<String>,
+    excluded_labels: Vec<String>,
+    base: Option<String>,
+}
+
+impl Default for ChangelogRules {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ChangelogRules {
+    /// Create rules which put every pull request into the "Other Changes" section.
+    pub fn new() -> ChangelogRules {
+        ChangelogRules {
+            sections: Vec::new(),
+            default_section: Some("Other Changes".to_string()),
+            excluded_labels: Vec::new(),
+            base: None,
+        }
+    }
+
+    /// Add a section for pull requests with one of `labels`.
+    ///
+    /// A pull request goes to the first section matching one of its labels. Labels are case-insensitive.
+    pub fn section(mut self, title: impl Into<String>, labels: &[&str]) -> Self {
+        self.sections.push((
+            title.into(),
+            labels.iter().map(|label| label.to_lowercase()).collect(),
+        ));
+        self
+    }
+
+    /// Set the title of the section for pull requests which match no other section.
+    pub fn default_section(mut self, title: impl Into<String>) -> Self {
+        self.default_section = Some(title.into());
+        self
+    }
+
+    /// Leave out pull requests which match no section.
+    pub fn omit_unmatched(mut self) -> Self {
+        self.default_section = None;
+        self
+    }
+
+    /// Leave out pull requests with one of `labels`, e.g. `skip-changelog`.
+    pub fn exclude_labels(mut self, labels: &[&str]) -> Self {
+        self.excluded_labels
+            .extend(labels.iter().map(|label| label.to_lowercase()));
+        self
+    }
+
+    /// Set the branch which pull requests are merged into for [`ChangelogRules::fetch`],
+    /// the default branch of the repository by default.
+    pub fn base(mut self, base: impl Into<String>) -> Self {
+        self.base = Some(base.into());
+        self
+    }
+
+    /// Build a changelog from `pull_requests`. Pull requests which are not merged are ignored.
+    ///
+    /// `is_first_time` is called once per author with the login,
+    /// and returns `true` if the author has no earlier contribution.
+    pub fn build<F>(&self, pull_requests: &[PullRequest], mut is_first_time: F) -> Changelog
+    where
+        F: FnMut(&str) -> bool,
+    {
+        let mut merged: Vec<(&PullRequest, DateTime<Utc>)> = pull_requests
+            .iter()
+            .filter_map(|pull_request| pull_request.merged_at.map(|at| (pull_request, at)))
+            .collect();
+        merged.sort_by_key(|(pull_request, merged_at)| (*merged_at, pull_request.number));
+
+        let mut changelog = Changelog::default();
+        for (pull_request, merged_at) in merged {
+            let labels: Vec<String> = pull_request
+                .labels
+                .iter()
+                .map(|label| label.name.clone())
+                .collect();
+            let has_label = |names: &[String]| {
+                labels
+                    .iter()
+                    .any(|label| names.contains(&label.to_lowercase()))
+            };
+            if has_label(&self.excluded_labels) {
+                continue;
+            }
+            let title = match self.sections.iter().find(|(_, names)| has_label(names)) {
+                Some((title, _)) => title,
+                None => match self.default_section.as_ref() {
+                    Some(title) => title,
+                    None => continue,
+                },
+            };
+
+            let entry = Entry {
+                number: pull_request.number,
+                title: pull_request.title.clone(),
+                url: pull_request.html_url.clone(),
+                author: pull_request.user.login.clone(),
+                merged_at,
+                labels,
+            };
+            match changelog
+                .contributors
+                .iter_mut()
+                .find(|contributor| contributor.login == entry.author)
+            {
+                Some(contributor) => contributor.pull_requests.push(entry.number),
+                None => changelog.contributors.push(Contributor {
+                    login: entry.author.clone(),
+                    url: pull_request.user.html_url.clone(),
+                    pull_requests: vec![entry.number],
+                    first_time: is_first_time(&entry.author),
+                }),
+            }
+            match changelog
+                .sections
+                .iter_mut()
+                .find(|section| &section.title == title)
+            {
+                Some(section) => section.entries.push(entry),
+                None => changelog.sections.push(Section {
+                    title: title.clone(),
+                    entries: vec![entry],
+                }),
+            }
+        }
+
+        // Sections are in the order of the rules, and the default section is the last one.
+        let position = |title: &str| {
+            self.sections
+                .iter()
+                .position(|(t, _)| t == title)
+                .unwrap_or(self.sections.len())
+        };
+        changelog
+            .sections
+            .sort_by_key(|section| position(&section.title));
+        changelog
+    }
+
+    /// Fetch pull requests merged after `since` until `until`, or now if it's `None`, and build a changelog.
+    ///
+    /// Only pull requests merged into the base branch are included, see [`ChangelogRules::base`].
+    /// An author is a first-time contributor if they authored no commit in the repository before `since`.
+    /// ```no_run
+    /// use ghrs::changelog::{Boundary, ChangelogRules};
+    ///
+    /// let client = ghrs::Client::new().token("your_token");
+    /// let changelog = ChangelogRules::new()
+    ///     .section("Features", &["feature"])
+    ///     .fetch(
+    ///         &client,
+    ///         "owner",
+    ///         "repo",
+    ///         &Boundary::tag("v1.0.0"),
+    ///         Some(&Boundary::tag("v1.1.0")),
+    ///     )
+    ///     .unwrap();
+    /// println!("{}", changelog.to_markdown());
+    /// ```
+    pub fn fetch(
+        &self,
+        client: &Client,
+        owner: &str,
+        repo: &str,
+        since: &Boundary,
+        until: Option<&Boundary>,
+    ) -> Result<Changelog, ureq::Error> {
+        let since = since.resolve(client, owner, repo)?;
+        let until = match until {
+            Some(until) => until.resolve(client, owner, repo)?,
+            None => Utc::now(),
+        };
+        let base = match self.base.clone() {
+            Some(base) => base,
+            None => client.repos(owner, repo).get().send()?.default_branch,
+        };
+
+        // Pull requests are sorted by the update time, which is never before the merge time.
+        let mut pull_requests = Vec::new();
+        let mut page = 1;
+        loop {
+            let items = client
+                .pulls(owner, repo)
+                .list()
+                .state("closed")
+                .base(base.as_str())
+                .sort("updated")
+                .direction("desc")
+                .per_page(100)
+                .page(page)
+                .send()?
+                .take_items();
+            let done = items.is_empty()
+                || items
+                    .last()
+                    .and_then(|pull_request| pull_request.updated_at)
+                    .is_some_and(|updated_at| updated_at <= since);
+            pull_requests.extend(items.into_iter().filter(|pull_request| {
+                pull_request
+                    .merged_at
+                    .is_some_and(|merged_at| since < merged_at && merged_at <= until)
+            }));
+            if done || page == u8::MAX {
+                break;
+            }
+            page += 1;
+        }
+
+        let mut first_timers = HashSet::new();
+        let authors: HashSet<&str> = pull_requests
+            .iter()
+            .map(|pull_request| pull_request.user.login.as_str())
+            .collect();
+        for author in authors {
+            let earlier_commits = client
+                .repos(owner, repo)
+                .commits()
+                .author(author)
+                .until(since.to_rfc3339())
+                .per_page(1)
+                .send()?
+                .take_items();
+            if earlier_commits.is_empty() {
+                first_timers.insert(author.to_string());
+            }
+        }
+        Ok(self.build(&pull_requests, |login| first_timers.contains(login)))
+    }
+}
+
+/// A start or an end of a changelog.
+#[derive(Clone, Debug)]
+pub enum Boundary {
+    /// The commit date of a tag.
+    Tag(String),
+    /// A date and time.
+    Date(DateTime<Utc>),
+}
+
+impl Boundary {
+    pub fn tag(tag: impl Into<String>) -> Boundary {
+        Boundary::Tag(tag.into())
+    }
+
+    fn resolve(
+        &self,
+        client: &Client,
+        owner: &str,
+        repo: &str,
+    ) -> Result<DateTime<Utc>, ureq::Error> {
+        match self {
+            Boundary::Date(date) => Ok(*date),
+            Boundary::Tag(tag) => {
+                let commit = client.repos(owner, repo).commit(tag.as_str()).send()?;
+                commit.commit.committer.date.ok_or_else(|| {
+                    std::io::Error::new(
+                        std::io::ErrorKind::InvalidData,
+                        format!("the commit of `{}` has no date", tag),
+                    )
+                    .into()
+                })
+            }
+        }
+    }
+}
+
+/// A changelog, which is serialized to JSON as is.
+#[derive(Clone, Debug, Default, Serialize)]
+pub struct Changelog {
+    pub sections: Vec<Section>,
+    /// Authors in order of their first pull request in the changelog.
+    pub contributors: Vec<Contributor>,
+}
+
+impl Changelog {
+    /// Returns contributors whose first contribution is in the changelog.
+    pub fn first_time_contributors(&self) -> impl Iterator<Item = &Contributor> {
+        self.contributors
+            .iter()
+            .filter(|contributor| contributor.first_time)
+    }
+
+    /// Render the changelog in Markdown.
+    pub fn to_markdown(&self) -> String {
+        let mut markdown = String::new();
+        for section in self.sections.iter() {
+            let _ = writeln!(markdown, "## {}", section.title);
+            for entry in section.entries.iter() {
+                let _ = writeln!(
+                    markdown,
+                    "- {} by @{} in [#{}]({})",
+                    entry.title, entry.author, entry.number, entry.url
+                );
+            }
+            markdown.push('\n');
+        }
+
+        let first_timers: Vec<&Contributor> = self.first_time_contributors().collect();
+        if !first_timers.is_empty() {
+            markdown.push_str("## New Contributors\n");
+            for contributor in first_timers {
+                let number = contributor.pull_requests[0];
+                let url = self
+                    .sections
+                    .iter()
+                    .flat_map(|section| section.entries.iter())
+                    .find(|entry| entry.number == number)
+                    .map(|entry| entry.url.as_str())
+                    .unwrap_or_default();
+                let _ = writeln!(
+                    markdown,
+                    "- @{} made their first contribution in [#{}]({})",
+                    contributor.login, number, url
+                );
+            }
+            markdown.push('\n');
+        }
+
+        if !self.contributors.is_empty() {
+            markdown.push_str("## Contributors\n");
+            let logins: Vec<String> = self
+                .contributors
+                .iter()
+                .map(|contributor| format!("@{}", contributor.login))
+                .collect();
+            let _ = writeln!(markdown, "{}", logins.join(", "));
+        }
+        markdown
+    }
+
+    /// Render the changelog in JSON.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).unwrap_or_default()
+    }
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Section {
+    pub title: String,
+    /// Entries in order of the merge time.
+    pub entries: Vec<Entry>,
+}
+
+/// A merged pull request.
+#[derive(Clone, Debug, Serialize)]
+pub struct Entry {
+    pub number: u64,
+    pub title: String,
+    pub url: String,
+    pub author: String,
+    pub merged_at: DateTime<Utc>,
+    pub labels: Vec<String>,
+}
+
+#[derive(Clone, Debug, Serialize)]
+pub struct Contributor {
+    pub login: String,
+    pub url: String,
+    /// Numbers of the pull requests in the changelog.
+    pub pull_requests: Vec<u64>,
+    pub first_time: bool,
+}
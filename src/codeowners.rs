@@ -0,0 +1,361 @@
+//! A parser for CODEOWNERS files
+//!
+//! See <https://docs.github.com/en/repositories/managing-your-repositorys-settings-and-features/customizing-your-repository/about-code-owners>.
+use crate::model::Review;
+
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+/// A parsed CODEOWNERS file.
+///
+/// ```
+/// use ghrs::codeowners::{CodeOwners, Owner};
+///
+/// let codeowners = CodeOwners::parse("*.rs @rustaceans\n/docs/ @octo-org/docs-team docs@example.com\n");
+/// assert_eq!(codeowners.owners("src/lib.rs"), &[Owner::User("rustaceans".to_string())]);
+/// assert_eq!(codeowners.owners("docs/README.md").len(), 2);
+/// assert!(codeowners.owners("Cargo.toml").is_empty());
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct CodeOwners {
+    rules: Vec<Rule>,
+    errors: Vec<ParseError>,
+}
+
+impl CodeOwners {
+    /// Parse the content of a CODEOWNERS file.
+    ///
+    /// Like GitHub, invalid lines are skipped. They are available via [`CodeOwners::errors`].
+    pub fn parse(content: &str) -> CodeOwners {
+        let mut codeowners = CodeOwners::default();
+        for (i, line) in content.lines().enumerate() {
+            match Rule::parse(i + 1, line) {
+                Ok(Some(rule)) => codeowners.rules.push(rule),
+                Ok(None) => {}
+                Err(error) => codeowners.errors.push(error),
+            }
+        }
+        codeowners
+    }
+
+    /// Returns the rules in order of appearance.
+    pub fn rules(&self) -> &[Rule] {
+        &self.rules
+    }
+
+    /// Returns the lines which could not be parsed.
+    pub fn errors(&self) -> &[ParseError] {
+        &self.errors
+    }
+
+    /// Returns the rule applied to `path`, i.e. the last matching rule.
+    pub fn rule_for(&self, path: &str) -> Option<&Rule> {
+        self.rules.iter().rev().find(|rule| rule.matches(path))
+    }
+
+    /// Returns the owners of `path`.
+    pub fn owners(&self, path: &str) -> &[Owner] {
+        self.rule_for(path)
+            .map(|rule| rule.owners.as_slice())
+            .unwrap_or_default()
+    }
+
+    /// Compute which owners have to approve a change of `paths`.
+    ///
+    /// `approvers` are logins who approved the change, see [`approvers`].
+    /// `team_members` is called with an organization and a team slug, and returns logins of the team members.
+    ///
+    /// Email owners can't be matched with logins, so they never approve a change.
+    /// A requirement owned only by email owners is never satisfied, use logins or teams in CODEOWNERS instead.
+    pub fn report<'a, F>(
+        &self,
+        paths: impl IntoIterator<Item = &'a str>,
+        approvers: &[String],
+        mut team_members: F,
+    ) -> OwnersReport
+    where
+        F: FnMut(&str, &str) -> Vec<String>,
+    {
+        let mut report = OwnersReport::default();
+        let mut members: HashMap<(String, String), Vec<String>> = HashMap::new();
+        for path in paths {
+            let owners = self.owners(path);
+            if owners.is_empty() {
+                report.unowned.push(path.to_string());
+                continue;
+            }
+            if let Some(requirement) = report
+                .requirements
+                .iter_mut()
+                .find(|requirement| requirement.owners == owners)
+            {
+                requirement.paths.push(path.to_string());
+                continue;
+            }
+
+            let mut approved_by = Vec::new();
+            for approver in approvers.iter() {
+                let approved = owners.iter().any(|owner| match owner {
+                    Owner::User(login) => login.eq_ignore_ascii_case(approver),
+                    Owner::Team { org, slug } => members
+                        .entry((org.clone(), slug.clone()))
+                        .or_insert_with(|| team_members(org, slug))
+                        .iter()
+                        .any(|member| member.eq_ignore_ascii_case(approver)),
+                    Owner::Email(_) => false,
+                });
+                if approved {
+                    approved_by.push(approver.clone());
+                }
+            }
+            report.requirements.push(Requirement {
+                owners: owners.to_vec(),
+                paths: vec![path.to_string()],
+                approved_by,
+            });
+        }
+        report
+    }
+}
+
+/// A line of a CODEOWNERS file.
+#[derive(Clone, Debug)]
+pub struct Rule {
+    pub line: usize,
+    pub pattern: String,
+    pub owners: Vec<Owner>,
+    segments: Vec<String>,
+    dir_only: bool,
+    match_children: bool,
+}
+
+impl Rule {
+    fn parse(line_number: usize, line: &str) -> Result<Option<Rule>, ParseError> {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            return Ok(None);
+        }
+
+        let mut tokens = line
+            .split_whitespace()
+            .take_while(|token| !token.starts_with('#'));
+        let pattern = tokens.next().unwrap_or_default();
+        if pattern.starts_with('!') || pattern.contains('[') || pattern.contains('\\') {
+            return Err(ParseError {
+                line: line_number,
+                message: format!("unsupported pattern `{}`", pattern),
+            });
+        }
+        let owners = tokens
+            .map(|token| {
+                token.parse::<Owner>().map_err(|_| ParseError {
+                    line: line_number,
+                    message: format!("invalid owner `{}`", token),
+                })
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let dir_only = pattern.ends_with('/');
+        let trimmed = pattern.trim_end_matches('/');
+        let anchored = trimmed.contains('/');
+        let mut segments: Vec<String> = trimmed
+            .split('/')
+            .filter(|segment| !segment.is_empty())
+            .map(|segment| segment.to_string())
+            .collect();
+        if !anchored {
+            segments.insert(0, "**".to_string());
+        }
+        // `docs/*` matches files in `docs`, but not files in its subdirectories.
+        let match_children = match segments.last() {
+            Some(last) => !anchored || last != "*",
+            None => true,
+        };
+
+        Ok(Some(Rule {
+            line: line_number,
+            pattern: pattern.to_string(),
+            owners,
+            segments,
+            dir_only,
+            match_children,
+        }))
+    }
+
+    /// Returns `true` if the rule matches `path`, a path of a file relative to the repository root.
+    pub fn matches(&self, path: &str) -> bool {
+        let path: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+        if !self.dir_only && match_segments(&self.segments, &path) {
+            return true;
+        }
+        self.match_children && (1..path.len()).any(|i| match_segments(&self.segments, &path[..i]))
+    }
+}
+
+fn match_segments(pattern: &[String], path: &[&str]) -> bool {
+    match pattern.split_first() {
+        None => path.is_empty(),
+        Some((first, rest)) if first == "**" => {
+            (0..=path.len()).any(|i| match_segments(rest, &path[i..]))
+        }
+        Some((first, rest)) => {
+            !path.is_empty() && match_segment(first, path[0]) && match_segments(rest, &path[1..])
+        }
+    }
+}
+
+fn match_segment(pattern: &str, name: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let name: Vec<char> = name.chars().collect();
+    let (mut p, mut n) = (0, 0);
+    let mut star: Option<(usize, usize)> = None;
+    while n < name.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == name[n]) {
+            p += 1;
+            n += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            star = Some((p, n));
+            p += 1;
+        } else if let Some((star_p, star_n)) = star {
+            p = star_p + 1;
+            n = star_n + 1;
+            star = Some((star_p, star_n + 1));
+        } else {
+            return false;
+        }
+    }
+    pattern[p..].iter().all(|c| *c == '*')
+}
+
+/// An owner of files.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub enum Owner {
+    /// `@login`
+    User(String),
+    /// `@org/team-slug`
+    Team { org: String, slug: String },
+    /// `user@example.com`
+    ///
+    /// Approvals are only matched by login, so [`CodeOwners::report`] never counts one for an email owner.
+    Email(String),
+}
+
+impl FromStr for Owner {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || ParseError {
+            line: 0,
+            message: format!("invalid owner `{}`", s),
+        };
+        if let Some(name) = s.strip_prefix('@') {
+            match name.split_once('/') {
+                Some((org, slug)) if !org.is_empty() && !slug.is_empty() => Ok(Owner::Team {
+                    org: org.to_string(),
+                    slug: slug.to_string(),
+                }),
+                None if !name.is_empty() => Ok(Owner::User(name.to_string())),
+                _ => Err(invalid()),
+            }
+        } else if s.contains('@') {
+            Ok(Owner::Email(s.to_string()))
+        } else {
+            Err(invalid())
+        }
+    }
+}
+
+impl fmt::Display for Owner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Owner::User(login) => write!(f, "@{}", login),
+            Owner::Team { org, slug } => write!(f, "@{}/{}", org, slug),
+            Owner::Email(email) => write!(f, "{}", email),
+        }
+    }
+}
+
+/// An error for a line which could not be parsed.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Owners who have to approve a change, and the result of the approvals.
+#[derive(Clone, Debug, Default)]
+pub struct OwnersReport {
+    /// Requirements grouped by owners.
+    pub requirements: Vec<Requirement>,
+    /// Paths without owners.
+    pub unowned: Vec<String>,
+}
+
+impl OwnersReport {
+    /// Returns `true` if every requirement is approved.
+    pub fn is_satisfied(&self) -> bool {
+        self.requirements
+            .iter()
+            .all(|requirement| requirement.is_satisfied())
+    }
+
+    /// Returns requirements which are not approved yet.
+    pub fn missing(&self) -> impl Iterator<Item = &Requirement> {
+        self.requirements
+            .iter()
+            .filter(|requirement| !requirement.is_satisfied())
+    }
+}
+
+/// Paths owned by the same owners, one of whom has to approve.
+#[derive(Clone, Debug)]
+pub struct Requirement {
+    pub owners: Vec<Owner>,
+    pub paths: Vec<String>,
+    /// Approvers who are one of the owners.
+    pub approved_by: Vec<String>,
+}
+
+impl Requirement {
+    /// Returns `true` if one of the owners approved.
+    pub fn is_satisfied(&self) -> bool {
+        !self.approved_by.is_empty()
+    }
+}
+
+/// Returns logins of users whose latest review is an approval.
+///
+/// `reviews` must be in chronological order as returned by the API.
+/// Comments don't dismiss an approval, but requested changes and dismissals do.
+pub fn approvers(reviews: &[Review]) -> Vec<String> {
+    let mut latest: Vec<(String, bool)> = Vec::new();
+    for review in reviews {
+        let login = match review.user.as_ref() {
+            Some(user) => user.login.clone(),
+            None => continue,
+        };
+        let approved = match review.state.as_str() {
+            "APPROVED" => true,
+            "CHANGES_REQUESTED" | "DISMISSED" => false,
+            _ => continue,
+        };
+        match latest.iter_mut().find(|(l, _)| *l == login) {
+            Some(entry) => entry.1 = approved,
+            None => latest.push((login, approved)),
+        }
+    }
+    latest
+        .into_iter()
+        .filter(|(_, approved)| *approved)
+        .map(|(login, _)| login)
+        .collect()
+}
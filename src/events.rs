@@ -2,6 +2,17 @@
 use crate::model::event::Event;
 use crate::{Client, Page};
 
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::mpsc::Sender;
+use std::time::Duration;
+
+/// The number of event IDs a [`WatchCursor`] remembers, which is more than a feed has.
+const MAX_SEEN_EVENTS: usize = 1000;
+
+/// The interval of polling until GitHub tells one with `X-Poll-Interval`.
+const DEFAULT_POLL_INTERVAL: u64 = 60;
+
 /// A client for the Event API.
 ///
 /// See <https://docs.github.com/en/rest/reference/activity#events>.
@@ -10,13 +21,127 @@ pub struct EventsHandler<'a> {
 }
 
 impl<'a> EventsHandler<'a> {
-    pub fn new(client: &'a Client) -> EventsHandler {
+    pub fn new(client: &'a Client) -> EventsHandler<'a> {
         EventsHandler { client }
     }
 
-    pub fn list_user_events(&self, user: impl Into<String>) -> ListUserEventsBuilder {
+    pub fn list_user_events(&self, user: impl Into<String>) -> ListUserEventsBuilder<'_> {
         ListUserEventsBuilder::new(self, user)
     }
+
+    /// Watch an events feed, and receive new events only.
+    ///
+    /// Conditional requests with ETags are used, which don't count against the rate limit
+    /// if there is no new event, and the feed is polled at the interval GitHub asks for.
+    /// ```no_run
+    /// use ghrs::events::EventFeed;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let mut watcher = client.events().watch(EventFeed::repo("owner", "repo"));
+    /// let result = watcher.run(|event| {
+    ///     println!("{:?} by {}", event.r#type, event.actor.login);
+    ///     true
+    /// });
+    /// ```
+    pub fn watch(&self, feed: EventFeed) -> EventWatcher<'a> {
+        EventWatcher::new(self.client, feed)
+    }
+
+    /// List public events.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-public-events>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_public_events().per_page(100).send();
+    /// ```
+    pub fn list_public_events(&self) -> ListPublicEventsBuilder<'_> {
+        ListPublicEventsBuilder::new(self)
+    }
+
+    /// List repository events.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-repository-events>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_repo_events("owner", "repo").per_page(100).send();
+    /// ```
+    pub fn list_repo_events(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> ListRepoEventsBuilder<'_> {
+        ListRepoEventsBuilder::new(self, owner, repo)
+    }
+
+    /// List public events for a network of repositories.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-public-events-for-a-network-of-repositories>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_network_events("owner", "repo").per_page(100).send();
+    /// ```
+    pub fn list_network_events(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> ListNetworkEventsBuilder<'_> {
+        ListNetworkEventsBuilder::new(self, owner, repo)
+    }
+
+    /// List public organization events.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-public-organization-events>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_org_events("org").per_page(100).send();
+    /// ```
+    pub fn list_org_events(&self, org: impl Into<String>) -> ListOrgEventsBuilder<'_> {
+        ListOrgEventsBuilder::new(self, org)
+    }
+
+    /// List events received by the authenticated user.
+    ///
+    /// If `user` is the authenticated user, private events are included as well.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-events-received-by-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_received_events("user").per_page(100).send();
+    /// ```
+    pub fn list_received_events(&self, user: impl Into<String>) -> ListReceivedEventsBuilder<'_> {
+        ListReceivedEventsBuilder::new(self, user)
+    }
+
+    /// List public events for a user.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-public-events-for-a-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_public_user_events("user").per_page(100).send();
+    /// ```
+    pub fn list_public_user_events(
+        &self,
+        user: impl Into<String>,
+    ) -> ListPublicUserEventsBuilder<'_> {
+        ListPublicUserEventsBuilder::new(self, user)
+    }
+
+    /// List organization events for the authenticated user.
+    ///
+    /// `user` must be the authenticated user, and private events of the organization are included.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-organization-events-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.token("your_token").events().list_user_org_events("user", "org").send();
+    /// ```
+    pub fn list_user_org_events(
+        &self,
+        user: impl Into<String>,
+        org: impl Into<String>,
+    ) -> ListUserOrgEventsBuilder<'_> {
+        ListUserOrgEventsBuilder::new(self, user, org)
+    }
 }
 
 /// A builder for listing user events
@@ -85,3 +210,747 @@ impl<'a> ListUserEventsBuilder<'a> {
         self
     }
 }
+
+/// A builder for listing public events.
+pub struct ListPublicEventsBuilder<'a> {
+    handler: &'a EventsHandler<'a>,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListPublicEventsBuilder<'a> {
+    fn new(handler: &'a EventsHandler) -> Self {
+        ListPublicEventsBuilder {
+            handler,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List public events.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-public-events>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_public_events().per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Event>, ureq::Error> {
+        let mut request = ureq::get(&format!("{}/events", self.handler.client.base_url));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let events = Page::from_response(response)?;
+        Ok(events)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing repository events.
+pub struct ListRepoEventsBuilder<'a> {
+    handler: &'a EventsHandler<'a>,
+    owner: String,
+    repo: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListRepoEventsBuilder<'a> {
+    fn new(handler: &'a EventsHandler, owner: impl Into<String>, repo: impl Into<String>) -> Self {
+        ListRepoEventsBuilder {
+            handler,
+            owner: owner.into(),
+            repo: repo.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List repository events.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-repository-events>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_repo_events("owner", "repo").per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Event>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/events",
+            self.handler.client.base_url, self.owner, self.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let events = Page::from_response(response)?;
+        Ok(events)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing public events for a network of repositories.
+pub struct ListNetworkEventsBuilder<'a> {
+    handler: &'a EventsHandler<'a>,
+    owner: String,
+    repo: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListNetworkEventsBuilder<'a> {
+    fn new(handler: &'a EventsHandler, owner: impl Into<String>, repo: impl Into<String>) -> Self {
+        ListNetworkEventsBuilder {
+            handler,
+            owner: owner.into(),
+            repo: repo.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List public events for a network of repositories.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-public-events-for-a-network-of-repositories>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_network_events("owner", "repo").per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Event>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/networks/{}/{}/events",
+            self.handler.client.base_url, self.owner, self.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let events = Page::from_response(response)?;
+        Ok(events)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing public organization events.
+pub struct ListOrgEventsBuilder<'a> {
+    handler: &'a EventsHandler<'a>,
+    org: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListOrgEventsBuilder<'a> {
+    fn new(handler: &'a EventsHandler, org: impl Into<String>) -> Self {
+        ListOrgEventsBuilder {
+            handler,
+            org: org.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List public organization events.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-public-organization-events>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_org_events("org").per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Event>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/orgs/{}/events",
+            self.handler.client.base_url, self.org
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let events = Page::from_response(response)?;
+        Ok(events)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing events received by a user.
+pub struct ListReceivedEventsBuilder<'a> {
+    handler: &'a EventsHandler<'a>,
+    user: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListReceivedEventsBuilder<'a> {
+    fn new(handler: &'a EventsHandler, user: impl Into<String>) -> Self {
+        ListReceivedEventsBuilder {
+            handler,
+            user: user.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List events received by the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-events-received-by-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_received_events("user").per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Event>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/users/{}/received_events",
+            self.handler.client.base_url, self.user
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let events = Page::from_response(response)?;
+        Ok(events)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing public events for a user.
+pub struct ListPublicUserEventsBuilder<'a> {
+    handler: &'a EventsHandler<'a>,
+    user: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListPublicUserEventsBuilder<'a> {
+    fn new(handler: &'a EventsHandler, user: impl Into<String>) -> Self {
+        ListPublicUserEventsBuilder {
+            handler,
+            user: user.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List public events for a user.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-public-events-for-a-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.events().list_public_user_events("user").per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Event>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/users/{}/events/public",
+            self.handler.client.base_url, self.user
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let events = Page::from_response(response)?;
+        Ok(events)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing organization events for the authenticated user.
+pub struct ListUserOrgEventsBuilder<'a> {
+    handler: &'a EventsHandler<'a>,
+    user: String,
+    org: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListUserOrgEventsBuilder<'a> {
+    fn new(handler: &'a EventsHandler, user: impl Into<String>, org: impl Into<String>) -> Self {
+        ListUserOrgEventsBuilder {
+            handler,
+            user: user.into(),
+            org: org.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List organization events for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/events#list-organization-events-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let events = client.token("your_token").events().list_user_org_events("user", "org").send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Event>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/users/{}/events/orgs/{}",
+            self.handler.client.base_url, self.user, self.org
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let events = Page::from_response(response)?;
+        Ok(events)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// An events feed to watch.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub enum EventFeed {
+    Public,
+    Repo { owner: String, repo: String },
+    Network { owner: String, repo: String },
+    Org(String),
+    User(String),
+    PublicUser(String),
+    Received(String),
+    UserOrg { user: String, org: String },
+}
+
+impl EventFeed {
+    pub fn repo(owner: impl Into<String>, repo: impl Into<String>) -> EventFeed {
+        EventFeed::Repo {
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    pub fn network(owner: impl Into<String>, repo: impl Into<String>) -> EventFeed {
+        EventFeed::Network {
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    pub fn org(org: impl Into<String>) -> EventFeed {
+        EventFeed::Org(org.into())
+    }
+
+    pub fn user(user: impl Into<String>) -> EventFeed {
+        EventFeed::User(user.into())
+    }
+
+    pub fn public_user(user: impl Into<String>) -> EventFeed {
+        EventFeed::PublicUser(user.into())
+    }
+
+    pub fn received(user: impl Into<String>) -> EventFeed {
+        EventFeed::Received(user.into())
+    }
+
+    pub fn user_org(user: impl Into<String>, org: impl Into<String>) -> EventFeed {
+        EventFeed::UserOrg {
+            user: user.into(),
+            org: org.into(),
+        }
+    }
+
+    /// Returns the path of the feed without the base URL.
+    ///
+    /// ```
+    /// use ghrs::events::EventFeed;
+    ///
+    /// assert_eq!(EventFeed::repo("owner", "repo").path(), "repos/owner/repo/events");
+    /// assert_eq!(EventFeed::user_org("user", "org").path(), "users/user/events/orgs/org");
+    /// ```
+    pub fn path(&self) -> String {
+        match self {
+            EventFeed::Public => "events".to_string(),
+            EventFeed::Repo { owner, repo } => format!("repos/{}/{}/events", owner, repo),
+            EventFeed::Network { owner, repo } => format!("networks/{}/{}/events", owner, repo),
+            EventFeed::Org(org) => format!("orgs/{}/events", org),
+            EventFeed::User(user) => format!("users/{}/events", user),
+            EventFeed::PublicUser(user) => format!("users/{}/events/public", user),
+            EventFeed::Received(user) => format!("users/{}/received_events", user),
+            EventFeed::UserOrg { user, org } => format!("users/{}/events/orgs/{}", user, org),
+        }
+    }
+}
+
+/// The state of watching a feed, which can be persisted to resume watching later.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Deserialize, Serialize)]
+pub struct WatchCursor {
+    etag: Option<String>,
+    poll_interval: Option<u64>,
+    /// IDs of delivered events, the oldest first.
+    seen: VecDeque<String>,
+}
+
+impl WatchCursor {
+    /// Returns the ETag of the first page of the last response.
+    pub fn etag(&self) -> Option<&str> {
+        self.etag.as_deref()
+    }
+
+    /// Returns the interval GitHub asked for.
+    pub fn poll_interval(&self) -> Duration {
+        Duration::from_secs(self.poll_interval.unwrap_or(DEFAULT_POLL_INTERVAL))
+    }
+
+    /// Returns `true` if the event has been delivered.
+    pub fn has_seen(&self, id: &str) -> bool {
+        self.seen.iter().any(|seen| seen == id)
+    }
+
+    /// Returns events which are not delivered yet in order of creation, and remember them.
+    ///
+    /// `events` are the newest first like a feed, and may contain duplicates, e.g. the same event on two pages.
+    pub fn take_new(&mut self, events: Vec<Event>) -> Vec<Event> {
+        let mut new_events: Vec<Event> = Vec::new();
+        for event in events {
+            if !self.has_seen(&event.id) && !new_events.iter().any(|new| new.id == event.id) {
+                new_events.push(event);
+            }
+        }
+        // Feeds are the newest first, and events created at the same time keep the order.
+        new_events.reverse();
+        new_events.sort_by_key(|event| event.created_at);
+        for event in new_events.iter() {
+            self.seen.push_back(event.id.clone());
+        }
+        while self.seen.len() > MAX_SEEN_EVENTS {
+            self.seen.pop_front();
+        }
+        new_events
+    }
+
+    /// Forget events which are returned but not delivered, so they are returned again.
+    fn forget<'b>(&mut self, ids: impl IntoIterator<Item = &'b str>) {
+        let ids: Vec<&str> = ids.into_iter().collect();
+        if ids.is_empty() {
+            return;
+        }
+        self.seen.retain(|seen| !ids.contains(&seen.as_str()));
+        // The feed must be fetched again even if it's not modified.
+        self.etag = None;
+    }
+}
+
+/// A watcher of an events feed.
+///
+/// The first poll without a cursor delivers all events in the feed.
+pub struct EventWatcher<'a> {
+    client: &'a Client,
+    feed: EventFeed,
+    accept: Option<String>,
+    per_page: u8,
+    max_pages: u8,
+    cursor: WatchCursor,
+}
+
+impl<'a> EventWatcher<'a> {
+    fn new(client: &'a Client, feed: EventFeed) -> Self {
+        EventWatcher {
+            client,
+            feed,
+            accept: None,
+            per_page: 100,
+            max_pages: 3,
+            cursor: WatchCursor::default(),
+        }
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Set the number of events per request, 100 by default.
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = per_page.into();
+        self
+    }
+
+    /// Set the maximum number of pages to fetch in a poll, 3 by default.
+    ///
+    /// Pages are fetched until an event which has been delivered is found.
+    pub fn max_pages(mut self, max_pages: impl Into<u8>) -> Self {
+        self.max_pages = max_pages.into();
+        self
+    }
+
+    /// Resume watching from `cursor`.
+    pub fn cursor(mut self, cursor: WatchCursor) -> Self {
+        self.cursor = cursor;
+        self
+    }
+
+    /// Returns the current cursor, which should be persisted after events are handled.
+    pub fn current_cursor(&self) -> &WatchCursor {
+        &self.cursor
+    }
+
+    /// Fetch the feed once, and returns new events in order of creation.
+    pub fn poll(&mut self) -> Result<Vec<Event>, ureq::Error> {
+        let mut events = Vec::new();
+        // The ETag is saved only after all pages are fetched, or events of page 1 would be
+        // skipped with `304 Not Modified` when a poll is retried after an error.
+        let mut etag = None;
+        for page in 1..=self.max_pages.max(1) {
+            let mut request = ureq::get(&format!("{}/{}", self.client.base_url, self.feed.path()));
+
+            if let Some(token) = self.client.token.clone() {
+                request = request.set("Authorization", &format!("token {}", token));
+            }
+            if let Some(accept) = self.accept.clone() {
+                request = request.set("Accept", &accept);
+            }
+            if page == 1 {
+                if let Some(etag) = self.cursor.etag.clone() {
+                    request = request.set("If-None-Match", &etag);
+                }
+            }
+            request = request.query("per_page", &self.per_page.to_string());
+            request = request.query("page", &page.to_string());
+
+            let response = request.call()?;
+            if page == 1 {
+                if let Some(interval) = response
+                    .header("X-Poll-Interval")
+                    .and_then(|interval| interval.parse().ok())
+                {
+                    self.cursor.poll_interval = Some(interval);
+                }
+                if response.status() == 304 {
+                    return Ok(Vec::new());
+                }
+                etag = response.header("ETag").map(|etag| etag.to_string());
+            }
+            let mut page: Page<Event> = Page::from_response(response)?;
+            let has_next = page.next.is_some();
+            let items = page.take_items();
+            let found_seen = items.iter().any(|event| self.cursor.has_seen(&event.id));
+            let is_empty = items.is_empty();
+            events.extend(items);
+            if found_seen || is_empty || !has_next {
+                break;
+            }
+        }
+        self.cursor.etag = etag;
+        Ok(self.cursor.take_new(events))
+    }
+
+    /// Poll the feed repeatedly and call `callback` for each new event.
+    ///
+    /// Return `false` from `callback` to stop watching. The cursor is updated even if an error is returned,
+    /// so watching can be resumed with [`EventWatcher::current_cursor`].
+    pub fn run<F>(&mut self, mut callback: F) -> Result<(), ureq::Error>
+    where
+        F: FnMut(&Event) -> bool,
+    {
+        loop {
+            let events = self.poll()?;
+            for (i, event) in events.iter().enumerate() {
+                if !callback(event) {
+                    self.cursor
+                        .forget(events[i + 1..].iter().map(|event| event.id.as_str()));
+                    return Ok(());
+                }
+            }
+            std::thread::sleep(self.cursor.poll_interval());
+        }
+    }
+
+    /// Poll the feed repeatedly and send new events to `sender` until the receiver is dropped.
+    pub fn run_with_sender(&mut self, sender: &Sender<Event>) -> Result<(), ureq::Error> {
+        loop {
+            let mut events = self.poll()?.into_iter();
+            while let Some(event) = events.next() {
+                if let Err(error) = sender.send(event) {
+                    let unsent: Vec<Event> = std::iter::once(error.0).chain(events).collect();
+                    self.cursor
+                        .forget(unsent.iter().map(|event| event.id.as_str()));
+                    return Ok(());
+                }
+            }
+            std::thread::sleep(self.cursor.poll_interval());
+        }
+    }
+}
@@ -0,0 +1,830 @@
+//! The Webhooks API
+//!
+//! Webhooks exist for repositories and organizations. The handlers are created with
+//! [`crate::repos::ReposHandler::hooks`] and [`crate::Client::org_hooks`].
+use crate::model::hooks::{Hook, HookConfig, HookDelivery, HookDeliveryDetail};
+use crate::{Client, Page};
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// A client for the webhooks of a repository or an organization.
+///
+/// See <https://docs.github.com/en/rest/webhooks>.
+pub struct HooksHandler<'a> {
+    client: &'a Client,
+    path: String,
+}
+
+impl<'a> HooksHandler<'a> {
+    /// `path` is a path of the repository or the organization without the base URL, e.g. `orgs/org`.
+    pub fn new(client: &'a Client, path: impl Into<String>) -> HooksHandler<'a> {
+        HooksHandler {
+            client,
+            path: path.into(),
+        }
+    }
+
+    /// List webhooks.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#list-repository-webhooks>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let hooks = client.token("your_token").repos("owner", "repo").hooks().list().send();
+    /// ```
+    pub fn list(&self) -> ListHooksBuilder<'_> {
+        ListHooksBuilder::new(self)
+    }
+
+    /// Get a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#get-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let hook = client.token("your_token").repos("owner", "repo").hooks().get(42).send();
+    /// ```
+    pub fn get(&self, hook_id: u64) -> GetHookBuilder<'_> {
+        GetHookBuilder::new(self, hook_id)
+    }
+
+    /// Create a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#create-a-repository-webhook>.
+    /// ```no_run
+    /// use ghrs::model::hooks::HookConfig;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let hook = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .create(HookConfig::json("https://example.com/webhook").secret("secret"))
+    ///     .events(vec!["push".to_string(), "pull_request".to_string()])
+    ///     .send();
+    /// ```
+    pub fn create(&self, config: HookConfig) -> CreateHookBuilder<'_> {
+        CreateHookBuilder::new(self, config)
+    }
+
+    /// Update a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#update-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let hook = client.token("your_token").repos("owner", "repo").hooks().update(42).active(false).send();
+    /// ```
+    pub fn update(&self, hook_id: u64) -> UpdateHookBuilder<'_> {
+        UpdateHookBuilder::new(self, hook_id)
+    }
+
+    /// Delete a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#delete-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").hooks().delete(42).send();
+    /// ```
+    pub fn delete(&self, hook_id: u64) -> DeleteHookBuilder<'_> {
+        DeleteHookBuilder::new(self, hook_id)
+    }
+
+    /// Ping a webhook.
+    ///
+    /// Sends a `ping` event to the webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#ping-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").hooks().ping(42).send();
+    /// ```
+    pub fn ping(&self, hook_id: u64) -> PingHookBuilder<'_> {
+        PingHookBuilder::new(self, hook_id)
+    }
+
+    /// Test the push webhook.
+    ///
+    /// Sends the latest push to the webhook if it's subscribed to `push`. Only for repository webhooks.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#test-the-push-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").hooks().test(42).send();
+    /// ```
+    pub fn test(&self, hook_id: u64) -> TestHookBuilder<'_> {
+        TestHookBuilder::new(self, hook_id)
+    }
+
+    /// List deliveries for a webhook.
+    ///
+    /// Deliveries are listed from the latest one, and the next page is specified with a cursor instead of a page number.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repo-deliveries#list-deliveries-for-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let deliveries = client.token("your_token").repos("owner", "repo").hooks().list_deliveries(42).per_page(100).send();
+    /// ```
+    pub fn list_deliveries(&self, hook_id: u64) -> ListHookDeliveriesBuilder<'_> {
+        ListHookDeliveriesBuilder::new(self, hook_id)
+    }
+
+    /// Get a delivery for a webhook.
+    ///
+    /// The delivery has the request and the response.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repo-deliveries#get-a-delivery-for-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let delivery = client.token("your_token").repos("owner", "repo").hooks().get_delivery(42, 12345678).send();
+    /// ```
+    pub fn get_delivery(&self, hook_id: u64, delivery_id: u64) -> GetHookDeliveryBuilder<'_> {
+        GetHookDeliveryBuilder::new(self, hook_id, delivery_id)
+    }
+
+    /// Redeliver a delivery for a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repo-deliveries#redeliver-a-delivery-for-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").hooks().redeliver(42, 12345678).send();
+    /// ```
+    pub fn redeliver(&self, hook_id: u64, delivery_id: u64) -> RedeliverHookDeliveryBuilder<'_> {
+        RedeliverHookDeliveryBuilder::new(self, hook_id, delivery_id)
+    }
+
+    /// Redeliver deliveries since `since` which have never succeeded, e.g. after an outage.
+    ///
+    /// Returns the redelivered deliveries from the oldest one. See [`undelivered`] for which ones are redelivered.
+    /// ```no_run
+    /// use chrono::{Duration, Utc};
+    ///
+    /// let client = ghrs::Client::new();
+    /// let redelivered = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .redeliver_failed(42, Utc::now() - Duration::hours(3));
+    /// ```
+    pub fn redeliver_failed(
+        &self,
+        hook_id: u64,
+        since: DateTime<Utc>,
+    ) -> Result<Vec<HookDelivery>, ureq::Error> {
+        let mut deliveries = Vec::new();
+        let mut page = self.list_deliveries(hook_id).per_page(100).send()?;
+        loop {
+            let items = page.take_items();
+            let reached_since = items.iter().any(|delivery| delivery.delivered_at < since);
+            deliveries.extend(items);
+            let next = match page.next.clone() {
+                Some(next) if !reached_since => next,
+                _ => break,
+            };
+            let mut request = ureq::get(&next);
+            if let Some(token) = self.client.token.clone() {
+                request = request.set("Authorization", &format!("token {}", token));
+            }
+            page = Page::from_response(request.call()?)?;
+        }
+
+        let failed: Vec<HookDelivery> = undelivered(&deliveries, since)
+            .into_iter()
+            .cloned()
+            .collect();
+        for delivery in failed.iter() {
+            self.redeliver(hook_id, delivery.id).send()?;
+        }
+        Ok(failed)
+    }
+}
+
+/// Returns deliveries since `since` which have never succeeded, one for each `guid`.
+///
+/// A delivery which succeeded with a redelivery is excluded. The latest attempt of each delivery is returned
+/// in the order the deliveries were first attempted.
+///
+/// ```
+/// use ghrs::hooks::undelivered;
+/// use ghrs::model::hooks::HookDelivery;
+///
+/// let deliveries: Vec<HookDelivery> = serde_json::from_str(r#"[
+///     {"id": 3, "guid": "a", "delivered_at": "2024-01-01T00:10:00Z", "redelivery": true, "duration": 0.1,
+///      "status": "OK", "status_code": 200, "event": "push", "action": null},
+///     {"id": 2, "guid": "b", "delivered_at": "2024-01-01T00:05:00Z", "redelivery": false, "duration": 10.0,
+///      "status": "timed out", "status_code": 0, "event": "push", "action": null},
+///     {"id": 1, "guid": "a", "delivered_at": "2024-01-01T00:00:00Z", "redelivery": false, "duration": 0.1,
+///      "status": "Invalid HTTP Response: 502", "status_code": 502, "event": "push", "action": null}
+/// ]"#).unwrap();
+/// let since = "2024-01-01T00:00:00Z".parse().unwrap();
+/// let ids: Vec<u64> = undelivered(&deliveries, since).iter().map(|delivery| delivery.id).collect();
+/// assert_eq!(ids, vec![2]);
+/// ```
+pub fn undelivered(deliveries: &[HookDelivery], since: DateTime<Utc>) -> Vec<&HookDelivery> {
+    let succeeded: HashSet<&str> = deliveries
+        .iter()
+        .filter(|delivery| delivery.is_success())
+        .map(|delivery| delivery.guid.as_str())
+        .collect();
+    // guid -> (first attempt, latest attempt)
+    let mut attempts: HashMap<&str, (&HookDelivery, &HookDelivery)> = HashMap::new();
+    for delivery in deliveries.iter() {
+        if delivery.delivered_at < since || succeeded.contains(delivery.guid.as_str()) {
+            continue;
+        }
+        let entry = attempts
+            .entry(delivery.guid.as_str())
+            .or_insert((delivery, delivery));
+        if delivery.delivered_at < entry.0.delivered_at {
+            entry.0 = delivery;
+        }
+        if delivery.delivered_at > entry.1.delivered_at {
+            entry.1 = delivery;
+        }
+    }
+    let mut attempts: Vec<(&HookDelivery, &HookDelivery)> = attempts.into_values().collect();
+    attempts.sort_by_key(|(first, _)| (first.delivered_at, first.id));
+    attempts.into_iter().map(|(_, latest)| latest).collect()
+}
+
+/// A builder for listing webhooks.
+pub struct ListHooksBuilder<'a> {
+    handler: &'a HooksHandler<'a>,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListHooksBuilder<'a> {
+    fn new(handler: &'a HooksHandler) -> Self {
+        ListHooksBuilder {
+            handler,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List webhooks.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#list-repository-webhooks>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let hooks = client.token("your_token").repos("owner", "repo").hooks().list().send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Hook>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/{}/hooks",
+            self.handler.client.base_url, self.handler.path
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let hooks = Page::from_response(response)?;
+        Ok(hooks)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting a webhook.
+pub struct GetHookBuilder<'a> {
+    handler: &'a HooksHandler<'a>,
+    hook_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> GetHookBuilder<'a> {
+    fn new(handler: &'a HooksHandler, hook_id: u64) -> Self {
+        GetHookBuilder {
+            handler,
+            hook_id,
+            accept: None,
+        }
+    }
+
+    /// Get a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#get-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let hook = client.token("your_token").repos("owner", "repo").hooks().get(42).send();
+    /// ```
+    pub fn send(&self) -> Result<Hook, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/{}/hooks/{}",
+            self.handler.client.base_url, self.handler.path, self.hook_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let hook: Hook = request.call()?.into_json()?;
+        Ok(hook)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for creating a webhook.
+#[derive(Serialize)]
+pub struct CreateHookBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a HooksHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    config: HookConfig,
+    name: &'static str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+}
+
+impl<'a> CreateHookBuilder<'a> {
+    fn new(handler: &'a HooksHandler, config: HookConfig) -> Self {
+        CreateHookBuilder {
+            handler,
+            accept: None,
+            config,
+            name: "web",
+            events: None,
+            active: None,
+        }
+    }
+
+    /// Create a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#create-a-repository-webhook>.
+    /// ```no_run
+    /// use ghrs::model::hooks::HookConfig;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let hook = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .hooks()
+    ///     .create(HookConfig::json("https://example.com/webhook").secret("secret"))
+    ///     .events(vec!["push".to_string(), "pull_request".to_string()])
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Hook, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/{}/hooks",
+            self.handler.client.base_url, self.handler.path
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let hook: Hook = request.send_json(self)?.into_json()?;
+        Ok(hook)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Events triggering the webhook, `push` by default.
+    pub fn events(mut self, events: impl Into<Vec<String>>) -> Self {
+        self.events = Some(events.into());
+        self
+    }
+
+    /// `true` by default.
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+}
+
+/// A builder for updating a webhook.
+#[derive(Serialize)]
+pub struct UpdateHookBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a HooksHandler<'a>,
+    #[serde(skip)]
+    hook_id: u64,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    config: Option<HookConfig>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    events: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    add_events: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    remove_events: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    active: Option<bool>,
+}
+
+impl<'a> UpdateHookBuilder<'a> {
+    fn new(handler: &'a HooksHandler, hook_id: u64) -> Self {
+        UpdateHookBuilder {
+            handler,
+            hook_id,
+            accept: None,
+            config: None,
+            events: None,
+            add_events: None,
+            remove_events: None,
+            active: None,
+        }
+    }
+
+    /// Update a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#update-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let hook = client.token("your_token").repos("owner", "repo").hooks().update(42).active(false).send();
+    /// ```
+    pub fn send(&self) -> Result<Hook, ureq::Error> {
+        let mut request = ureq::patch(&format!(
+            "{}/{}/hooks/{}",
+            self.handler.client.base_url, self.handler.path, self.hook_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let hook: Hook = request.send_json(self)?.into_json()?;
+        Ok(hook)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn config(mut self, config: HookConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Replace the events triggering the webhook.
+    pub fn events(mut self, events: impl Into<Vec<String>>) -> Self {
+        self.events = Some(events.into());
+        self
+    }
+
+    /// Only for repository webhooks.
+    pub fn add_events(mut self, add_events: impl Into<Vec<String>>) -> Self {
+        self.add_events = Some(add_events.into());
+        self
+    }
+
+    /// Only for repository webhooks.
+    pub fn remove_events(mut self, remove_events: impl Into<Vec<String>>) -> Self {
+        self.remove_events = Some(remove_events.into());
+        self
+    }
+
+    pub fn active(mut self, active: bool) -> Self {
+        self.active = Some(active);
+        self
+    }
+}
+
+/// A builder for deleting a webhook.
+pub struct DeleteHookBuilder<'a> {
+    handler: &'a HooksHandler<'a>,
+    hook_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> DeleteHookBuilder<'a> {
+    fn new(handler: &'a HooksHandler, hook_id: u64) -> Self {
+        DeleteHookBuilder {
+            handler,
+            hook_id,
+            accept: None,
+        }
+    }
+
+    /// Delete a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#delete-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").hooks().delete(42).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/{}/hooks/{}",
+            self.handler.client.base_url, self.handler.path, self.hook_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for pinging a webhook.
+pub struct PingHookBuilder<'a> {
+    handler: &'a HooksHandler<'a>,
+    hook_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> PingHookBuilder<'a> {
+    fn new(handler: &'a HooksHandler, hook_id: u64) -> Self {
+        PingHookBuilder {
+            handler,
+            hook_id,
+            accept: None,
+        }
+    }
+
+    /// Ping a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#ping-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").hooks().ping(42).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/{}/hooks/{}/pings",
+            self.handler.client.base_url, self.handler.path, self.hook_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for testing a webhook.
+pub struct TestHookBuilder<'a> {
+    handler: &'a HooksHandler<'a>,
+    hook_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> TestHookBuilder<'a> {
+    fn new(handler: &'a HooksHandler, hook_id: u64) -> Self {
+        TestHookBuilder {
+            handler,
+            hook_id,
+            accept: None,
+        }
+    }
+
+    /// Test the push webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repos#test-the-push-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").hooks().test(42).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/{}/hooks/{}/tests",
+            self.handler.client.base_url, self.handler.path, self.hook_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for listing deliveries for a webhook.
+pub struct ListHookDeliveriesBuilder<'a> {
+    handler: &'a HooksHandler<'a>,
+    hook_id: u64,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    cursor: Option<String>,
+}
+
+impl<'a> ListHookDeliveriesBuilder<'a> {
+    fn new(handler: &'a HooksHandler, hook_id: u64) -> Self {
+        ListHookDeliveriesBuilder {
+            handler,
+            hook_id,
+            accept: None,
+            per_page: None,
+            cursor: None,
+        }
+    }
+
+    /// List deliveries for a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repo-deliveries#list-deliveries-for-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let deliveries = client.token("your_token").repos("owner", "repo").hooks().list_deliveries(42).per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<HookDelivery>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/{}/hooks/{}/deliveries",
+            self.handler.client.base_url, self.handler.path, self.hook_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(cursor) = self.cursor.clone() {
+            request = request.query("cursor", &cursor);
+        }
+
+        let response = request.call()?;
+        let deliveries = Page::from_response(response)?;
+        Ok(deliveries)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    /// A cursor in the link of the next page.
+    pub fn cursor(mut self, cursor: impl Into<String>) -> Self {
+        self.cursor = Some(cursor.into());
+        self
+    }
+}
+
+/// A builder for getting a delivery for a webhook.
+pub struct GetHookDeliveryBuilder<'a> {
+    handler: &'a HooksHandler<'a>,
+    hook_id: u64,
+    delivery_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> GetHookDeliveryBuilder<'a> {
+    fn new(handler: &'a HooksHandler, hook_id: u64, delivery_id: u64) -> Self {
+        GetHookDeliveryBuilder {
+            handler,
+            hook_id,
+            delivery_id,
+            accept: None,
+        }
+    }
+
+    /// Get a delivery for a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repo-deliveries#get-a-delivery-for-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let delivery = client.token("your_token").repos("owner", "repo").hooks().get_delivery(42, 12345678).send();
+    /// ```
+    pub fn send(&self) -> Result<HookDeliveryDetail, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/{}/hooks/{}/deliveries/{}",
+            self.handler.client.base_url, self.handler.path, self.hook_id, self.delivery_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let delivery: HookDeliveryDetail = request.call()?.into_json()?;
+        Ok(delivery)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for redelivering a delivery for a webhook.
+pub struct RedeliverHookDeliveryBuilder<'a> {
+    handler: &'a HooksHandler<'a>,
+    hook_id: u64,
+    delivery_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> RedeliverHookDeliveryBuilder<'a> {
+    fn new(handler: &'a HooksHandler, hook_id: u64, delivery_id: u64) -> Self {
+        RedeliverHookDeliveryBuilder {
+            handler,
+            hook_id,
+            delivery_id,
+            accept: None,
+        }
+    }
+
+    /// Redeliver a delivery for a webhook.
+    ///
+    /// See <https://docs.github.com/en/rest/webhooks/repo-deliveries#redeliver-a-delivery-for-a-repository-webhook>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").hooks().redeliver(42, 12345678).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/{}/hooks/{}/deliveries/{}/attempts",
+            self.handler.client.base_url, self.handler.path, self.hook_id, self.delivery_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
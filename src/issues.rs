@@ -16,7 +16,7 @@ impl<'a> IssuesHandler<'a> {
         client: &'a Client,
         owner: impl Into<String>,
         repo: impl Into<String>,
-    ) -> IssuesHandler {
+    ) -> IssuesHandler<'a> {
         IssuesHandler {
             client,
             owner: owner.into(),
@@ -31,8 +31,8 @@ impl<'a> IssuesHandler<'a> {
     /// let client = ghrs::Client::new();
     /// let issues = client.pulls("owner", "repo").list().per_page(100).send();
     /// ```
-    pub fn list(&self) -> ListIssuesBuilder {
-        ListIssuesBuilder::new(&self)
+    pub fn list(&self) -> ListIssuesBuilder<'_> {
+        ListIssuesBuilder::new(self)
     }
 
     /// Get an issue.
@@ -42,8 +42,8 @@ impl<'a> IssuesHandler<'a> {
     /// let client = ghrs::Client::new();
     /// let issue = client.issues("owner", "repo").get(1234).send();
     /// ```
-    pub fn get(&self, issue_number: u64) -> GetIssueBuilder {
-        GetIssueBuilder::new(&self, issue_number)
+    pub fn get(&self, issue_number: u64) -> GetIssueBuilder<'_> {
+        GetIssueBuilder::new(self, issue_number)
     }
 }
 
@@ -2,16 +2,35 @@
 //! it avoids complexity unlike Async I/O, so it's so easy to use.
 //! ghrs is inspired by [Octocrab](https://github.com/XAMPPRocky/octocrab).
 
+// `ureq::Error` is part of the public API, so its size is out of our hands.
+#![allow(clippy::result_large_err)]
+
+pub mod changelog;
+pub mod codeowners;
 pub mod events;
+pub mod hooks;
 pub mod issues;
 pub mod model;
+pub mod notifications;
 pub mod pulls;
+pub mod repos;
+pub mod rulesets;
+pub mod search;
+pub mod secrets;
+pub mod webhook;
 
 use crate::events::EventsHandler;
+use crate::hooks::HooksHandler;
 use crate::issues::IssuesHandler;
+use crate::notifications::NotificationsHandler;
 use crate::pulls::PullsHandler;
+use crate::repos::{OrgReposHandler, ReposHandler, UserReposHandler};
+use crate::rulesets::RulesetsHandler;
+use crate::search::SearchHandler;
+use crate::secrets::{SecretsHandler, VariablesHandler};
 
 const GITHUB_API_URL: &str = "https://api.github.com";
+const GITHUB_UPLOADS_URL: &str = "https://uploads.github.com";
 
 /// A client for GitHub v3 API.
 pub struct Client {
@@ -56,18 +75,84 @@ impl Client {
         self
     }
 
+    /// Returns the base URL for uploads, e.g. release assets.
+    ///
+    /// ```
+    /// let client = ghrs::Client::new();
+    /// assert_eq!(client.uploads_url(), "https://uploads.github.com");
+    ///
+    /// let client = ghrs::Client::new().base_url("https://github.your_company.com/api/v3");
+    /// assert_eq!(client.uploads_url(), "https://github.your_company.com/api/uploads");
+    /// ```
+    pub fn uploads_url(&self) -> String {
+        let base_url = self.base_url.trim_end_matches('/');
+        if base_url == GITHUB_API_URL {
+            return GITHUB_UPLOADS_URL.to_string();
+        }
+        match base_url.strip_suffix("/api/v3") {
+            Some(host) => format!("{}/api/uploads", host),
+            // e.g. a mock server, which serves uploads as well.
+            None => base_url.to_string(),
+        }
+    }
+
     /// Create a [`issues::IssuesHandler`].
-    pub fn issues(&self, owner: impl Into<String>, repo: impl Into<String>) -> IssuesHandler {
+    pub fn issues(&self, owner: impl Into<String>, repo: impl Into<String>) -> IssuesHandler<'_> {
         IssuesHandler::new(self, owner, repo)
     }
 
     /// Create a [`pulls::PullsHandler`].
-    pub fn pulls(&self, owner: impl Into<String>, repo: impl Into<String>) -> PullsHandler {
+    pub fn pulls(&self, owner: impl Into<String>, repo: impl Into<String>) -> PullsHandler<'_> {
         PullsHandler::new(self, owner, repo)
     }
 
+    /// Create a [`repos::ReposHandler`].
+    pub fn repos(&self, owner: impl Into<String>, repo: impl Into<String>) -> ReposHandler<'_> {
+        ReposHandler::new(self, owner, repo)
+    }
+
+    /// Create a [`repos::UserReposHandler`] for the authenticated user.
+    pub fn user_repos(&self) -> UserReposHandler<'_> {
+        UserReposHandler::new(self)
+    }
+
+    /// Create a [`repos::OrgReposHandler`].
+    pub fn org_repos(&self, org: impl Into<String>) -> OrgReposHandler<'_> {
+        OrgReposHandler::new(self, org)
+    }
+
+    /// Create a [`hooks::HooksHandler`] for organization webhooks.
+    pub fn org_hooks(&self, org: impl Into<String>) -> HooksHandler<'_> {
+        HooksHandler::new(self, format!("orgs/{}", org.into()))
+    }
+
+    /// Create a [`rulesets::RulesetsHandler`] for organization rulesets.
+    pub fn org_rulesets(&self, org: impl Into<String>) -> RulesetsHandler<'_> {
+        RulesetsHandler::new(self, format!("orgs/{}", org.into()))
+    }
+
+    /// Create a [`secrets::SecretsHandler`] for organization secrets.
+    pub fn org_secrets(&self, org: impl Into<String>) -> SecretsHandler<'_> {
+        SecretsHandler::new(self, format!("orgs/{}/actions", org.into()))
+    }
+
+    /// Create a [`secrets::VariablesHandler`] for organization variables.
+    pub fn org_variables(&self, org: impl Into<String>) -> VariablesHandler<'_> {
+        VariablesHandler::new(self, format!("orgs/{}/actions", org.into()))
+    }
+
+    /// Create a [`notifications::NotificationsHandler`].
+    pub fn notifications(&self) -> NotificationsHandler<'_> {
+        NotificationsHandler::new(self)
+    }
+
+    /// Create a [`search::SearchHandler`].
+    pub fn search(&self) -> SearchHandler<'_> {
+        SearchHandler::new(self)
+    }
+
     /// Create a [`events::EventsHandler`].
-    pub fn events(&self) -> EventsHandler {
+    pub fn events(&self) -> EventsHandler<'_> {
         EventsHandler::new(self)
     }
 }
@@ -83,35 +168,33 @@ pub struct Page<T> {
 impl<T: serde::de::DeserializeOwned> Page<T> {
     /// Create a [`Page`] from response.
     pub fn from_response(response: ureq::Response) -> Result<Page<T>, ureq::Error> {
-        let link_header = {
-            if let Some(link_header) = response.header("link") {
-                link_header.parse::<hyperx::header::Link>().unwrap()
-            } else {
-                let items: Vec<T> = response.into_json()?;
-                return Ok(Page {
-                    items,
-                    prev: None,
-                    next: None,
-                });
-            }
-        };
-
-        let mut next = None;
-        let mut prev = None;
-        for v in link_header.values() {
-            let rel = v.rel().unwrap();
-            if rel.contains(&hyperx::header::RelationType::Next) {
-                next = Some(v.link().to_string());
-            }
-            if rel.contains(&hyperx::header::RelationType::Prev) {
-                prev = Some(v.link().to_string());
-            }
-        }
+        let (prev, next) = links(&response);
         let items: Vec<T> = response.into_json()?;
         Ok(Page { items, prev, next })
     }
 }
 
+/// Returns the prev and next links in the `Link` header.
+fn links(response: &ureq::Response) -> (Option<String>, Option<String>) {
+    let link_header = match response.header("link") {
+        Some(link_header) => link_header.parse::<hyperx::header::Link>().unwrap(),
+        None => return (None, None),
+    };
+
+    let mut next = None;
+    let mut prev = None;
+    for v in link_header.values() {
+        let rel = v.rel().unwrap();
+        if rel.contains(&hyperx::header::RelationType::Next) {
+            next = Some(v.link().to_string());
+        }
+        if rel.contains(&hyperx::header::RelationType::Prev) {
+            prev = Some(v.link().to_string());
+        }
+    }
+    (prev, next)
+}
+
 impl<T> IntoIterator for Page<T> {
     type Item = T;
     type IntoIter = std::vec::IntoIter<Self::Item>;
@@ -135,7 +218,7 @@ impl<T: serde::de::DeserializeOwned> Page<T> {
 
     /// Returns current items, and set an empty `Vec` instead.
     pub fn take_items(&mut self) -> Vec<T> {
-        std::mem::replace(&mut self.items, Vec::new())
+        std::mem::take(&mut self.items)
     }
 
     pub fn get_prev(&self) -> Option<String> {
@@ -1,7 +1,22 @@
+pub mod actions;
+pub mod branches;
+pub mod checks;
+pub mod collaborators;
+pub mod commits;
+pub mod contents;
 pub mod event;
+pub mod git;
+pub mod hooks;
+pub mod notifications;
+pub mod releases;
+pub mod rulesets;
+pub mod search;
+pub mod secrets;
+pub mod statuses;
 
+use base64::Engine;
 use chrono::{DateTime, Utc};
-use serde::{Deserialize, Serialize};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PullRequest {
@@ -19,6 +34,8 @@ pub struct PullRequest {
     pub number: u64,
     pub state: String,
     pub title: String,
+    /// An empty string if the pull request has no description.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
     pub body: String,
     pub labels: Vec<Label>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -119,6 +136,8 @@ pub struct Issue {
     pub number: i64,
     pub state: String,
     pub title: String,
+    /// An empty string if the issue has no description.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
     pub body: String,
     pub user: User,
     pub labels: Vec<Label>,
@@ -175,6 +194,8 @@ pub struct Team {
     pub html_url: String,
     pub name: String,
     pub slug: String,
+    /// An empty string if the team has no description.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
     pub description: String,
     pub privacy: String,
     pub permission: String,
@@ -182,6 +203,47 @@ pub struct Team {
     pub repositories_url: String,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PullRequestFile {
+    pub sha: String,
+    pub filename: String,
+    pub status: String,
+    pub additions: u64,
+    pub deletions: u64,
+    pub changes: u64,
+    pub blob_url: String,
+    pub raw_url: String,
+    pub contents_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_filename: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Review {
+    pub id: u64,
+    pub node_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    pub state: String,
+    pub html_url: String,
+    pub pull_request_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub submitted_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub commit_id: Option<String>,
+    pub author_association: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RequestedReviewers {
+    pub users: Vec<User>,
+    pub teams: Vec<Team>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Head {
     pub label: String,
@@ -264,6 +326,8 @@ pub struct Repository {
     pub owner: User,
     pub private: bool,
     pub html_url: String,
+    /// An empty string if the repository has no description.
+    #[serde(default, deserialize_with = "deserialize_nullable")]
     pub description: String,
     pub fork: bool,
     pub url: String,
@@ -349,6 +413,14 @@ pub struct Repository {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub allow_merge_commit: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_auto_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_update_branch: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_forking: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub delete_branch_on_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub subscribers_count: Option<u64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub network_count: Option<u64>,
@@ -359,11 +431,46 @@ pub struct Repository {
     pub watchers: u64,
 }
 
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Topics {
+    pub names: Vec<String>,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Permissions {
-    admin: bool,
-    push: bool,
-    pull: bool,
+    pub admin: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub maintain: Option<bool>,
+    pub push: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub triage: Option<bool>,
+    pub pull: bool,
+}
+
+impl Permissions {
+    /// Returns the highest permission level, or `None` if there is no permission at all.
+    pub fn level(&self) -> Option<collaborators::Permission> {
+        use collaborators::Permission;
+
+        if self.admin {
+            Some(Permission::Admin)
+        } else if self.maintain == Some(true) {
+            Some(Permission::Maintain)
+        } else if self.push {
+            Some(Permission::Push)
+        } else if self.triage == Some(true) {
+            Some(Permission::Triage)
+        } else if self.pull {
+            Some(Permission::Pull)
+        } else {
+            None
+        }
+    }
+
+    /// Returns `true` if `permission` or a higher one is granted.
+    pub fn allows(&self, permission: collaborators::Permission) -> bool {
+        self.level().is_some_and(|level| level >= permission)
+    }
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
@@ -377,3 +484,30 @@ pub struct License {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub html_url: Option<String>,
 }
+
+pub(crate) fn deserialize_nullable<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+pub(crate) fn deserialize_base64<'de, D>(deserializer: D) -> Result<Vec<u8>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    let encoded = Option::<String>::deserialize(deserializer)?.unwrap_or_default();
+    // GitHub wraps the encoded content at 60 characters.
+    let encoded: String = encoded.split_whitespace().collect();
+    base64::engine::general_purpose::STANDARD
+        .decode(encoded)
+        .map_err(serde::de::Error::custom)
+}
+
+pub(crate) fn serialize_base64<S>(content: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_str(&base64::engine::general_purpose::STANDARD.encode(content))
+}
@@ -0,0 +1,136 @@
+use super::checks::{CheckConclusion, CheckStatus};
+use super::User;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Workflow {
+    pub id: u64,
+    pub node_id: String,
+    pub name: String,
+    pub path: String,
+    /// e.g. `active` or `disabled_manually`.
+    pub state: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    pub url: String,
+    pub html_url: String,
+    pub badge_url: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Workflows {
+    pub total_count: u64,
+    pub workflows: Vec<Workflow>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WorkflowRun {
+    pub id: u64,
+    pub node_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub display_title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+    pub run_number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_attempt: Option<u64>,
+    pub event: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<CheckConclusion>,
+    pub workflow_id: u64,
+    pub url: String,
+    pub html_url: String,
+    pub jobs_url: String,
+    pub logs_url: String,
+    pub artifacts_url: String,
+    pub cancel_url: String,
+    pub rerun_url: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_started_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor: Option<User>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WorkflowRuns {
+    pub total_count: u64,
+    pub workflow_runs: Vec<WorkflowRun>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Job {
+    pub id: u64,
+    pub run_id: u64,
+    pub run_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub run_attempt: Option<u64>,
+    pub node_id: String,
+    pub head_sha: String,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    pub status: CheckStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<CheckConclusion>,
+    pub started_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    pub name: String,
+    #[serde(default)]
+    pub steps: Vec<Step>,
+    pub check_run_url: String,
+    pub labels: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub runner_name: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Step {
+    pub name: String,
+    pub status: CheckStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<CheckConclusion>,
+    pub number: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Jobs {
+    pub total_count: u64,
+    pub jobs: Vec<Job>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Artifact {
+    pub id: u64,
+    pub node_id: String,
+    pub name: String,
+    pub size_in_bytes: u64,
+    pub url: String,
+    pub archive_download_url: String,
+    pub expired: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expires_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Artifacts {
+    pub total_count: u64,
+    pub artifacts: Vec<Artifact>,
+}
@@ -0,0 +1,199 @@
+use super::{Team, User};
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Branch {
+    pub name: String,
+    pub commit: BranchCommit,
+    pub protected: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protection_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BranchCommit {
+    pub sha: String,
+    pub url: String,
+}
+
+/// The protection of a branch. Rules which are not enabled are `None`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BranchProtection {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_status_checks: Option<RequiredStatusChecks>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_pull_request_reviews: Option<RequiredReviews>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub restrictions: Option<Restrictions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub enforce_admins: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_linear_history: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_force_pushes: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub allow_deletions: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_conversation_resolution: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub required_signatures: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub block_creations: Option<ProtectionSetting>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub lock_branch: Option<ProtectionSetting>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ProtectionSetting {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub enabled: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RequiredStatusChecks {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// `true` if branches must be up to date before merging.
+    pub strict: bool,
+    pub contexts: Vec<String>,
+    #[serde(default)]
+    pub checks: Vec<StatusCheck>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
+pub struct StatusCheck {
+    pub context: String,
+    /// The app which must set the status, or any app if it's `None`.
+    pub app_id: Option<i64>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RequiredReviews {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub dismiss_stale_reviews: bool,
+    pub require_code_owner_reviews: bool,
+    #[serde(default)]
+    pub required_approving_review_count: u8,
+    #[serde(default)]
+    pub require_last_push_approval: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions: Option<Restrictions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_pull_request_allowances: Option<Restrictions>,
+}
+
+/// Users and teams a rule applies to.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Restrictions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    pub users: Vec<User>,
+    pub teams: Vec<Team>,
+}
+
+/// Required status checks to update branch protection with.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NewRequiredStatusChecks {
+    pub strict: bool,
+    pub contexts: Vec<String>,
+    pub checks: Vec<StatusCheck>,
+}
+
+impl NewRequiredStatusChecks {
+    /// Require statuses of `contexts` set by any app.
+    pub fn new(strict: bool, contexts: &[&str]) -> NewRequiredStatusChecks {
+        NewRequiredStatusChecks {
+            strict,
+            contexts: contexts.iter().map(|context| context.to_string()).collect(),
+            checks: contexts
+                .iter()
+                .map(|context| StatusCheck {
+                    context: context.to_string(),
+                    app_id: None,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl From<&RequiredStatusChecks> for NewRequiredStatusChecks {
+    fn from(checks: &RequiredStatusChecks) -> Self {
+        NewRequiredStatusChecks {
+            strict: checks.strict,
+            contexts: checks.contexts.clone(),
+            checks: checks.checks.clone(),
+        }
+    }
+}
+
+/// Required reviews to update branch protection with.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NewRequiredReviews {
+    pub dismiss_stale_reviews: bool,
+    pub require_code_owner_reviews: bool,
+    pub required_approving_review_count: u8,
+    pub require_last_push_approval: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub dismissal_restrictions: Option<NewRestrictions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub bypass_pull_request_allowances: Option<NewRestrictions>,
+}
+
+impl NewRequiredReviews {
+    /// Require `required_approving_review_count` approvals, between 0 and 6.
+    pub fn new(required_approving_review_count: u8) -> NewRequiredReviews {
+        NewRequiredReviews {
+            dismiss_stale_reviews: false,
+            require_code_owner_reviews: false,
+            required_approving_review_count,
+            require_last_push_approval: false,
+            dismissal_restrictions: None,
+            bypass_pull_request_allowances: None,
+        }
+    }
+}
+
+impl From<&RequiredReviews> for NewRequiredReviews {
+    fn from(reviews: &RequiredReviews) -> Self {
+        NewRequiredReviews {
+            dismiss_stale_reviews: reviews.dismiss_stale_reviews,
+            require_code_owner_reviews: reviews.require_code_owner_reviews,
+            required_approving_review_count: reviews.required_approving_review_count,
+            require_last_push_approval: reviews.require_last_push_approval,
+            dismissal_restrictions: reviews.dismissal_restrictions.as_ref().map(Into::into),
+            bypass_pull_request_allowances: reviews
+                .bypass_pull_request_allowances
+                .as_ref()
+                .map(Into::into),
+        }
+    }
+}
+
+/// Logins of users and slugs of teams a rule applies to.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NewRestrictions {
+    pub users: Vec<String>,
+    pub teams: Vec<String>,
+}
+
+impl From<&Restrictions> for NewRestrictions {
+    fn from(restrictions: &Restrictions) -> Self {
+        NewRestrictions {
+            users: restrictions
+                .users
+                .iter()
+                .map(|user| user.login.clone())
+                .collect(),
+            teams: restrictions
+                .teams
+                .iter()
+                .map(|team| team.slug.clone())
+                .collect(),
+        }
+    }
+}
@@ -0,0 +1,243 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// The maximum number of annotations GitHub accepts in a single request.
+pub const MAX_ANNOTATIONS_PER_REQUEST: usize = 50;
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckRun {
+    pub id: u64,
+    pub node_id: String,
+    pub head_sha: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub external_id: Option<String>,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub details_url: Option<String>,
+    pub status: CheckStatus,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<CheckConclusion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub started_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub completed_at: Option<DateTime<Utc>>,
+    pub output: CheckRunOutput,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_suite: Option<CheckSuiteRef>,
+}
+
+/// The output of a check run as returned by the API.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckRunOutput {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    pub annotations_count: u64,
+    pub annotations_url: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckSuiteRef {
+    pub id: u64,
+}
+
+/// Check runs for a reference.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckRuns {
+    pub total_count: u64,
+    pub check_runs: Vec<CheckRun>,
+}
+
+/// The output of a check run to be created or updated.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NewCheckRunOutput {
+    pub title: String,
+    pub summary: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub text: Option<String>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub annotations: Vec<CheckAnnotation>,
+}
+
+impl NewCheckRunOutput {
+    pub fn new(title: impl Into<String>, summary: impl Into<String>) -> NewCheckRunOutput {
+        NewCheckRunOutput {
+            title: title.into(),
+            summary: summary.into(),
+            text: None,
+            annotations: Vec::new(),
+        }
+    }
+
+    /// Set the details of the check run, which may be Markdown.
+    pub fn text(mut self, text: impl Into<String>) -> NewCheckRunOutput {
+        self.text = Some(text.into());
+        self
+    }
+
+    pub fn annotations(mut self, annotations: Vec<CheckAnnotation>) -> NewCheckRunOutput {
+        self.annotations = annotations;
+        self
+    }
+
+    /// Split the output into outputs with at most [`MAX_ANNOTATIONS_PER_REQUEST`] annotations.
+    ///
+    /// Every output has the same title, summary and text, and there is always at least one.
+    pub fn batches(&self) -> Vec<NewCheckRunOutput> {
+        let batch = |annotations: &[CheckAnnotation]| NewCheckRunOutput {
+            title: self.title.clone(),
+            summary: self.summary.clone(),
+            text: self.text.clone(),
+            annotations: annotations.to_vec(),
+        };
+        if self.annotations.is_empty() {
+            return vec![batch(&[])];
+        }
+        self.annotations
+            .chunks(MAX_ANNOTATIONS_PER_REQUEST)
+            .map(batch)
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckAnnotation {
+    pub path: String,
+    pub start_line: u64,
+    pub end_line: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start_column: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end_column: Option<u64>,
+    pub annotation_level: AnnotationLevel,
+    pub message: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub raw_details: Option<String>,
+    /// Only present in annotations returned by the API.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub blob_href: Option<String>,
+}
+
+impl CheckAnnotation {
+    pub fn new(
+        path: impl Into<String>,
+        start_line: u64,
+        end_line: u64,
+        annotation_level: AnnotationLevel,
+        message: impl Into<String>,
+    ) -> CheckAnnotation {
+        CheckAnnotation {
+            path: path.into(),
+            start_line,
+            end_line,
+            start_column: None,
+            end_column: None,
+            annotation_level,
+            message: message.into(),
+            title: None,
+            raw_details: None,
+            blob_href: None,
+        }
+    }
+
+    /// Set the columns, which GitHub only accepts if the annotation is on a single line.
+    pub fn columns(mut self, start_column: u64, end_column: u64) -> CheckAnnotation {
+        self.start_column = Some(start_column);
+        self.end_column = Some(end_column);
+        self
+    }
+
+    pub fn title(mut self, title: impl Into<String>) -> CheckAnnotation {
+        self.title = Some(title.into());
+        self
+    }
+
+    pub fn raw_details(mut self, raw_details: impl Into<String>) -> CheckAnnotation {
+        self.raw_details = Some(raw_details.into());
+        self
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AnnotationLevel {
+    Notice,
+    Warning,
+    Failure,
+}
+
+/// The status of a check run or a check suite.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckStatus {
+    Queued,
+    InProgress,
+    Completed,
+    Waiting,
+    Requested,
+    Pending,
+}
+
+impl std::fmt::Display for CheckStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let status = match self {
+            CheckStatus::Queued => "queued",
+            CheckStatus::InProgress => "in_progress",
+            CheckStatus::Completed => "completed",
+            CheckStatus::Waiting => "waiting",
+            CheckStatus::Requested => "requested",
+            CheckStatus::Pending => "pending",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+/// The conclusion of a completed check run or check suite.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckConclusion {
+    ActionRequired,
+    Cancelled,
+    Failure,
+    Neutral,
+    Success,
+    Skipped,
+    Stale,
+    TimedOut,
+    StartupFailure,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CheckSuite {
+    pub id: u64,
+    pub node_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_branch: Option<String>,
+    pub head_sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub status: Option<CheckStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conclusion: Option<CheckConclusion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub before: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub after: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub latest_check_runs_count: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub check_runs_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
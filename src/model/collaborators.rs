@@ -0,0 +1,123 @@
+use super::User;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A permission level on a repository, in order from the lowest to the highest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Permission {
+    Pull,
+    Triage,
+    Push,
+    Maintain,
+    Admin,
+}
+
+impl Permission {
+    /// Parse a permission or role name, where `read` is `pull` and `write` is `push`.
+    ///
+    /// Returns `None` for `none` and custom roles.
+    /// ```
+    /// use ghrs::model::collaborators::Permission;
+    ///
+    /// assert_eq!(Permission::from_name("write"), Some(Permission::Push));
+    /// assert_eq!(Permission::from_name("maintain"), Some(Permission::Maintain));
+    /// assert_eq!(Permission::from_name("none"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Permission> {
+        match name {
+            "pull" | "read" => Some(Permission::Pull),
+            "triage" => Some(Permission::Triage),
+            "push" | "write" => Some(Permission::Push),
+            "maintain" => Some(Permission::Maintain),
+            "admin" => Some(Permission::Admin),
+            _ => None,
+        }
+    }
+}
+
+impl std::fmt::Display for Permission {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let permission = match self {
+            Permission::Pull => "pull",
+            Permission::Triage => "triage",
+            Permission::Push => "push",
+            Permission::Maintain => "maintain",
+            Permission::Admin => "admin",
+        };
+        write!(f, "{}", permission)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Collaborator {
+    #[serde(flatten)]
+    pub user: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permissions: Option<super::Permissions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_name: Option<String>,
+}
+
+/// The effective permission of a user on a repository.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RepositoryPermission {
+    /// `admin`, `write`, `read` or `none`, which doesn't tell `maintain` and `triage`.
+    pub permission: String,
+    /// e.g. `maintain`, `triage` or the name of a custom role.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub user: Option<User>,
+}
+
+impl RepositoryPermission {
+    /// Returns the permission level, preferring the role name which is more precise.
+    pub fn level(&self) -> Option<Permission> {
+        self.role_name
+            .as_deref()
+            .and_then(Permission::from_name)
+            .or_else(|| Permission::from_name(&self.permission))
+    }
+
+    /// Returns `true` if the user has `permission` or a higher one.
+    pub fn allows(&self, permission: Permission) -> bool {
+        self.level().is_some_and(|level| level >= permission)
+    }
+}
+
+/// A repository in an invitation, which has fewer fields than [`super::Repository`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MinimalRepository {
+    pub id: u64,
+    pub node_id: String,
+    pub name: String,
+    pub full_name: String,
+    pub owner: User,
+    pub private: bool,
+    pub html_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    pub fork: bool,
+    pub url: String,
+}
+
+/// An invitation to collaborate on a repository.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Invitation {
+    pub id: u64,
+    pub node_id: String,
+    pub repository: MinimalRepository,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub invitee: Option<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub inviter: Option<User>,
+    /// `read`, `write`, `admin`, `triage` or `maintain`.
+    pub permissions: String,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub expired: Option<bool>,
+    pub url: String,
+    pub html_url: String,
+}
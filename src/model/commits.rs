@@ -0,0 +1,83 @@
+use super::git::{CommitAuthor, CommitParent, CommitTree, Verification};
+use super::User;
+
+use serde::{Deserialize, Serialize};
+
+/// A commit of a repository.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct RepoCommit {
+    pub sha: String,
+    pub node_id: String,
+    pub url: String,
+    pub html_url: String,
+    pub comments_url: String,
+    pub commit: CommitData,
+    /// The GitHub user of the author, which is `None` if the email is not linked to a user.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub author: Option<User>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub committer: Option<User>,
+    pub parents: Vec<CommitParent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stats: Option<CommitStats>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<CommitFile>>,
+}
+
+/// The Git data of a [`RepoCommit`].
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CommitData {
+    pub url: String,
+    pub author: CommitAuthor,
+    pub committer: CommitAuthor,
+    pub message: String,
+    pub tree: CommitTree,
+    pub comment_count: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<Verification>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CommitStats {
+    pub additions: u64,
+    pub deletions: u64,
+    pub total: u64,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CommitFile {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<String>,
+    pub filename: String,
+    pub status: String,
+    pub additions: u64,
+    pub deletions: u64,
+    pub changes: u64,
+    pub blob_url: String,
+    pub raw_url: String,
+    pub contents_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub patch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub previous_filename: Option<String>,
+}
+
+/// A comparison between two commits.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Comparison {
+    pub url: String,
+    pub html_url: String,
+    pub permalink_url: String,
+    pub diff_url: String,
+    pub patch_url: String,
+    pub base_commit: RepoCommit,
+    pub merge_base_commit: RepoCommit,
+    /// One of `diverged`, `ahead`, `behind` and `identical`.
+    pub status: String,
+    pub ahead_by: u64,
+    pub behind_by: u64,
+    pub total_commits: u64,
+    pub commits: Vec<RepoCommit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub files: Option<Vec<CommitFile>>,
+}
@@ -0,0 +1,125 @@
+use super::git::Commit;
+use super::{deserialize_base64, serialize_base64};
+
+use serde::{de::Error, Deserialize, Deserializer, Serialize};
+
+/// A content of a repository, which is a file, a directory, a symlink or a submodule.
+#[derive(Clone, Debug, Serialize)]
+#[serde(untagged)]
+pub enum Content {
+    File(ContentFile),
+    Dir(Vec<ContentEntry>),
+    Symlink(ContentSymlink),
+    Submodule(ContentSubmodule),
+}
+
+impl<'de> Deserialize<'de> for Content {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = serde_json::Value::deserialize(deserializer)?;
+        if value.is_array() {
+            return serde_json::from_value(value)
+                .map(Content::Dir)
+                .map_err(|e| Error::custom(e.to_string()));
+        }
+        let content = match value.get("type").and_then(|t| t.as_str()) {
+            Some("file") => serde_json::from_value(value).map(Content::File),
+            Some("symlink") => serde_json::from_value(value).map(Content::Symlink),
+            Some("submodule") => serde_json::from_value(value).map(Content::Submodule),
+            Some(other) => return Err(Error::custom(format!("unknown content type `{}`", other))),
+            None => return Err(Error::missing_field("type")),
+        };
+        content.map_err(|e| Error::custom(e.to_string()))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ContentFile {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub size: u64,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encoding: Option<String>,
+    /// The decoded content. This is empty for files larger than 1 MB, use the raw media type for them.
+    #[serde(
+        default,
+        deserialize_with = "deserialize_base64",
+        serialize_with = "serialize_base64"
+    )]
+    pub content: Vec<u8>,
+}
+
+impl ContentFile {
+    /// Returns the content as UTF-8 text.
+    pub fn text(&self) -> Option<&str> {
+        std::str::from_utf8(&self.content).ok()
+    }
+}
+
+/// An entry of a directory.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ContentEntry {
+    pub r#type: String,
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub size: u64,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ContentSymlink {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub size: u64,
+    pub url: String,
+    pub target: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ContentSubmodule {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub size: u64,
+    pub url: String,
+    pub submodule_git_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub git_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub download_url: Option<String>,
+}
+
+/// A result of creating, updating or deleting a file.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct FileCommit {
+    /// The file, which is `None` when it was deleted.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<ContentFile>,
+    pub commit: Commit,
+}
@@ -1,7 +1,11 @@
-use super::{Issue, PullRequest, Comment, Label, User};
+use super::releases::Release;
+use super::{Comment, Issue, Label, PullRequest, Review, User};
 
 use chrono::{DateTime, Utc};
-use serde::{de::Error, Deserialize, Deserializer, Serialize};
+use serde::de::{DeserializeOwned, Error};
+use serde::{Deserialize, Deserializer, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, Serialize)]
 pub struct Event {
@@ -23,7 +27,7 @@ impl<'de> Deserialize<'de> for Event {
         #[derive(Deserialize)]
         struct Tmp {
             id: String,
-            r#type: EventType,
+            r#type: String,
             actor: Actor,
             repo: Repository,
             public: bool,
@@ -32,12 +36,15 @@ impl<'de> Deserialize<'de> for Event {
             org: Option<Org>,
         }
         let tmp = Tmp::deserialize(deserializer)?;
-        let payload = tmp.payload.clone().map_or(Ok(None), |data| {
-            deserialize_payload(&tmp.r#type, data).map_err(|e| Error::custom(e.to_string()))
-        })?;
+        let event_type: EventType = serde_json::from_value(Value::String(tmp.r#type.clone()))
+            .map_err(|e| Error::custom(e.to_string()))?;
+        let type_name = tmp.r#type;
+        let payload = tmp
+            .payload
+            .map(|data| deserialize_payload(&type_name, &event_type, data));
         let event = Event {
             id: tmp.id,
-            r#type: tmp.r#type,
+            r#type: event_type,
             actor: tmp.actor,
             repo: tmp.repo,
             public: tmp.public,
@@ -49,42 +56,92 @@ impl<'de> Deserialize<'de> for Event {
     }
 }
 
-fn deserialize_payload(
-    event_type: &EventType,
-    data: serde_json::Value,
-) -> Result<Option<Payload>, serde_json::Error> {
+/// Parse a payload of `event_type`, or keep it as [`Payload::Unknown`] if the type is unknown
+/// or the payload doesn't match the type.
+fn deserialize_payload(type_name: &str, event_type: &EventType, data: Value) -> Payload {
     let payload = match event_type {
-        EventType::IssuesEvent => Some(
-            serde_json::from_value::<IssuesEventPayload>(data).map(Payload::IssuesEventPayload)?,
-        ),
-        EventType::PullRequestEvent => Some(
-            serde_json::from_value::<PullRequestEventPayload>(data)
-                .map(Payload::PullRequestEventPayload)?,
-        ),
-        EventType::PullRequestReviewCommentEvent => Some(
-            serde_json::from_value::<PullRequestReviewCommentEventPayload>(data)
-                .map(Payload::PullRequestReviewCommentEventPayload)?,
-        ),
-        EventType::IssueCommentEvent => Some(
-            serde_json::from_value::<IssueCommentEventPayload>(data)
-                .map(Payload::IssueCommentEventPayload)?,
-        ),
-        EventType::CommitCommentEvent => Some(
-            serde_json::from_value::<CommitCommentEventPayload>(data)
-                .map(Payload::CommitCommentEventPayload)?,
-        ),
-        _ => None,
+        EventType::CommitCommentEvent => {
+            CommitCommentEventPayload::deserialize(&data).map(Payload::CommitCommentEventPayload)
+        }
+        EventType::CreateEvent => {
+            CreateEventPayload::deserialize(&data).map(Payload::CreateEventPayload)
+        }
+        EventType::DeleteEvent => {
+            DeleteEventPayload::deserialize(&data).map(Payload::DeleteEventPayload)
+        }
+        EventType::DiscussionEvent => {
+            DiscussionEventPayload::deserialize(&data).map(Payload::DiscussionEventPayload)
+        }
+        EventType::ForkEvent => ForkEventPayload::deserialize(&data).map(Payload::ForkEventPayload),
+        EventType::GollumEvent => {
+            GollumEventPayload::deserialize(&data).map(Payload::GollumEventPayload)
+        }
+        EventType::IssueCommentEvent => {
+            IssueCommentEventPayload::deserialize(&data).map(Payload::IssueCommentEventPayload)
+        }
+        EventType::IssuesEvent => {
+            IssuesEventPayload::deserialize(&data).map(Payload::IssuesEventPayload)
+        }
+        EventType::MemberEvent => {
+            MemberEventPayload::deserialize(&data).map(Payload::MemberEventPayload)
+        }
+        EventType::PublicEvent => {
+            PublicEventPayload::deserialize(&data).map(Payload::PublicEventPayload)
+        }
+        EventType::PullRequestEvent => {
+            PullRequestEventPayload::deserialize(&data).map(Payload::PullRequestEventPayload)
+        }
+        EventType::PullRequestReviewEvent => PullRequestReviewEventPayload::deserialize(&data)
+            .map(Payload::PullRequestReviewEventPayload),
+        EventType::PullRequestReviewCommentEvent => {
+            PullRequestReviewCommentEventPayload::deserialize(&data)
+                .map(Payload::PullRequestReviewCommentEventPayload)
+        }
+        EventType::PullRequestReviewThreadEvent => {
+            PullRequestReviewThreadEventPayload::deserialize(&data)
+                .map(Payload::PullRequestReviewThreadEventPayload)
+        }
+        EventType::PushEvent => PushEventPayload::deserialize(&data).map(Payload::PushEventPayload),
+        EventType::ReleaseEvent => {
+            ReleaseEventPayload::deserialize(&data).map(Payload::ReleaseEventPayload)
+        }
+        EventType::SponsorshipEvent => {
+            SponsorshipEventPayload::deserialize(&data).map(Payload::SponsorshipEventPayload)
+        }
+        EventType::WatchEvent => {
+            WatchEventPayload::deserialize(&data).map(Payload::WatchEventPayload)
+        }
+        EventType::UnknownEvent => Err(serde_json::Error::custom("unknown event type")),
     };
-    Ok(payload)
+    payload.unwrap_or(Payload::Unknown {
+        type_name: type_name.to_string(),
+        raw: data,
+    })
 }
 
-#[derive(Clone, Debug, Deserialize, Serialize)]
+/// The type of an event.
+///
+/// See <https://docs.github.com/en/rest/using-the-rest-api/github-event-types>.
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize, Serialize)]
 pub enum EventType {
+    CommitCommentEvent,
+    CreateEvent,
+    DeleteEvent,
+    DiscussionEvent,
+    ForkEvent,
+    GollumEvent,
+    IssueCommentEvent,
     IssuesEvent,
+    MemberEvent,
+    PublicEvent,
     PullRequestEvent,
+    PullRequestReviewEvent,
     PullRequestReviewCommentEvent,
-    IssueCommentEvent,
-    CommitCommentEvent,
+    PullRequestReviewThreadEvent,
+    PushEvent,
+    ReleaseEvent,
+    SponsorshipEvent,
+    WatchEvent,
     #[serde(other)]
     UnknownEvent,
 }
@@ -92,18 +149,38 @@ pub enum EventType {
 #[allow(clippy::large_enum_variant)]
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub enum Payload {
+    CommitCommentEventPayload(CommitCommentEventPayload),
+    CreateEventPayload(CreateEventPayload),
+    DeleteEventPayload(DeleteEventPayload),
+    DiscussionEventPayload(DiscussionEventPayload),
+    ForkEventPayload(ForkEventPayload),
+    GollumEventPayload(GollumEventPayload),
+    IssueCommentEventPayload(IssueCommentEventPayload),
     IssuesEventPayload(IssuesEventPayload),
+    MemberEventPayload(MemberEventPayload),
+    PublicEventPayload(PublicEventPayload),
     PullRequestEventPayload(PullRequestEventPayload),
+    PullRequestReviewEventPayload(PullRequestReviewEventPayload),
     PullRequestReviewCommentEventPayload(PullRequestReviewCommentEventPayload),
-    IssueCommentEventPayload(IssueCommentEventPayload),
-    CommitCommentEventPayload(CommitCommentEventPayload),
+    PullRequestReviewThreadEventPayload(PullRequestReviewThreadEventPayload),
+    PushEventPayload(PushEventPayload),
+    ReleaseEventPayload(ReleaseEventPayload),
+    SponsorshipEventPayload(SponsorshipEventPayload),
+    WatchEventPayload(WatchEventPayload),
+    /// A payload of an unknown event type, or one which doesn't match its type, as is.
+    Unknown {
+        type_name: String,
+        raw: Value,
+    },
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IssuesEventPayload {
     pub action: String,
     pub issue: Issue,
-    // pub changes: Changes,
+    /// Only present if `action` is `edited`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Changes>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub assignee: Option<User>,
     #[serde(skip_serializing_if = "Option::is_none")]
@@ -114,14 +191,18 @@ pub struct IssuesEventPayload {
 pub struct PullRequestEventPayload {
     pub action: String,
     pub number: u64,
-    // pub changes: Changes,
+    /// Only present if `action` is `edited`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Changes>,
     pub pull_request: PullRequest,
 }
 
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct PullRequestReviewCommentEventPayload {
     pub action: String,
-    // pub changes: Changes,
+    /// Only present if `action` is `edited`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Changes>,
     pub pull_request: PullRequest,
     pub comment: Comment,
 }
@@ -129,7 +210,9 @@ pub struct PullRequestReviewCommentEventPayload {
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct IssueCommentEventPayload {
     pub action: String,
-    // pub changes: Changes,
+    /// Only present if `action` is `edited`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Changes>,
     pub issue: Issue,
     pub comment: Comment,
 }
@@ -140,6 +223,291 @@ pub struct CommitCommentEventPayload {
     pub comment: Comment,
 }
 
+/// Changes of an edited object, where only changed fields are present.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Changes {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub title: Option<Change<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<Change<String>>,
+    /// The base branch of a pull request.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base: Option<BaseChanges>,
+    /// The name of a release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<Change<String>>,
+    /// The tag of a release.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tag_name: Option<Change<String>>,
+    /// The permission of a collaborator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub permission: Option<Change<String>>,
+    /// The previous permission of a collaborator, which is sent instead of `permission` sometimes.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old_permission: Option<Change<String>>,
+    /// The role of a collaborator.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub role_name: Option<Change<String>>,
+    /// The privacy level of a sponsorship.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub privacy_level: Option<Change<String>>,
+    /// Changes of other fields as they are, e.g. `category` of a discussion or `new_issue` of a transferred issue.
+    /// Use [`Changes::other`] to deserialize them.
+    #[serde(flatten)]
+    pub others: BTreeMap<String, Value>,
+}
+
+impl Changes {
+    /// Returns the names of changed fields, e.g. `base.ref`.
+    ///
+    /// ```
+    /// use ghrs::model::event::Changes;
+    ///
+    /// let changes: Changes = serde_json::from_str(
+    ///     r#"{"title": {"from": "Fix"}, "base": {"ref": {"from": "master"}}, "tier": {"from": {}}}"#,
+    /// )
+    /// .unwrap();
+    /// assert_eq!(changes.fields(), vec!["title", "base.ref", "tier"]);
+    /// ```
+    pub fn fields(&self) -> Vec<&str> {
+        let mut fields = Vec::new();
+        let named = [
+            ("title", self.title.is_some()),
+            ("body", self.body.is_some()),
+            (
+                "base.ref",
+                self.base.as_ref().is_some_and(|base| base.r#ref.is_some()),
+            ),
+            (
+                "base.sha",
+                self.base.as_ref().is_some_and(|base| base.sha.is_some()),
+            ),
+            ("name", self.name.is_some()),
+            ("tag_name", self.tag_name.is_some()),
+            ("permission", self.permission.is_some()),
+            ("old_permission", self.old_permission.is_some()),
+            ("role_name", self.role_name.is_some()),
+            ("privacy_level", self.privacy_level.is_some()),
+        ];
+        for (field, changed) in named.iter() {
+            if *changed {
+                fields.push(*field);
+            }
+        }
+        fields.extend(self.others.keys().map(String::as_str));
+        fields
+    }
+
+    /// Deserialize the change of another field, or returns `None` if it isn't changed or doesn't match `T`.
+    ///
+    /// ```
+    /// use ghrs::model::event::{Change, Changes};
+    /// use serde_json::Value;
+    ///
+    /// let changes: Changes = serde_json::from_str(
+    ///     r#"{"category": {"from": {"name": "Q&A"}}, "new_repository": {"full_name": "octo-org/other"}}"#,
+    /// )
+    /// .unwrap();
+    /// let category: Change<Value> = changes.other("category").unwrap();
+    /// assert_eq!(category.from.unwrap()["name"], "Q&A");
+    /// let repository: Value = changes.other("new_repository").unwrap();
+    /// assert_eq!(repository["full_name"], "octo-org/other");
+    /// ```
+    pub fn other<T: DeserializeOwned>(&self, field: &str) -> Option<T> {
+        T::deserialize(self.others.get(field)?).ok()
+    }
+}
+
+/// A change of a field. Usually only the previous value is sent.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct Change<T> {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub from: Option<T>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub to: Option<T>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize, Serialize)]
+pub struct BaseChanges {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub r#ref: Option<Change<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<Change<String>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CreateEventPayload {
+    /// `None` if a repository is created.
+    pub r#ref: Option<String>,
+    /// `repository`, `branch` or `tag`.
+    pub ref_type: String,
+    pub master_branch: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pusher_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DeleteEventPayload {
+    pub r#ref: String,
+    /// `branch` or `tag`.
+    pub ref_type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub pusher_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DiscussionEventPayload {
+    pub action: String,
+    pub discussion: Discussion,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Changes>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Discussion {
+    pub id: u64,
+    pub node_id: String,
+    pub number: u64,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    pub state: String,
+    pub html_url: String,
+    pub user: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub category: Option<DiscussionCategory>,
+    pub comments: u64,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct DiscussionCategory {
+    pub id: u64,
+    pub node_id: String,
+    pub name: String,
+    pub slug: String,
+    pub emoji: String,
+    pub is_answerable: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ForkEventPayload {
+    /// The created fork.
+    pub forkee: super::Repository,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GollumEventPayload {
+    pub pages: Vec<WikiPage>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WikiPage {
+    pub page_name: String,
+    pub title: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub summary: Option<String>,
+    /// `created` or `edited`.
+    pub action: String,
+    pub sha: String,
+    pub html_url: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct MemberEventPayload {
+    pub action: String,
+    pub member: User,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Changes>,
+}
+
+/// The payload of a `PublicEvent`, which is always empty.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PublicEventPayload {}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PullRequestReviewEventPayload {
+    pub action: String,
+    /// Only present if `action` is `edited`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Changes>,
+    pub pull_request: PullRequest,
+    pub review: Review,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PullRequestReviewThreadEventPayload {
+    /// `resolved` or `unresolved`.
+    pub action: String,
+    pub pull_request: PullRequest,
+    pub thread: ReviewThread,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReviewThread {
+    pub node_id: String,
+    pub comments: Vec<Comment>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PushEventPayload {
+    pub push_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub distinct_size: Option<u64>,
+    pub r#ref: String,
+    pub head: String,
+    pub before: String,
+    /// At most 20 commits, which may be left out by the API.
+    #[serde(default)]
+    pub commits: Vec<PushCommit>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PushCommit {
+    pub sha: String,
+    pub message: String,
+    pub author: PushCommitAuthor,
+    pub url: String,
+    /// `true` if the commit wasn't pushed before.
+    pub distinct: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PushCommitAuthor {
+    pub name: String,
+    pub email: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReleaseEventPayload {
+    pub action: String,
+    pub release: Release,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Changes>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct SponsorshipEventPayload {
+    pub action: String,
+    /// The date when a pending cancellation or tier change takes effect.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub effective_date: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Changes>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct WatchEventPayload {
+    /// Always `started`.
+    pub action: String,
+}
+
 #[derive(Clone, Debug, Deserialize, Serialize)]
 pub struct Repository {
     pub id: u64,
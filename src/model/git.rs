@@ -0,0 +1,189 @@
+use super::{deserialize_base64, serialize_base64};
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Blob {
+    pub sha: String,
+    pub node_id: String,
+    pub url: String,
+    pub size: u64,
+    pub encoding: String,
+    /// The decoded content.
+    #[serde(
+        deserialize_with = "deserialize_base64",
+        serialize_with = "serialize_base64"
+    )]
+    pub content: Vec<u8>,
+}
+
+/// A created blob.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BlobRef {
+    pub sha: String,
+    pub url: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Tree {
+    pub sha: String,
+    pub url: String,
+    pub tree: Vec<TreeEntry>,
+    /// `true` if the recursive tree exceeded the limit of GitHub.
+    pub truncated: bool,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct TreeEntry {
+    pub path: String,
+    pub mode: String,
+    pub r#type: String,
+    pub sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub size: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+}
+
+/// An entry of a tree to be created.
+#[derive(Clone, Debug, Serialize)]
+pub struct NewTreeEntry {
+    pub path: String,
+    pub mode: String,
+    pub r#type: String,
+    /// `Some(None)` deletes the entry from the base tree.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sha: Option<Option<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content: Option<String>,
+}
+
+impl NewTreeEntry {
+    /// A file pointing to an existing blob.
+    pub fn blob(path: impl Into<String>, sha: impl Into<String>) -> NewTreeEntry {
+        NewTreeEntry {
+            path: path.into(),
+            mode: "100644".to_string(),
+            r#type: "blob".to_string(),
+            sha: Some(Some(sha.into())),
+            content: None,
+        }
+    }
+
+    /// A file with UTF-8 content, for which GitHub creates a blob.
+    pub fn text(path: impl Into<String>, content: impl Into<String>) -> NewTreeEntry {
+        NewTreeEntry {
+            path: path.into(),
+            mode: "100644".to_string(),
+            r#type: "blob".to_string(),
+            sha: None,
+            content: Some(content.into()),
+        }
+    }
+
+    /// A file to be deleted from the base tree.
+    pub fn delete(path: impl Into<String>) -> NewTreeEntry {
+        NewTreeEntry {
+            path: path.into(),
+            mode: "100644".to_string(),
+            r#type: "blob".to_string(),
+            sha: Some(None),
+            content: None,
+        }
+    }
+
+    /// Set the file mode, e.g. `100755` for an executable.
+    pub fn mode(mut self, mode: impl Into<String>) -> NewTreeEntry {
+        self.mode = mode.into();
+        self
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Commit {
+    pub sha: String,
+    pub node_id: String,
+    pub url: String,
+    pub html_url: String,
+    pub author: CommitAuthor,
+    pub committer: CommitAuthor,
+    pub message: String,
+    pub tree: CommitTree,
+    pub parents: Vec<CommitParent>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<Verification>,
+}
+
+/// The author or committer of a commit.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CommitAuthor {
+    pub name: String,
+    pub email: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub date: Option<DateTime<Utc>>,
+}
+
+impl CommitAuthor {
+    pub fn new(name: impl Into<String>, email: impl Into<String>) -> CommitAuthor {
+        CommitAuthor {
+            name: name.into(),
+            email: email.into(),
+            date: None,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CommitTree {
+    pub sha: String,
+    pub url: String,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CommitParent {
+    pub sha: String,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub html_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Verification {
+    pub verified: bool,
+    pub reason: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub payload: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Reference {
+    #[serde(rename = "ref")]
+    pub ref_field: String,
+    pub node_id: String,
+    pub url: String,
+    pub object: GitObject,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct GitObject {
+    pub r#type: String,
+    pub sha: String,
+    pub url: String,
+}
+
+/// An annotated tag.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Tag {
+    pub node_id: String,
+    pub tag: String,
+    pub sha: String,
+    pub url: String,
+    pub message: String,
+    pub tagger: CommitAuthor,
+    pub object: GitObject,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verification: Option<Verification>,
+}
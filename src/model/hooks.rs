@@ -0,0 +1,142 @@
+use crate::webhook::Delivery;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+/// A webhook of a repository or an organization.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Hook {
+    pub id: u64,
+    /// `Repository` or `Organization`.
+    #[serde(rename = "type")]
+    pub hook_type: String,
+    /// Always `web`.
+    pub name: String,
+    pub active: bool,
+    pub events: Vec<String>,
+    pub config: HookConfig,
+    pub url: String,
+    /// Only for repository webhooks.
+    pub test_url: Option<String>,
+    pub ping_url: String,
+    pub deliveries_url: Option<String>,
+    pub last_response: Option<HookResponse>,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+}
+
+/// The configuration of a webhook.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct HookConfig {
+    /// The URL deliveries are sent to.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub url: Option<String>,
+    /// `json` or `form`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub content_type: Option<String>,
+    /// The secret isn't returned, GitHub returns `********` if it's set.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub secret: Option<String>,
+    /// `0` to verify SSL certificates, `1` not to verify them.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub insecure_ssl: Option<String>,
+}
+
+impl HookConfig {
+    /// A configuration sending JSON payloads to `url`.
+    ///
+    /// ```
+    /// use ghrs::model::hooks::HookConfig;
+    ///
+    /// let config = HookConfig::json("https://example.com/webhook").secret("secret");
+    /// assert_eq!(config.content_type.as_deref(), Some("json"));
+    /// ```
+    pub fn json(url: impl Into<String>) -> HookConfig {
+        HookConfig {
+            url: Some(url.into()),
+            content_type: Some("json".to_string()),
+            ..Default::default()
+        }
+    }
+
+    pub fn secret(mut self, secret: impl Into<String>) -> Self {
+        self.secret = Some(secret.into());
+        self
+    }
+}
+
+/// The response to the last delivery of a webhook.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HookResponse {
+    pub code: Option<u16>,
+    /// e.g. `active` or `unused`.
+    pub status: Option<String>,
+    pub message: Option<String>,
+}
+
+/// A delivery of a webhook.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HookDelivery {
+    pub id: u64,
+    /// The value of `X-GitHub-Delivery`, which is the same for redeliveries.
+    pub guid: String,
+    pub delivered_at: DateTime<Utc>,
+    /// `true` if this is a redelivery.
+    pub redelivery: bool,
+    /// Seconds taken to deliver.
+    pub duration: f64,
+    /// e.g. `OK` or `Invalid HTTP Response: 503`.
+    pub status: String,
+    /// The status code of the response, `0` if there is no response.
+    pub status_code: u16,
+    /// e.g. `pull_request`.
+    pub event: String,
+    pub action: Option<String>,
+    pub installation_id: Option<u64>,
+    pub repository_id: Option<u64>,
+    pub throttled_at: Option<DateTime<Utc>>,
+}
+
+impl HookDelivery {
+    /// Returns `true` if the delivery got a 2xx response.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status_code)
+    }
+}
+
+/// A delivery of a webhook with the request and the response.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HookDeliveryDetail {
+    #[serde(flatten)]
+    pub delivery: HookDelivery,
+    pub url: Option<String>,
+    pub request: HookDeliveryRequest,
+    pub response: HookDeliveryResponse,
+}
+
+impl HookDeliveryDetail {
+    /// Parse the request payload as a [`Delivery`], e.g. to handle a missed delivery locally.
+    pub fn to_delivery(&self) -> Result<Delivery, serde_json::Error> {
+        let body = serde_json::to_vec(&self.request.payload)?;
+        Delivery::parse(
+            self.delivery.guid.clone(),
+            self.delivery.event.clone(),
+            &body,
+        )
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HookDeliveryRequest {
+    pub headers: Option<BTreeMap<String, String>>,
+    pub payload: Option<Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct HookDeliveryResponse {
+    pub headers: Option<BTreeMap<String, String>>,
+    /// The body of the response.
+    pub payload: Option<String>,
+}
@@ -0,0 +1,106 @@
+use super::collaborators::MinimalRepository;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Notification {
+    /// The ID of the thread.
+    pub id: String,
+    pub repository: MinimalRepository,
+    pub subject: NotificationSubject,
+    /// e.g. `mention`, `review_requested` or `subscribed`.
+    pub reason: String,
+    pub unread: bool,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_read_at: Option<DateTime<Utc>>,
+    pub url: String,
+    pub subscription_url: String,
+}
+
+impl Notification {
+    /// Returns the issue or the pull request the notification is about.
+    pub fn subject_ref(&self) -> Option<SubjectRef> {
+        self.subject.url.as_deref().and_then(SubjectRef::from_url)
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct NotificationSubject {
+    pub title: String,
+    /// The API URL of the subject, which is `None` for some types, e.g. discussions.
+    pub url: Option<String>,
+    pub latest_comment_url: Option<String>,
+    /// e.g. `Issue`, `PullRequest`, `Release`, `Commit` or `Discussion`.
+    pub r#type: String,
+}
+
+/// An issue or a pull request a notification is about.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct SubjectRef {
+    pub owner: String,
+    pub repo: String,
+    pub kind: SubjectKind,
+    pub number: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SubjectKind {
+    Issue,
+    PullRequest,
+}
+
+impl SubjectRef {
+    /// Parse an API URL like `https://api.github.com/repos/owner/repo/issues/1`.
+    ///
+    /// Returns `None` for URLs of other subjects, e.g. releases and commits.
+    ///
+    /// ```
+    /// use ghrs::model::notifications::{SubjectKind, SubjectRef};
+    ///
+    /// let subject = SubjectRef::from_url("https://api.github.com/repos/octocat/Hello-World/pulls/123");
+    /// assert_eq!(
+    ///     subject,
+    ///     Some(SubjectRef {
+    ///         owner: "octocat".to_string(),
+    ///         repo: "Hello-World".to_string(),
+    ///         kind: SubjectKind::PullRequest,
+    ///         number: 123,
+    ///     })
+    /// );
+    /// ```
+    pub fn from_url(url: &str) -> Option<SubjectRef> {
+        let path = &url[url.find("/repos/")? + "/repos/".len()..];
+        let segments: Vec<&str> = path.split('/').collect();
+        if segments.len() != 4 {
+            return None;
+        }
+        let kind = match segments[2] {
+            "issues" => SubjectKind::Issue,
+            "pulls" => SubjectKind::PullRequest,
+            _ => return None,
+        };
+        Some(SubjectRef {
+            owner: segments[0].to_string(),
+            repo: segments[1].to_string(),
+            kind,
+            number: segments[3].parse().ok()?,
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ThreadSubscription {
+    pub subscribed: bool,
+    pub ignored: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub thread_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_url: Option<String>,
+}
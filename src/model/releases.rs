@@ -0,0 +1,59 @@
+use super::User;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Release {
+    pub id: u64,
+    pub node_id: String,
+    pub url: String,
+    pub html_url: String,
+    pub assets_url: String,
+    /// A URI template like `https://uploads.github.com/repos/o/r/releases/1/assets{?name,label}`.
+    pub upload_url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub tarball_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub zipball_url: Option<String>,
+    pub tag_name: String,
+    pub target_commitish: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub body: Option<String>,
+    pub draft: bool,
+    pub prerelease: bool,
+    pub created_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published_at: Option<DateTime<Utc>>,
+    pub author: User,
+    pub assets: Vec<ReleaseAsset>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReleaseAsset {
+    pub id: u64,
+    pub node_id: String,
+    pub url: String,
+    pub browser_download_url: String,
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    /// `uploaded` or `open`.
+    pub state: String,
+    pub content_type: String,
+    pub size: u64,
+    pub download_count: u64,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub uploader: Option<User>,
+}
+
+/// Generated release notes.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct ReleaseNotes {
+    pub name: String,
+    pub body: String,
+}
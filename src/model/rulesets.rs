@@ -0,0 +1,274 @@
+use chrono::{DateTime, Utc};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use serde_json::Value;
+
+/// A ruleset of a repository or an organization.
+///
+/// Rulesets in a list don't have `rules`, `conditions` and `bypass_actors`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Ruleset {
+    pub id: u64,
+    pub name: String,
+    /// `branch`, `tag` or `push`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target: Option<String>,
+    /// `Repository` or `Organization`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub source_type: Option<String>,
+    pub source: String,
+    pub enforcement: Enforcement,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub bypass_actors: Vec<BypassActor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub conditions: Option<RulesetConditions>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rules: Vec<Rule>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub created_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub updated_at: Option<DateTime<Utc>>,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Enforcement {
+    Disabled,
+    Active,
+    /// Rules are evaluated but not enforced, which is only available for GitHub Enterprise.
+    Evaluate,
+}
+
+/// An actor who can bypass a ruleset.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BypassActor {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub actor_id: Option<u64>,
+    /// e.g. `Team`, `Integration`, `OrganizationAdmin` or `RepositoryRole`.
+    pub actor_type: String,
+    /// `always` or `pull_request`.
+    pub bypass_mode: String,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RulesetConditions {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ref_name: Option<NameCondition>,
+    /// Only for organization rulesets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_name: Option<NameCondition>,
+    /// Only for organization rulesets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub repository_id: Option<RepositoryIdCondition>,
+}
+
+/// Patterns of names to include and exclude.
+///
+/// For refs, `~DEFAULT_BRANCH` and `~ALL` are special patterns.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct NameCondition {
+    pub include: Vec<String>,
+    pub exclude: Vec<String>,
+    /// Only for repository names, `true` to prevent renaming matching repositories.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub protected: Option<bool>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+pub struct RepositoryIdCondition {
+    pub repository_ids: Vec<u64>,
+}
+
+/// A rule of a ruleset.
+///
+/// Rules which are unknown to this crate, or whose parameters can't be parsed, are kept as [`Rule::Unknown`].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Rule {
+    Creation,
+    Update(UpdateParameters),
+    Deletion,
+    RequiredLinearHistory,
+    RequiredDeployments(RequiredDeploymentsParameters),
+    RequiredSignatures,
+    PullRequest(PullRequestParameters),
+    RequiredStatusChecks(RequiredStatusChecksParameters),
+    NonFastForward,
+    CommitMessagePattern(PatternParameters),
+    CommitAuthorEmailPattern(PatternParameters),
+    CommitterEmailPattern(PatternParameters),
+    BranchNamePattern(PatternParameters),
+    TagNamePattern(PatternParameters),
+    Unknown {
+        r#type: String,
+        parameters: Option<Value>,
+    },
+}
+
+impl Rule {
+    /// Returns the type of the rule, e.g. `pull_request`.
+    pub fn type_name(&self) -> &str {
+        match self {
+            Rule::Creation => "creation",
+            Rule::Update(_) => "update",
+            Rule::Deletion => "deletion",
+            Rule::RequiredLinearHistory => "required_linear_history",
+            Rule::RequiredDeployments(_) => "required_deployments",
+            Rule::RequiredSignatures => "required_signatures",
+            Rule::PullRequest(_) => "pull_request",
+            Rule::RequiredStatusChecks(_) => "required_status_checks",
+            Rule::NonFastForward => "non_fast_forward",
+            Rule::CommitMessagePattern(_) => "commit_message_pattern",
+            Rule::CommitAuthorEmailPattern(_) => "commit_author_email_pattern",
+            Rule::CommitterEmailPattern(_) => "committer_email_pattern",
+            Rule::BranchNamePattern(_) => "branch_name_pattern",
+            Rule::TagNamePattern(_) => "tag_name_pattern",
+            Rule::Unknown { r#type, .. } => r#type,
+        }
+    }
+
+    fn parameters(&self) -> Result<Option<Value>, serde_json::Error> {
+        match self {
+            Rule::Creation
+            | Rule::Deletion
+            | Rule::RequiredLinearHistory
+            | Rule::RequiredSignatures
+            | Rule::NonFastForward => Ok(None),
+            Rule::Update(parameters) => serde_json::to_value(parameters).map(Some),
+            Rule::RequiredDeployments(parameters) => serde_json::to_value(parameters).map(Some),
+            Rule::PullRequest(parameters) => serde_json::to_value(parameters).map(Some),
+            Rule::RequiredStatusChecks(parameters) => serde_json::to_value(parameters).map(Some),
+            Rule::CommitMessagePattern(parameters)
+            | Rule::CommitAuthorEmailPattern(parameters)
+            | Rule::CommitterEmailPattern(parameters)
+            | Rule::BranchNamePattern(parameters)
+            | Rule::TagNamePattern(parameters) => serde_json::to_value(parameters).map(Some),
+            Rule::Unknown { parameters, .. } => Ok(parameters.clone()),
+        }
+    }
+}
+
+#[derive(Deserialize, Serialize)]
+struct RawRule {
+    r#type: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    parameters: Option<Value>,
+}
+
+impl<'de> Deserialize<'de> for Rule {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let raw = RawRule::deserialize(deserializer)?;
+        let parameters = raw.parameters.clone().unwrap_or(Value::Null);
+        let rule = match raw.r#type.as_str() {
+            "creation" => Ok(Rule::Creation),
+            "update" => serde_json::from_value(parameters).map(Rule::Update),
+            "deletion" => Ok(Rule::Deletion),
+            "required_linear_history" => Ok(Rule::RequiredLinearHistory),
+            "required_deployments" => {
+                serde_json::from_value(parameters).map(Rule::RequiredDeployments)
+            }
+            "required_signatures" => Ok(Rule::RequiredSignatures),
+            "pull_request" => serde_json::from_value(parameters).map(Rule::PullRequest),
+            "required_status_checks" => {
+                serde_json::from_value(parameters).map(Rule::RequiredStatusChecks)
+            }
+            "non_fast_forward" => Ok(Rule::NonFastForward),
+            "commit_message_pattern" => {
+                serde_json::from_value(parameters).map(Rule::CommitMessagePattern)
+            }
+            "commit_author_email_pattern" => {
+                serde_json::from_value(parameters).map(Rule::CommitAuthorEmailPattern)
+            }
+            "committer_email_pattern" => {
+                serde_json::from_value(parameters).map(Rule::CommitterEmailPattern)
+            }
+            "branch_name_pattern" => {
+                serde_json::from_value(parameters).map(Rule::BranchNamePattern)
+            }
+            "tag_name_pattern" => serde_json::from_value(parameters).map(Rule::TagNamePattern),
+            _ => Err(serde_json::Error::custom("unknown rule")),
+        };
+        Ok(rule.unwrap_or(Rule::Unknown {
+            r#type: raw.r#type,
+            parameters: raw.parameters,
+        }))
+    }
+}
+
+impl Serialize for Rule {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        RawRule {
+            r#type: self.type_name().to_string(),
+            parameters: self.parameters().map_err(serde::ser::Error::custom)?,
+        }
+        .serialize(serializer)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct UpdateParameters {
+    /// `true` if branches can be updated only with a fetch and merge.
+    #[serde(default)]
+    pub update_allows_fetch_and_merge: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RequiredDeploymentsParameters {
+    pub required_deployment_environments: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PullRequestParameters {
+    pub dismiss_stale_reviews_on_push: bool,
+    pub require_code_owner_review: bool,
+    pub require_last_push_approval: bool,
+    pub required_approving_review_count: u8,
+    pub required_review_thread_resolution: bool,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub allowed_merge_methods: Vec<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct RequiredStatusChecksParameters {
+    pub required_status_checks: Vec<StatusCheckConfiguration>,
+    /// `true` if branches must be up to date before merging.
+    pub strict_required_status_checks_policy: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub do_not_enforce_on_create: Option<bool>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct StatusCheckConfiguration {
+    pub context: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub integration_id: Option<i64>,
+}
+
+#[derive(Clone, Debug, PartialEq, Deserialize, Serialize)]
+pub struct PatternParameters {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub name: Option<String>,
+    #[serde(default)]
+    pub negate: bool,
+    /// `starts_with`, `ends_with`, `contains` or `regex`.
+    pub operator: String,
+    pub pattern: String,
+}
+
+/// A rule which applies to a branch, and the ruleset it comes from.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct BranchRule {
+    #[serde(flatten)]
+    pub rule: Rule,
+    /// `Repository` or `Organization`.
+    pub ruleset_source_type: String,
+    pub ruleset_source: String,
+    pub ruleset_id: u64,
+}
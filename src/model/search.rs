@@ -0,0 +1,26 @@
+use super::collaborators::MinimalRepository;
+use super::commits::RepoCommit;
+
+use serde::{Deserialize, Serialize};
+
+/// A file found by code search.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CodeSearchItem {
+    pub name: String,
+    pub path: String,
+    pub sha: String,
+    pub url: String,
+    pub git_url: String,
+    pub html_url: String,
+    pub repository: MinimalRepository,
+    pub score: f64,
+}
+
+/// A commit found by commit search.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CommitSearchItem {
+    #[serde(flatten)]
+    pub commit: RepoCommit,
+    pub repository: MinimalRepository,
+    pub score: f64,
+}
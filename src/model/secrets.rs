@@ -0,0 +1,49 @@
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A public key to encrypt secrets with.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PublicKey {
+    pub key_id: String,
+    /// The Base64 encoded key.
+    pub key: String,
+}
+
+/// A secret, whose value is never returned by the API.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Secret {
+    pub name: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Only for organization secrets.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_repositories_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Secrets {
+    pub total_count: u64,
+    pub secrets: Vec<Secret>,
+}
+
+/// A configuration variable.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Variable {
+    pub name: String,
+    pub value: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    /// Only for organization variables.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub selected_repositories_url: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Variables {
+    pub total_count: u64,
+    pub variables: Vec<Variable>,
+}
@@ -0,0 +1,47 @@
+use super::User;
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A commit status.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Status {
+    pub id: u64,
+    pub node_id: String,
+    pub url: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub avatar_url: Option<String>,
+    pub state: StatusState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub target_url: Option<String>,
+    pub context: String,
+    pub created_at: DateTime<Utc>,
+    pub updated_at: DateTime<Utc>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub creator: Option<User>,
+}
+
+/// The state of a commit status.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum StatusState {
+    Error,
+    Failure,
+    Pending,
+    Success,
+}
+
+/// The combined status of a reference, which is the latest status for each context.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct CombinedStatus {
+    /// `failure` if any of the contexts is `error` or `failure`, `pending` if there are no statuses
+    /// or a context is `pending`, otherwise `success`.
+    pub state: StatusState,
+    pub statuses: Vec<Status>,
+    pub sha: String,
+    pub total_count: u64,
+    pub commit_url: String,
+    pub url: String,
+}
@@ -0,0 +1,836 @@
+//! The Notifications API
+use crate::model::notifications::{Notification, SubjectKind, ThreadSubscription};
+use crate::model::{Issue, PullRequest};
+use crate::{Client, Page};
+
+use serde::Serialize;
+
+/// A client for the Notifications API.
+///
+/// See <https://docs.github.com/en/rest/activity/notifications>.
+pub struct NotificationsHandler<'a> {
+    client: &'a Client,
+}
+
+impl<'a> NotificationsHandler<'a> {
+    pub fn new(client: &'a Client) -> NotificationsHandler<'a> {
+        NotificationsHandler { client }
+    }
+
+    /// List notifications for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#list-notifications-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let notifications = client.token("your_token").notifications().list().participating(true).send();
+    /// ```
+    pub fn list(&self) -> ListNotificationsBuilder<'_> {
+        ListNotificationsBuilder::new(self)
+    }
+
+    /// List repository notifications for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#list-repository-notifications-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let notifications = client.token("your_token").notifications().list_for_repo("owner", "repo").all(true).send();
+    /// ```
+    pub fn list_for_repo(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> ListRepoNotificationsBuilder<'_> {
+        ListRepoNotificationsBuilder::new(self, owner, repo)
+    }
+
+    /// Mark notifications as read.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#mark-notifications-as-read>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").notifications().mark_as_read().last_read_at("2024-01-01T00:00:00Z").send();
+    /// ```
+    pub fn mark_as_read(&self) -> MarkNotificationsAsReadBuilder<'_> {
+        MarkNotificationsAsReadBuilder::new(self)
+    }
+
+    /// Mark repository notifications as read.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#mark-repository-notifications-as-read>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").notifications().mark_repo_as_read("owner", "repo").send();
+    /// ```
+    pub fn mark_repo_as_read(
+        &self,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> MarkRepoNotificationsAsReadBuilder<'_> {
+        MarkRepoNotificationsAsReadBuilder::new(self, owner, repo)
+    }
+
+    /// Get a thread.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#get-a-thread>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let notification = client.token("your_token").notifications().get_thread("1").send();
+    /// ```
+    pub fn get_thread(&self, thread_id: impl Into<String>) -> GetThreadBuilder<'_> {
+        GetThreadBuilder::new(self, thread_id)
+    }
+
+    /// Mark a thread as read.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#mark-a-thread-as-read>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").notifications().mark_thread_as_read("1").send();
+    /// ```
+    pub fn mark_thread_as_read(&self, thread_id: impl Into<String>) -> MarkThreadAsReadBuilder<'_> {
+        MarkThreadAsReadBuilder::new(self, thread_id)
+    }
+
+    /// Mark a thread as done.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#mark-a-thread-as-done>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").notifications().mark_thread_as_done("1").send();
+    /// ```
+    pub fn mark_thread_as_done(&self, thread_id: impl Into<String>) -> MarkThreadAsDoneBuilder<'_> {
+        MarkThreadAsDoneBuilder::new(self, thread_id)
+    }
+
+    /// Get a thread subscription for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#get-a-thread-subscription-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let subscription = client.token("your_token").notifications().get_thread_subscription("1").send();
+    /// ```
+    pub fn get_thread_subscription(
+        &self,
+        thread_id: impl Into<String>,
+    ) -> GetThreadSubscriptionBuilder<'_> {
+        GetThreadSubscriptionBuilder::new(self, thread_id)
+    }
+
+    /// Set a thread subscription.
+    ///
+    /// Ignore the thread to mute notifications until you comment on it or get mentioned.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#set-a-thread-subscription>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let subscription = client.token("your_token").notifications().set_thread_subscription("1").ignored(true).send();
+    /// ```
+    pub fn set_thread_subscription(
+        &self,
+        thread_id: impl Into<String>,
+    ) -> SetThreadSubscriptionBuilder<'_> {
+        SetThreadSubscriptionBuilder::new(self, thread_id)
+    }
+
+    /// Delete a thread subscription.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#delete-a-thread-subscription>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").notifications().delete_thread_subscription("1").send();
+    /// ```
+    pub fn delete_thread_subscription(
+        &self,
+        thread_id: impl Into<String>,
+    ) -> DeleteThreadSubscriptionBuilder<'_> {
+        DeleteThreadSubscriptionBuilder::new(self, thread_id)
+    }
+
+    /// Get the issue or the pull request a notification is about.
+    ///
+    /// Returns `None` if the subject is something else, e.g. a release or a commit.
+    /// ```no_run
+    /// use ghrs::notifications::Subject;
+    ///
+    /// let client = ghrs::Client::new().token("your_token");
+    /// let notifications = client.notifications().list().send().unwrap();
+    /// for notification in notifications {
+    ///     match client.notifications().resolve_subject(&notification) {
+    ///         Ok(Some(Subject::PullRequest(pull_request))) => println!("{}", pull_request.title),
+    ///         Ok(Some(Subject::Issue(issue))) => println!("{}", issue.title),
+    ///         _ => {}
+    ///     }
+    /// }
+    /// ```
+    pub fn resolve_subject(
+        &self,
+        notification: &Notification,
+    ) -> Result<Option<Subject>, ureq::Error> {
+        let subject = match notification.subject_ref() {
+            Some(subject) => subject,
+            None => return Ok(None),
+        };
+        let resolved = match subject.kind {
+            SubjectKind::Issue => Subject::Issue(
+                self.client
+                    .issues(subject.owner, subject.repo)
+                    .get(subject.number)
+                    .send()?,
+            ),
+            SubjectKind::PullRequest => Subject::PullRequest(
+                self.client
+                    .pulls(subject.owner, subject.repo)
+                    .get(subject.number)
+                    .send()?,
+            ),
+        };
+        Ok(Some(resolved))
+    }
+}
+
+/// The subject of a notification resolved by [`NotificationsHandler::resolve_subject`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug)]
+pub enum Subject {
+    Issue(Issue),
+    PullRequest(PullRequest),
+}
+
+/// A builder for listing notifications for the authenticated user.
+pub struct ListNotificationsBuilder<'a> {
+    handler: &'a NotificationsHandler<'a>,
+    accept: Option<String>,
+    all: Option<bool>,
+    participating: Option<bool>,
+    since: Option<String>,
+    before: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListNotificationsBuilder<'a> {
+    fn new(handler: &'a NotificationsHandler) -> Self {
+        ListNotificationsBuilder {
+            handler,
+            accept: None,
+            all: None,
+            participating: None,
+            since: None,
+            before: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List notifications for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#list-notifications-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let notifications = client.token("your_token").notifications().list().participating(true).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Notification>, ureq::Error> {
+        let mut request = ureq::get(&format!("{}/notifications", self.handler.client.base_url));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(all) = self.all {
+            request = request.query("all", &all.to_string());
+        }
+        if let Some(participating) = self.participating {
+            request = request.query("participating", &participating.to_string());
+        }
+        if let Some(since) = self.since.clone() {
+            request = request.query("since", &since);
+        }
+        if let Some(before) = self.before.clone() {
+            request = request.query("before", &before);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let notifications = Page::from_response(response)?;
+        Ok(notifications)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Include notifications marked as read if `true`.
+    pub fn all(mut self, all: bool) -> Self {
+        self.all = Some(all);
+        self
+    }
+
+    /// Return only notifications in which the user is directly participating or mentioned if `true`.
+    pub fn participating(mut self, participating: bool) -> Self {
+        self.participating = Some(participating);
+        self
+    }
+
+    /// Return only notifications updated after the time in ISO 8601 format.
+    pub fn since(mut self, since: impl Into<String>) -> Self {
+        self.since = Some(since.into());
+        self
+    }
+
+    /// Return only notifications updated before the time in ISO 8601 format.
+    pub fn before(mut self, before: impl Into<String>) -> Self {
+        self.before = Some(before.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing repository notifications for the authenticated user.
+pub struct ListRepoNotificationsBuilder<'a> {
+    handler: &'a NotificationsHandler<'a>,
+    owner: String,
+    repo: String,
+    accept: Option<String>,
+    all: Option<bool>,
+    participating: Option<bool>,
+    since: Option<String>,
+    before: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListRepoNotificationsBuilder<'a> {
+    fn new(
+        handler: &'a NotificationsHandler,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> Self {
+        ListRepoNotificationsBuilder {
+            handler,
+            owner: owner.into(),
+            repo: repo.into(),
+            accept: None,
+            all: None,
+            participating: None,
+            since: None,
+            before: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List repository notifications for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#list-repository-notifications-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let notifications = client.token("your_token").notifications().list_for_repo("owner", "repo").all(true).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Notification>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/notifications",
+            self.handler.client.base_url, self.owner, self.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(all) = self.all {
+            request = request.query("all", &all.to_string());
+        }
+        if let Some(participating) = self.participating {
+            request = request.query("participating", &participating.to_string());
+        }
+        if let Some(since) = self.since.clone() {
+            request = request.query("since", &since);
+        }
+        if let Some(before) = self.before.clone() {
+            request = request.query("before", &before);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let notifications = Page::from_response(response)?;
+        Ok(notifications)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Include notifications marked as read if `true`.
+    pub fn all(mut self, all: bool) -> Self {
+        self.all = Some(all);
+        self
+    }
+
+    /// Return only notifications in which the user is directly participating or mentioned if `true`.
+    pub fn participating(mut self, participating: bool) -> Self {
+        self.participating = Some(participating);
+        self
+    }
+
+    /// Return only notifications updated after the time in ISO 8601 format.
+    pub fn since(mut self, since: impl Into<String>) -> Self {
+        self.since = Some(since.into());
+        self
+    }
+
+    /// Return only notifications updated before the time in ISO 8601 format.
+    pub fn before(mut self, before: impl Into<String>) -> Self {
+        self.before = Some(before.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for marking notifications as read.
+#[derive(Serialize)]
+pub struct MarkNotificationsAsReadBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a NotificationsHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_read_at: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    read: Option<bool>,
+}
+
+impl<'a> MarkNotificationsAsReadBuilder<'a> {
+    fn new(handler: &'a NotificationsHandler) -> Self {
+        MarkNotificationsAsReadBuilder {
+            handler,
+            accept: None,
+            last_read_at: None,
+            read: None,
+        }
+    }
+
+    /// Mark notifications as read.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#mark-notifications-as-read>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").notifications().mark_as_read().last_read_at("2024-01-01T00:00:00Z").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::put(&format!("{}/notifications", self.handler.client.base_url));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.send_json(self)?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Mark notifications updated before the time in ISO 8601 format, which is now by default.
+    pub fn last_read_at(mut self, last_read_at: impl Into<String>) -> Self {
+        self.last_read_at = Some(last_read_at.into());
+        self
+    }
+
+    /// Mark notifications as unread if `false`.
+    pub fn read(mut self, read: bool) -> Self {
+        self.read = Some(read);
+        self
+    }
+}
+
+/// A builder for marking repository notifications as read.
+#[derive(Serialize)]
+pub struct MarkRepoNotificationsAsReadBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a NotificationsHandler<'a>,
+    #[serde(skip)]
+    owner: String,
+    #[serde(skip)]
+    repo: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    last_read_at: Option<String>,
+}
+
+impl<'a> MarkRepoNotificationsAsReadBuilder<'a> {
+    fn new(
+        handler: &'a NotificationsHandler,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> Self {
+        MarkRepoNotificationsAsReadBuilder {
+            handler,
+            owner: owner.into(),
+            repo: repo.into(),
+            accept: None,
+            last_read_at: None,
+        }
+    }
+
+    /// Mark repository notifications as read.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#mark-repository-notifications-as-read>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").notifications().mark_repo_as_read("owner", "repo").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::put(&format!(
+            "{}/repos/{}/{}/notifications",
+            self.handler.client.base_url, self.owner, self.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.send_json(self)?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Mark notifications updated before the time in ISO 8601 format, which is now by default.
+    pub fn last_read_at(mut self, last_read_at: impl Into<String>) -> Self {
+        self.last_read_at = Some(last_read_at.into());
+        self
+    }
+}
+
+/// A builder for getting a thread.
+pub struct GetThreadBuilder<'a> {
+    handler: &'a NotificationsHandler<'a>,
+    thread_id: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetThreadBuilder<'a> {
+    fn new(handler: &'a NotificationsHandler, thread_id: impl Into<String>) -> Self {
+        GetThreadBuilder {
+            handler,
+            thread_id: thread_id.into(),
+            accept: None,
+        }
+    }
+
+    /// Get a thread.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#get-a-thread>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let notification = client.token("your_token").notifications().get_thread("1").send();
+    /// ```
+    pub fn send(&self) -> Result<Notification, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/notifications/threads/{}",
+            self.handler.client.base_url, self.thread_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let notification: Notification = request.call()?.into_json()?;
+        Ok(notification)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for marking a thread as read.
+pub struct MarkThreadAsReadBuilder<'a> {
+    handler: &'a NotificationsHandler<'a>,
+    thread_id: String,
+    accept: Option<String>,
+}
+
+impl<'a> MarkThreadAsReadBuilder<'a> {
+    fn new(handler: &'a NotificationsHandler, thread_id: impl Into<String>) -> Self {
+        MarkThreadAsReadBuilder {
+            handler,
+            thread_id: thread_id.into(),
+            accept: None,
+        }
+    }
+
+    /// Mark a thread as read.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#mark-a-thread-as-read>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").notifications().mark_thread_as_read("1").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::patch(&format!(
+            "{}/notifications/threads/{}",
+            self.handler.client.base_url, self.thread_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for marking a thread as done.
+pub struct MarkThreadAsDoneBuilder<'a> {
+    handler: &'a NotificationsHandler<'a>,
+    thread_id: String,
+    accept: Option<String>,
+}
+
+impl<'a> MarkThreadAsDoneBuilder<'a> {
+    fn new(handler: &'a NotificationsHandler, thread_id: impl Into<String>) -> Self {
+        MarkThreadAsDoneBuilder {
+            handler,
+            thread_id: thread_id.into(),
+            accept: None,
+        }
+    }
+
+    /// Mark a thread as done.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#mark-a-thread-as-done>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").notifications().mark_thread_as_done("1").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/notifications/threads/{}",
+            self.handler.client.base_url, self.thread_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for getting a thread subscription.
+pub struct GetThreadSubscriptionBuilder<'a> {
+    handler: &'a NotificationsHandler<'a>,
+    thread_id: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetThreadSubscriptionBuilder<'a> {
+    fn new(handler: &'a NotificationsHandler, thread_id: impl Into<String>) -> Self {
+        GetThreadSubscriptionBuilder {
+            handler,
+            thread_id: thread_id.into(),
+            accept: None,
+        }
+    }
+
+    /// Get a thread subscription for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#get-a-thread-subscription-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let subscription = client.token("your_token").notifications().get_thread_subscription("1").send();
+    /// ```
+    pub fn send(&self) -> Result<ThreadSubscription, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/notifications/threads/{}/subscription",
+            self.handler.client.base_url, self.thread_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let subscription: ThreadSubscription = request.call()?.into_json()?;
+        Ok(subscription)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for setting a thread subscription.
+#[derive(Serialize)]
+pub struct SetThreadSubscriptionBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a NotificationsHandler<'a>,
+    #[serde(skip)]
+    thread_id: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    ignored: Option<bool>,
+}
+
+impl<'a> SetThreadSubscriptionBuilder<'a> {
+    fn new(handler: &'a NotificationsHandler, thread_id: impl Into<String>) -> Self {
+        SetThreadSubscriptionBuilder {
+            handler,
+            thread_id: thread_id.into(),
+            accept: None,
+            ignored: None,
+        }
+    }
+
+    /// Set a thread subscription.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#set-a-thread-subscription>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let subscription = client.token("your_token").notifications().set_thread_subscription("1").ignored(true).send();
+    /// ```
+    pub fn send(&self) -> Result<ThreadSubscription, ureq::Error> {
+        let mut request = ureq::put(&format!(
+            "{}/notifications/threads/{}/subscription",
+            self.handler.client.base_url, self.thread_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let subscription: ThreadSubscription = request.send_json(self)?.into_json()?;
+        Ok(subscription)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn ignored(mut self, ignored: bool) -> Self {
+        self.ignored = Some(ignored);
+        self
+    }
+}
+
+/// A builder for deleting a thread subscription.
+pub struct DeleteThreadSubscriptionBuilder<'a> {
+    handler: &'a NotificationsHandler<'a>,
+    thread_id: String,
+    accept: Option<String>,
+}
+
+impl<'a> DeleteThreadSubscriptionBuilder<'a> {
+    fn new(handler: &'a NotificationsHandler, thread_id: impl Into<String>) -> Self {
+        DeleteThreadSubscriptionBuilder {
+            handler,
+            thread_id: thread_id.into(),
+            accept: None,
+        }
+    }
+
+    /// Delete a thread subscription.
+    ///
+    /// See <https://docs.github.com/en/rest/activity/notifications#delete-a-thread-subscription>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").notifications().delete_thread_subscription("1").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/notifications/threads/{}/subscription",
+            self.handler.client.base_url, self.thread_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
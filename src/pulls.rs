@@ -1,7 +1,13 @@
 //! The Pull Request API
-use crate::model::PullRequest;
+pub mod reviewers;
+
+use crate::model::{PullRequest, PullRequestFile, RequestedReviewers, Review};
 use crate::{Client, Page};
 
+use self::reviewers::ReviewerStrategy;
+use crate::codeowners::{self, CodeOwners, OwnersReport};
+use serde::{Deserialize, Serialize};
+
 /// A client for the Pull Request API.
 ///
 /// See <https://docs.github.com/en/rest/reference/pulls>.
@@ -16,7 +22,7 @@ impl<'a> PullsHandler<'a> {
         client: &'a Client,
         owner: impl Into<String>,
         repo: impl Into<String>,
-    ) -> PullsHandler {
+    ) -> PullsHandler<'a> {
         PullsHandler {
             client,
             owner: owner.into(),
@@ -31,8 +37,8 @@ impl<'a> PullsHandler<'a> {
     /// let client = ghrs::Client::new();
     /// let pull_requests = client.pulls("owner", "repo").list().per_page(100).send();
     /// ```
-    pub fn list(&self) -> ListPullRequestsBuilder {
-        ListPullRequestsBuilder::new(&self)
+    pub fn list(&self) -> ListPullRequestsBuilder<'_> {
+        ListPullRequestsBuilder::new(self)
     }
 
     /// Get a pull request.
@@ -42,11 +48,221 @@ impl<'a> PullsHandler<'a> {
     /// let client = ghrs::Client::new();
     /// let pull_request = client.pulls("owner", "repo").get(1234).send();
     /// ```
-    pub fn get(&self, pull_number: u64) -> GetPullRequestBuilder {
-        GetPullRequestBuilder::new(&self, pull_number)
+    pub fn get(&self, pull_number: u64) -> GetPullRequestBuilder<'_> {
+        GetPullRequestBuilder::new(self, pull_number)
+    }
+
+    /// List pull request files.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/pulls#list-pull-requests-files>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let files = client.pulls("owner", "repo").list_files(1234).per_page(100).send();
+    /// ```
+    pub fn list_files(&self, pull_number: u64) -> ListPullRequestFilesBuilder<'_> {
+        ListPullRequestFilesBuilder::new(self, pull_number)
+    }
+
+    /// List reviews for a pull request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/pulls#list-reviews-for-a-pull-request>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let reviews = client.pulls("owner", "repo").list_reviews(1234).per_page(100).send();
+    /// ```
+    pub fn list_reviews(&self, pull_number: u64) -> ListReviewsBuilder<'_> {
+        ListReviewsBuilder::new(self, pull_number)
+    }
+
+    /// List requested reviewers for a pull request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/pulls#get-all-requested-reviewers-for-a-pull-request>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let requested_reviewers = client.pulls("owner", "repo").list_requested_reviewers(1234).send();
+    /// ```
+    pub fn list_requested_reviewers(&self, pull_number: u64) -> ListRequestedReviewersBuilder<'_> {
+        ListRequestedReviewersBuilder::new(self, pull_number)
+    }
+
+    /// Request reviewers for a pull request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/pulls#request-reviewers-for-a-pull-request>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let pull_request = client
+    ///     .pulls("owner", "repo")
+    ///     .request_reviewers(1234)
+    ///     .reviewers(vec!["octocat".to_string()])
+    ///     .team_reviewers(vec!["justice-league".to_string()])
+    ///     .send();
+    /// ```
+    pub fn request_reviewers(&self, pull_number: u64) -> RequestReviewersBuilder<'_> {
+        RequestReviewersBuilder::new(self, pull_number)
+    }
+
+    /// Remove requested reviewers from a pull request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/pulls#remove-requested-reviewers-from-a-pull-request>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let pull_request = client
+    ///     .pulls("owner", "repo")
+    ///     .remove_requested_reviewers(1234)
+    ///     .reviewers(vec!["octocat".to_string()])
+    ///     .send();
+    /// ```
+    pub fn remove_requested_reviewers(
+        &self,
+        pull_number: u64,
+    ) -> RemoveRequestedReviewersBuilder<'_> {
+        RemoveRequestedReviewersBuilder::new(self, pull_number)
+    }
+
+    /// Request `count` reviewers picked from `candidates` by `strategy`.
+    ///
+    /// The author of the pull request and users who are already requested are never picked.
+    /// ```no_run
+    /// use ghrs::pulls::reviewers::RoundRobin;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let candidates = vec!["alice".to_string(), "bob".to_string(), "carol".to_string()];
+    /// let mut strategy = RoundRobin::new();
+    /// let pull_request = client
+    ///     .pulls("owner", "repo")
+    ///     .assign_reviewers(1234, &candidates, 2, &mut strategy);
+    /// ```
+    pub fn assign_reviewers<S: ReviewerStrategy>(
+        &self,
+        pull_number: u64,
+        candidates: &[String],
+        count: usize,
+        strategy: &mut S,
+    ) -> Result<PullRequest, ureq::Error> {
+        let pull_request = self.get(pull_number).send()?;
+        let mut excluded = vec![pull_request.user.login.clone()];
+        excluded.extend(
+            pull_request
+                .requested_reviewers
+                .iter()
+                .map(|user| user.login.clone()),
+        );
+
+        let reviewers = strategy.pick_excluding(candidates, &excluded, count);
+        if reviewers.is_empty() {
+            return Ok(pull_request);
+        }
+        self.request_reviewers(pull_number)
+            .reviewers(reviewers)
+            .send()
+    }
+
+    /// Check the files of a pull request against `codeowners` and the approvals of its reviews.
+    ///
+    /// Members of team owners are fetched with the organization team API.
+    /// Email owners never approve, see [`CodeOwners::report`](crate::codeowners::CodeOwners::report).
+    /// ```no_run
+    /// use ghrs::codeowners::CodeOwners;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let codeowners = CodeOwners::parse("*.rs @octocat");
+    /// let report = client.pulls("owner", "repo").check_code_owners(1234, &codeowners).unwrap();
+    /// for requirement in report.missing() {
+    ///     println!("{:?} needs an approval from {:?}", requirement.paths, requirement.owners);
+    /// }
+    /// ```
+    pub fn check_code_owners(
+        &self,
+        pull_number: u64,
+        codeowners: &CodeOwners,
+    ) -> Result<OwnersReport, ureq::Error> {
+        let mut files = Vec::new();
+        let mut page = 1;
+        loop {
+            let items = self
+                .list_files(pull_number)
+                .per_page(100)
+                .page(page)
+                .send()?
+                .take_items();
+            if items.is_empty() {
+                break;
+            }
+            files.extend(items);
+            if page == u8::MAX {
+                break;
+            }
+            page += 1;
+        }
+
+        let mut reviews = Vec::new();
+        let mut page = 1;
+        loop {
+            let items = self
+                .list_reviews(pull_number)
+                .per_page(100)
+                .page(page)
+                .send()?
+                .take_items();
+            if items.is_empty() {
+                break;
+            }
+            reviews.extend(items);
+            if page == u8::MAX {
+                break;
+            }
+            page += 1;
+        }
+
+        let mut error = None;
+        let report = codeowners.report(
+            files.iter().map(|file| file.filename.as_str()),
+            &codeowners::approvers(&reviews),
+            |org, slug| match list_team_members(self.client, org, slug) {
+                Ok(members) => members,
+                Err(e) => {
+                    error.get_or_insert(e);
+                    Vec::new()
+                }
+            },
+        );
+        match error {
+            Some(e) => Err(e),
+            None => Ok(report),
+        }
     }
 }
 
+fn list_team_members(client: &Client, org: &str, slug: &str) -> Result<Vec<String>, ureq::Error> {
+    #[derive(Deserialize)]
+    struct Member {
+        login: String,
+    }
+
+    let mut members = Vec::new();
+    let mut page = 1;
+    loop {
+        let mut request = ureq::get(&format!(
+            "{}/orgs/{}/teams/{}/members",
+            client.base_url, org, slug
+        ));
+        if let Some(token) = client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        let items: Vec<Member> = request
+            .query("per_page", "100")
+            .query("page", &page.to_string())
+            .call()?
+            .into_json()?;
+        if items.is_empty() {
+            break;
+        }
+        members.extend(items.into_iter().map(|member| member.login));
+        page += 1;
+    }
+    Ok(members)
+}
+
 /// A builder for listing pull requests.
 pub struct ListPullRequestsBuilder<'a> {
     handler: &'a PullsHandler<'a>,
@@ -207,3 +423,322 @@ impl<'a> GetPullRequestBuilder<'a> {
         self
     }
 }
+
+/// A builder for listing pull request files.
+pub struct ListPullRequestFilesBuilder<'a> {
+    handler: &'a PullsHandler<'a>,
+    pull_number: u64,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListPullRequestFilesBuilder<'a> {
+    fn new(handler: &'a PullsHandler, pull_number: u64) -> Self {
+        ListPullRequestFilesBuilder {
+            handler,
+            pull_number,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List pull request files.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/pulls#list-pull-requests-files>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let files = client.pulls("owner", "repo").list_files(1234).per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<PullRequestFile>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/pulls/{}/files",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.pull_number
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let files = Page::from_response(response)?;
+        Ok(files)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing reviews for a pull request.
+pub struct ListReviewsBuilder<'a> {
+    handler: &'a PullsHandler<'a>,
+    pull_number: u64,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListReviewsBuilder<'a> {
+    fn new(handler: &'a PullsHandler, pull_number: u64) -> Self {
+        ListReviewsBuilder {
+            handler,
+            pull_number,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List reviews for a pull request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/pulls#list-reviews-for-a-pull-request>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let reviews = client.pulls("owner", "repo").list_reviews(1234).per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Review>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/pulls/{}/reviews",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.pull_number
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let reviews = Page::from_response(response)?;
+        Ok(reviews)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing requested reviewers for a pull request.
+pub struct ListRequestedReviewersBuilder<'a> {
+    handler: &'a PullsHandler<'a>,
+    pull_number: u64,
+    accept: Option<String>,
+}
+
+impl<'a> ListRequestedReviewersBuilder<'a> {
+    fn new(handler: &'a PullsHandler, pull_number: u64) -> Self {
+        ListRequestedReviewersBuilder {
+            handler,
+            pull_number,
+            accept: None,
+        }
+    }
+
+    /// List requested reviewers for a pull request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/pulls#get-all-requested-reviewers-for-a-pull-request>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let requested_reviewers = client.pulls("owner", "repo").list_requested_reviewers(1234).send();
+    /// ```
+    pub fn send(&self) -> Result<RequestedReviewers, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/pulls/{}/requested_reviewers",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.pull_number
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let requested_reviewers: RequestedReviewers = request.call()?.into_json()?;
+        Ok(requested_reviewers)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for requesting reviewers for a pull request.
+#[derive(Serialize)]
+pub struct RequestReviewersBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a PullsHandler<'a>,
+    #[serde(skip)]
+    pull_number: u64,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    reviewers: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    team_reviewers: Option<Vec<String>>,
+}
+
+impl<'a> RequestReviewersBuilder<'a> {
+    fn new(handler: &'a PullsHandler, pull_number: u64) -> Self {
+        RequestReviewersBuilder {
+            handler,
+            pull_number,
+            accept: None,
+            reviewers: None,
+            team_reviewers: None,
+        }
+    }
+
+    /// Request reviewers for a pull request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/pulls#request-reviewers-for-a-pull-request>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let pull_request = client
+    ///     .pulls("owner", "repo")
+    ///     .request_reviewers(1234)
+    ///     .reviewers(vec!["octocat".to_string()])
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<PullRequest, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/pulls/{}/requested_reviewers",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.pull_number
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let pull_request: PullRequest = request.send_json(self)?.into_json()?;
+        Ok(pull_request)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn reviewers(mut self, reviewers: impl Into<Vec<String>>) -> Self {
+        self.reviewers = Some(reviewers.into());
+        self
+    }
+
+    pub fn team_reviewers(mut self, team_reviewers: impl Into<Vec<String>>) -> Self {
+        self.team_reviewers = Some(team_reviewers.into());
+        self
+    }
+}
+
+/// A builder for removing requested reviewers from a pull request.
+#[derive(Serialize)]
+pub struct RemoveRequestedReviewersBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a PullsHandler<'a>,
+    #[serde(skip)]
+    pull_number: u64,
+    #[serde(skip)]
+    accept: Option<String>,
+    reviewers: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    team_reviewers: Option<Vec<String>>,
+}
+
+impl<'a> RemoveRequestedReviewersBuilder<'a> {
+    fn new(handler: &'a PullsHandler, pull_number: u64) -> Self {
+        RemoveRequestedReviewersBuilder {
+            handler,
+            pull_number,
+            accept: None,
+            reviewers: Vec::new(),
+            team_reviewers: None,
+        }
+    }
+
+    /// Remove requested reviewers from a pull request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/pulls#remove-requested-reviewers-from-a-pull-request>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let pull_request = client
+    ///     .pulls("owner", "repo")
+    ///     .remove_requested_reviewers(1234)
+    ///     .reviewers(vec!["octocat".to_string()])
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<PullRequest, ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/repos/{}/{}/pulls/{}/requested_reviewers",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.pull_number
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let pull_request: PullRequest = request.send_json(self)?.into_json()?;
+        Ok(pull_request)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn reviewers(mut self, reviewers: impl Into<Vec<String>>) -> Self {
+        self.reviewers = reviewers.into();
+        self
+    }
+
+    pub fn team_reviewers(mut self, team_reviewers: impl Into<Vec<String>>) -> Self {
+        self.team_reviewers = Some(team_reviewers.into());
+        self
+    }
+}
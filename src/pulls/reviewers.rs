@@ -0,0 +1,193 @@
+//! Strategies for picking reviewers of a pull request.
+use crate::model::PullRequest;
+use crate::pulls::PullsHandler;
+
+use std::collections::HashMap;
+
+/// A strategy which picks reviewers from candidates.
+///
+/// Any `FnMut(&[String], usize) -> Vec<String>` is also a strategy.
+pub trait ReviewerStrategy {
+    /// Returns at most `count` reviewers picked from `candidates`.
+    fn pick(&mut self, candidates: &[String], count: usize) -> Vec<String>;
+
+    /// Returns at most `count` reviewers picked from `candidates` except `excluded`, compared case-insensitively.
+    ///
+    /// `candidates` is the whole list, which lets a strategy keep its state independent of who is excluded.
+    /// By default, `excluded` are removed and the rest is passed to [`ReviewerStrategy::pick`].
+    fn pick_excluding(
+        &mut self,
+        candidates: &[String],
+        excluded: &[String],
+        count: usize,
+    ) -> Vec<String> {
+        let candidates: Vec<String> = candidates
+            .iter()
+            .filter(|candidate| !is_excluded(candidate, excluded))
+            .cloned()
+            .collect();
+        self.pick(&candidates, count)
+    }
+}
+
+fn is_excluded(candidate: &str, excluded: &[String]) -> bool {
+    excluded
+        .iter()
+        .any(|login| candidate.eq_ignore_ascii_case(login))
+}
+
+impl<F> ReviewerStrategy for F
+where
+    F: FnMut(&[String], usize) -> Vec<String>,
+{
+    fn pick(&mut self, candidates: &[String], count: usize) -> Vec<String> {
+        self(candidates, count)
+    }
+}
+
+/// Picks candidates in turn, continuing from where the previous pick stopped.
+///
+/// The cursor is an index of the whole candidate list, so excluded candidates, e.g. the author,
+/// are skipped without shifting the rotation.
+#[derive(Clone, Debug, Default)]
+pub struct RoundRobin {
+    cursor: usize,
+}
+
+impl RoundRobin {
+    /// Create a `RoundRobin` starting at the first candidate.
+    pub fn new() -> RoundRobin {
+        RoundRobin { cursor: 0 }
+    }
+
+    /// Create a `RoundRobin` starting at `cursor`, e.g. one persisted from a previous run.
+    pub fn starting_at(cursor: usize) -> RoundRobin {
+        RoundRobin { cursor }
+    }
+
+    /// Returns the current cursor.
+    pub fn cursor(&self) -> usize {
+        self.cursor
+    }
+}
+
+impl ReviewerStrategy for RoundRobin {
+    fn pick(&mut self, candidates: &[String], count: usize) -> Vec<String> {
+        self.pick_excluding(candidates, &[], count)
+    }
+
+    fn pick_excluding(
+        &mut self,
+        candidates: &[String],
+        excluded: &[String],
+        count: usize,
+    ) -> Vec<String> {
+        if candidates.is_empty() {
+            return Vec::new();
+        }
+        let start = self.cursor % candidates.len();
+        let mut reviewers = Vec::new();
+        for offset in 0..candidates.len() {
+            if reviewers.len() == count {
+                break;
+            }
+            let index = (start + offset) % candidates.len();
+            if is_excluded(&candidates[index], excluded) {
+                continue;
+            }
+            reviewers.push(candidates[index].clone());
+            self.cursor = (index + 1) % candidates.len();
+        }
+        reviewers
+    }
+}
+
+/// Picks the candidates with the fewest open review requests.
+///
+/// Ties are broken by the order of candidates. Picked reviewers are counted,
+/// so picking for several pull requests in a row spreads the load.
+#[derive(Clone, Debug, Default)]
+pub struct LeastLoaded {
+    load: HashMap<String, usize>,
+}
+
+impl LeastLoaded {
+    /// Create a `LeastLoaded` where nobody has review requests.
+    pub fn new() -> LeastLoaded {
+        LeastLoaded {
+            load: HashMap::new(),
+        }
+    }
+
+    /// Create a `LeastLoaded` counting `requested_reviewers` of `pull_requests`.
+    pub fn from_pull_requests<'a>(
+        pull_requests: impl IntoIterator<Item = &'a PullRequest>,
+    ) -> LeastLoaded {
+        let mut least_loaded = LeastLoaded::new();
+        for pull_request in pull_requests {
+            for user in pull_request.requested_reviewers.iter() {
+                least_loaded.add(&user.login, 1);
+            }
+        }
+        least_loaded
+    }
+
+    /// Create a `LeastLoaded` counting review requests of all open pull requests.
+    ///
+    /// ```no_run
+    /// use ghrs::pulls::reviewers::LeastLoaded;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let pulls = client.pulls("owner", "repo");
+    /// let mut strategy = LeastLoaded::fetch(&pulls).unwrap();
+    /// let candidates = vec!["alice".to_string(), "bob".to_string()];
+    /// let pull_request = pulls.assign_reviewers(1234, &candidates, 1, &mut strategy);
+    /// ```
+    pub fn fetch(handler: &PullsHandler) -> Result<LeastLoaded, ureq::Error> {
+        let mut pull_requests = Vec::new();
+        let mut page = 1;
+        loop {
+            let items = handler
+                .list()
+                .state("open")
+                .per_page(100)
+                .page(page)
+                .send()?
+                .take_items();
+            if items.is_empty() {
+                break;
+            }
+            pull_requests.extend(items);
+            if page == u8::MAX {
+                break;
+            }
+            page += 1;
+        }
+        Ok(LeastLoaded::from_pull_requests(&pull_requests))
+    }
+
+    /// Returns the number of open review requests of `login`.
+    pub fn load(&self, login: &str) -> usize {
+        self.load
+            .get(&login.to_lowercase())
+            .copied()
+            .unwrap_or_default()
+    }
+
+    /// Add `count` review requests to `login`.
+    pub fn add(&mut self, login: &str, count: usize) {
+        *self.load.entry(login.to_lowercase()).or_default() += count;
+    }
+}
+
+impl ReviewerStrategy for LeastLoaded {
+    fn pick(&mut self, candidates: &[String], count: usize) -> Vec<String> {
+        let mut sorted: Vec<&String> = candidates.iter().collect();
+        sorted.sort_by_key(|candidate| self.load(candidate));
+        let reviewers: Vec<String> = sorted.into_iter().take(count).cloned().collect();
+        for reviewer in reviewers.iter() {
+            self.add(reviewer, 1);
+        }
+        reviewers
+    }
+}
@@ -0,0 +1,1710 @@
+//! The Repository API
+pub mod actions;
+pub mod branches;
+pub mod checks;
+pub mod collaborators;
+pub mod commits;
+pub mod contents;
+pub mod git;
+pub mod releases;
+pub mod statuses;
+
+use crate::hooks::HooksHandler;
+use crate::model::statuses::StatusState;
+use crate::model::{Repository, Topics};
+use crate::rulesets::RulesetsHandler;
+use crate::{Client, Page};
+
+use self::actions::ActionsHandler;
+use self::branches::{
+    DeleteBranchProtectionBuilder, GetBranchBuilder, GetBranchProtectionBuilder,
+    GetBranchRulesBuilder, ListBranchesBuilder, RenameBranchBuilder, UpdateBranchProtectionBuilder,
+};
+use self::checks::ChecksHandler;
+use self::collaborators::{
+    AcceptInvitationBuilder, AddCollaboratorBuilder, CheckCollaboratorBuilder,
+    DeclineInvitationBuilder, DeleteInvitationBuilder, GetCollaboratorPermissionBuilder,
+    ListCollaboratorsBuilder, ListInvitationsBuilder, ListUserInvitationsBuilder,
+    RemoveCollaboratorBuilder, UpdateInvitationBuilder,
+};
+use self::commits::{CompareCommitsBuilder, GetCommitBuilder, ListCommitsBuilder};
+use self::contents::{
+    CreateOrUpdateFileBuilder, DeleteFileBuilder, GetContentBuilder, GetReadmeBuilder,
+};
+use self::git::GitHandler;
+use self::releases::ReleasesHandler;
+use self::statuses::{CreateStatusBuilder, GetCombinedStatusBuilder, ListStatusesBuilder};
+
+use serde::Serialize;
+use std::io::Read;
+
+fn read_bytes(response: ureq::Response) -> Result<Vec<u8>, ureq::Error> {
+    let mut bytes = Vec::new();
+    response.into_reader().read_to_end(&mut bytes)?;
+    Ok(bytes)
+}
+
+/// A client for the Repository API.
+///
+/// See <https://docs.github.com/en/rest/reference/repos>.
+pub struct ReposHandler<'a> {
+    client: &'a Client,
+    owner: String,
+    repo: String,
+}
+
+impl<'a> ReposHandler<'a> {
+    pub fn new(
+        client: &'a Client,
+        owner: impl Into<String>,
+        repo: impl Into<String>,
+    ) -> ReposHandler<'a> {
+        ReposHandler {
+            client,
+            owner: owner.into(),
+            repo: repo.into(),
+        }
+    }
+
+    /// Get a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repository = client.repos("owner", "repo").get().send();
+    /// ```
+    pub fn get(&self) -> GetRepositoryBuilder<'_> {
+        GetRepositoryBuilder::new(self)
+    }
+
+    /// Update a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#update-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repository = client
+    ///     .repos("owner", "repo")
+    ///     .update()
+    ///     .allow_squash_merge(true)
+    ///     .allow_merge_commit(false)
+    ///     .send();
+    /// ```
+    pub fn update(&self) -> UpdateRepositoryBuilder<'_> {
+        UpdateRepositoryBuilder::new(self)
+    }
+
+    /// Delete a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#delete-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").delete().send();
+    /// ```
+    pub fn delete(&self) -> DeleteRepositoryBuilder<'_> {
+        DeleteRepositoryBuilder::new(self)
+    }
+
+    /// Transfer a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#transfer-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repository = client.repos("owner", "repo").transfer("new_owner").send();
+    /// ```
+    pub fn transfer(&self, new_owner: impl Into<String>) -> TransferRepositoryBuilder<'_> {
+        TransferRepositoryBuilder::new(self, new_owner)
+    }
+
+    /// List forks.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#list-forks>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let forks = client.repos("owner", "repo").list_forks().sort("newest").per_page(100).send();
+    /// ```
+    pub fn list_forks(&self) -> ListForksBuilder<'_> {
+        ListForksBuilder::new(self)
+    }
+
+    /// Create a fork.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#create-a-fork>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repository = client.repos("owner", "repo").create_fork().organization("org").send();
+    /// ```
+    pub fn create_fork(&self) -> CreateForkBuilder<'_> {
+        CreateForkBuilder::new(self)
+    }
+
+    /// Get all repository topics.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-all-repository-topics>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let topics = client.repos("owner", "repo").get_topics().send();
+    /// ```
+    pub fn get_topics(&self) -> GetTopicsBuilder<'_> {
+        GetTopicsBuilder::new(self)
+    }
+
+    /// Replace all repository topics.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#replace-all-repository-topics>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let topics = client
+    ///     .repos("owner", "repo")
+    ///     .replace_topics(vec!["rust".to_string(), "github".to_string()])
+    ///     .send();
+    /// ```
+    pub fn replace_topics(&self, names: impl Into<Vec<String>>) -> ReplaceTopicsBuilder<'_> {
+        ReplaceTopicsBuilder::new(self, names)
+    }
+
+    /// Get repository content.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-repository-content>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let content = client.repos("owner", "repo").contents("src/lib.rs").r#ref("main").send();
+    /// ```
+    pub fn contents(&self, path: impl Into<String>) -> GetContentBuilder<'_> {
+        GetContentBuilder::new(self, path)
+    }
+
+    /// Get a repository README.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-a-repository-readme>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let readme = client.repos("owner", "repo").readme().send();
+    /// ```
+    pub fn readme(&self) -> GetReadmeBuilder<'_> {
+        GetReadmeBuilder::new(self)
+    }
+
+    /// Create a file.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#create-or-update-file-contents>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let file_commit = client
+    ///     .repos("owner", "repo")
+    ///     .create_file("config.toml", "Add config", "key = 1\n")
+    ///     .branch("main")
+    ///     .send();
+    /// ```
+    pub fn create_file(
+        &self,
+        path: impl Into<String>,
+        message: impl Into<String>,
+        content: impl Into<Vec<u8>>,
+    ) -> CreateOrUpdateFileBuilder<'_> {
+        CreateOrUpdateFileBuilder::new(self, path, message, content)
+    }
+
+    /// Update a file. `sha` is the blob SHA of the file being replaced.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#create-or-update-file-contents>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let file_commit = client
+    ///     .repos("owner", "repo")
+    ///     .update_file("config.toml", "Update config", "key = 2\n", "95b966ae1c166bd92f8ae7d1c313e738c731dfc3")
+    ///     .send();
+    /// ```
+    pub fn update_file(
+        &self,
+        path: impl Into<String>,
+        message: impl Into<String>,
+        content: impl Into<Vec<u8>>,
+        sha: impl Into<String>,
+    ) -> CreateOrUpdateFileBuilder<'_> {
+        CreateOrUpdateFileBuilder::new(self, path, message, content).sha(sha)
+    }
+
+    /// Delete a file. `sha` is the blob SHA of the file being deleted.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#delete-a-file>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let file_commit = client
+    ///     .repos("owner", "repo")
+    ///     .delete_file("config.toml", "Remove config", "95b966ae1c166bd92f8ae7d1c313e738c731dfc3")
+    ///     .send();
+    /// ```
+    pub fn delete_file(
+        &self,
+        path: impl Into<String>,
+        message: impl Into<String>,
+        sha: impl Into<String>,
+    ) -> DeleteFileBuilder<'_> {
+        DeleteFileBuilder::new(self, path, message, sha)
+    }
+
+    /// List commits.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#list-commits>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let commits = client
+    ///     .repos("owner", "repo")
+    ///     .commits()
+    ///     .sha("main")
+    ///     .path("src/lib.rs")
+    ///     .since("2021-01-01T00:00:00Z")
+    ///     .per_page(100)
+    ///     .send();
+    /// ```
+    pub fn commits(&self) -> ListCommitsBuilder<'_> {
+        ListCommitsBuilder::new(self)
+    }
+
+    /// Get a commit.
+    ///
+    /// `sha` may also be a branch or a tag name. The commit includes `files` and `stats`.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-a-commit>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let commit = client.repos("owner", "repo").commit("main").send();
+    /// ```
+    pub fn commit(&self, r#ref: impl Into<String>) -> GetCommitBuilder<'_> {
+        GetCommitBuilder::new(self, r#ref)
+    }
+
+    /// Compare two commits.
+    ///
+    /// `base` and `head` may be SHAs, branches or tags, and `head` may be `user:branch` of a fork.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#compare-two-commits>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let comparison = client.repos("owner", "repo").compare("v1.0.0", "main").send();
+    /// ```
+    pub fn compare(
+        &self,
+        base: impl Into<String>,
+        head: impl Into<String>,
+    ) -> CompareCommitsBuilder<'_> {
+        CompareCommitsBuilder::new(self, base, head)
+    }
+
+    /// Create a commit status.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#create-a-commit-status>.
+    /// ```no_run
+    /// use ghrs::model::statuses::StatusState;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let status = client
+    ///     .repos("owner", "repo")
+    ///     .create_status("6dcb09b5b57875f334f61aebed695e2e4193db5e", StatusState::Success)
+    ///     .target_url("https://ci.example.com/builds/1")
+    ///     .description("The build succeeded!")
+    ///     .context("continuous-integration/ci")
+    ///     .send();
+    /// ```
+    pub fn create_status(
+        &self,
+        sha: impl Into<String>,
+        state: StatusState,
+    ) -> CreateStatusBuilder<'_> {
+        CreateStatusBuilder::new(self, sha, state)
+    }
+
+    /// List commit statuses for a reference.
+    ///
+    /// `ref` may be a SHA, a branch or a tag. Statuses are returned in reverse chronological order.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#list-commit-statuses-for-a-reference>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let statuses = client.repos("owner", "repo").list_statuses("main").per_page(100).send();
+    /// ```
+    pub fn list_statuses(&self, r#ref: impl Into<String>) -> ListStatusesBuilder<'_> {
+        ListStatusesBuilder::new(self, r#ref)
+    }
+
+    /// Get the combined status for a specific reference.
+    ///
+    /// `ref` may be a SHA, a branch or a tag.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-the-combined-status-for-a-specific-reference>.
+    /// ```no_run
+    /// use ghrs::model::statuses::StatusState;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let status = client.repos("owner", "repo").combined_status("main").send();
+    /// if let Ok(status) = status {
+    ///     println!("{}", status.state == StatusState::Success);
+    /// }
+    /// ```
+    pub fn combined_status(&self, r#ref: impl Into<String>) -> GetCombinedStatusBuilder<'_> {
+        GetCombinedStatusBuilder::new(self, r#ref)
+    }
+
+    /// List branches.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#list-branches>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let branches = client.repos("owner", "repo").list_branches().protected(true).send();
+    /// ```
+    pub fn list_branches(&self) -> ListBranchesBuilder<'_> {
+        ListBranchesBuilder::new(self)
+    }
+
+    /// Get a branch.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#get-a-branch>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let branch = client.repos("owner", "repo").get_branch("main").send();
+    /// ```
+    pub fn get_branch(&self, branch: impl Into<String>) -> GetBranchBuilder<'_> {
+        GetBranchBuilder::new(self, branch)
+    }
+
+    /// Rename a branch.
+    ///
+    /// Pull requests and branch protection rules of the branch are updated as well.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#rename-a-branch>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let branch = client.repos("owner", "repo").rename_branch("master", "main").send();
+    /// ```
+    pub fn rename_branch(
+        &self,
+        branch: impl Into<String>,
+        new_name: impl Into<String>,
+    ) -> RenameBranchBuilder<'_> {
+        RenameBranchBuilder::new(self, branch, new_name)
+    }
+
+    /// Get branch protection.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#get-branch-protection>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let protection = client.repos("owner", "repo").get_branch_protection("main").send();
+    /// ```
+    pub fn get_branch_protection(
+        &self,
+        branch: impl Into<String>,
+    ) -> GetBranchProtectionBuilder<'_> {
+        GetBranchProtectionBuilder::new(self, branch)
+    }
+
+    /// Update branch protection.
+    ///
+    /// This replaces the whole protection, so status checks, reviews, enforcement for admins and
+    /// restrictions which are not set are disabled.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#update-branch-protection>.
+    /// ```no_run
+    /// use ghrs::model::branches::{NewRequiredReviews, NewRequiredStatusChecks};
+    ///
+    /// let client = ghrs::Client::new();
+    /// let protection = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .update_branch_protection("main")
+    ///     .required_status_checks(NewRequiredStatusChecks::new(true, &["ci/test"]))
+    ///     .required_pull_request_reviews(NewRequiredReviews::new(1))
+    ///     .enforce_admins(true)
+    ///     .required_linear_history(true)
+    ///     .send();
+    /// ```
+    pub fn update_branch_protection(
+        &self,
+        branch: impl Into<String>,
+    ) -> UpdateBranchProtectionBuilder<'_> {
+        UpdateBranchProtectionBuilder::new(self, branch)
+    }
+
+    /// Delete branch protection.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#delete-branch-protection>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").delete_branch_protection("main").send();
+    /// ```
+    pub fn delete_branch_protection(
+        &self,
+        branch: impl Into<String>,
+    ) -> DeleteBranchProtectionBuilder<'_> {
+        DeleteBranchProtectionBuilder::new(self, branch)
+    }
+
+    /// Get rules for a branch.
+    ///
+    /// Returns active rules of all rulesets which apply to the branch, regardless of whether the branch exists.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#get-rules-for-a-branch>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let rules = client.repos("owner", "repo").get_branch_rules("main").send();
+    /// ```
+    pub fn get_branch_rules(&self, branch: impl Into<String>) -> GetBranchRulesBuilder<'_> {
+        GetBranchRulesBuilder::new(self, branch)
+    }
+
+    /// List repository collaborators.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/collaborators#list-repository-collaborators>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let collaborators = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .list_collaborators()
+    ///     .affiliation("direct")
+    ///     .send();
+    /// ```
+    pub fn list_collaborators(&self) -> ListCollaboratorsBuilder<'_> {
+        ListCollaboratorsBuilder::new(self)
+    }
+
+    /// Check if a user is a repository collaborator.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/collaborators#check-if-a-user-is-a-repository-collaborator>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let is_collaborator = client.token("your_token").repos("owner", "repo").is_collaborator("octocat").send();
+    /// ```
+    pub fn is_collaborator(&self, username: impl Into<String>) -> CheckCollaboratorBuilder<'_> {
+        CheckCollaboratorBuilder::new(self, username)
+    }
+
+    /// Add a repository collaborator.
+    ///
+    /// Returns the invitation if one is sent, or `None` if the user became a collaborator directly,
+    /// e.g. because the user is already a collaborator or a member of the organization.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/collaborators#add-a-repository-collaborator>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let invitation = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .add_collaborator("octocat")
+    ///     .permission("maintain")
+    ///     .send();
+    /// ```
+    pub fn add_collaborator(&self, username: impl Into<String>) -> AddCollaboratorBuilder<'_> {
+        AddCollaboratorBuilder::new(self, username)
+    }
+
+    /// Remove a repository collaborator.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/collaborators#remove-a-repository-collaborator>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").remove_collaborator("octocat").send();
+    /// ```
+    pub fn remove_collaborator(
+        &self,
+        username: impl Into<String>,
+    ) -> RemoveCollaboratorBuilder<'_> {
+        RemoveCollaboratorBuilder::new(self, username)
+    }
+
+    /// Get repository permissions for a user.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/collaborators#get-repository-permissions-for-a-user>.
+    /// ```no_run
+    /// use ghrs::model::collaborators::Permission;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let permission = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .get_collaborator_permission("octocat")
+    ///     .send()
+    ///     .unwrap();
+    /// if permission.allows(Permission::Push) {
+    ///     println!("octocat can push");
+    /// }
+    /// ```
+    pub fn get_collaborator_permission(
+        &self,
+        username: impl Into<String>,
+    ) -> GetCollaboratorPermissionBuilder<'_> {
+        GetCollaboratorPermissionBuilder::new(self, username)
+    }
+
+    /// List repository invitations.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#list-repository-invitations>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let invitations = client.token("your_token").repos("owner", "repo").list_invitations().send();
+    /// ```
+    pub fn list_invitations(&self) -> ListInvitationsBuilder<'_> {
+        ListInvitationsBuilder::new(self)
+    }
+
+    /// Update a repository invitation.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#update-a-repository-invitation>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let invitation = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .update_invitation(1)
+    ///     .permissions("write")
+    ///     .send();
+    /// ```
+    pub fn update_invitation(&self, invitation_id: u64) -> UpdateInvitationBuilder<'_> {
+        UpdateInvitationBuilder::new(self, invitation_id)
+    }
+
+    /// Delete a repository invitation.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#delete-a-repository-invitation>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").delete_invitation(1).send();
+    /// ```
+    pub fn delete_invitation(&self, invitation_id: u64) -> DeleteInvitationBuilder<'_> {
+        DeleteInvitationBuilder::new(self, invitation_id)
+    }
+
+    /// Create a [`HooksHandler`] for repository webhooks.
+    pub fn hooks(&self) -> HooksHandler<'a> {
+        HooksHandler::new(self.client, format!("repos/{}/{}", self.owner, self.repo))
+    }
+
+    /// Create a [`RulesetsHandler`] for repository rulesets.
+    pub fn rulesets(&self) -> RulesetsHandler<'a> {
+        RulesetsHandler::new(self.client, format!("repos/{}/{}", self.owner, self.repo))
+    }
+
+    /// Create a [`actions::ActionsHandler`].
+    pub fn actions(&self) -> ActionsHandler<'a> {
+        ActionsHandler::new(self)
+    }
+
+    /// Create a [`checks::ChecksHandler`].
+    pub fn checks(&self) -> ChecksHandler<'a> {
+        ChecksHandler::new(self)
+    }
+
+    /// Create a [`git::GitHandler`].
+    pub fn git(&self) -> GitHandler<'a> {
+        GitHandler::new(self)
+    }
+
+    /// Create a [`releases::ReleasesHandler`].
+    pub fn releases(&self) -> ReleasesHandler<'a> {
+        ReleasesHandler::new(self)
+    }
+}
+
+/// A builder for getting a repository.
+pub struct GetRepositoryBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    accept: Option<String>,
+}
+
+impl<'a> GetRepositoryBuilder<'a> {
+    fn new(handler: &'a ReposHandler) -> Self {
+        GetRepositoryBuilder {
+            handler,
+            accept: None,
+        }
+    }
+
+    /// Get a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repository = client.repos("owner", "repo").get().send();
+    /// ```
+    pub fn send(&self) -> Result<Repository, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let repository: Repository = request.call()?.into_json()?;
+        Ok(repository)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for updating a repository.
+#[derive(Serialize)]
+pub struct UpdateRepositoryBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReposHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_template: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_squash_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_merge_commit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_rebase_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_auto_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_update_branch: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_forking: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_branch_on_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    archived: Option<bool>,
+}
+
+impl<'a> UpdateRepositoryBuilder<'a> {
+    fn new(handler: &'a ReposHandler) -> Self {
+        UpdateRepositoryBuilder {
+            handler,
+            accept: None,
+            name: None,
+            description: None,
+            homepage: None,
+            private: None,
+            visibility: None,
+            has_issues: None,
+            has_projects: None,
+            has_wiki: None,
+            is_template: None,
+            default_branch: None,
+            allow_squash_merge: None,
+            allow_merge_commit: None,
+            allow_rebase_merge: None,
+            allow_auto_merge: None,
+            allow_update_branch: None,
+            allow_forking: None,
+            delete_branch_on_merge: None,
+            archived: None,
+        }
+    }
+
+    /// Update a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#update-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repository = client.repos("owner", "repo").update().delete_branch_on_merge(true).send();
+    /// ```
+    pub fn send(&self) -> Result<Repository, ureq::Error> {
+        let mut request = ureq::patch(&format!(
+            "{}/repos/{}/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let repository: Repository = request.send_json(self)?.into_json()?;
+        Ok(repository)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.homepage = Some(homepage.into());
+        self
+    }
+
+    pub fn private(mut self, private: bool) -> Self {
+        self.private = Some(private);
+        self
+    }
+
+    pub fn visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
+    pub fn has_issues(mut self, has_issues: bool) -> Self {
+        self.has_issues = Some(has_issues);
+        self
+    }
+
+    pub fn has_projects(mut self, has_projects: bool) -> Self {
+        self.has_projects = Some(has_projects);
+        self
+    }
+
+    pub fn has_wiki(mut self, has_wiki: bool) -> Self {
+        self.has_wiki = Some(has_wiki);
+        self
+    }
+
+    pub fn is_template(mut self, is_template: bool) -> Self {
+        self.is_template = Some(is_template);
+        self
+    }
+
+    pub fn default_branch(mut self, default_branch: impl Into<String>) -> Self {
+        self.default_branch = Some(default_branch.into());
+        self
+    }
+
+    pub fn allow_squash_merge(mut self, allow_squash_merge: bool) -> Self {
+        self.allow_squash_merge = Some(allow_squash_merge);
+        self
+    }
+
+    pub fn allow_merge_commit(mut self, allow_merge_commit: bool) -> Self {
+        self.allow_merge_commit = Some(allow_merge_commit);
+        self
+    }
+
+    pub fn allow_rebase_merge(mut self, allow_rebase_merge: bool) -> Self {
+        self.allow_rebase_merge = Some(allow_rebase_merge);
+        self
+    }
+
+    pub fn allow_auto_merge(mut self, allow_auto_merge: bool) -> Self {
+        self.allow_auto_merge = Some(allow_auto_merge);
+        self
+    }
+
+    pub fn allow_update_branch(mut self, allow_update_branch: bool) -> Self {
+        self.allow_update_branch = Some(allow_update_branch);
+        self
+    }
+
+    pub fn allow_forking(mut self, allow_forking: bool) -> Self {
+        self.allow_forking = Some(allow_forking);
+        self
+    }
+
+    pub fn delete_branch_on_merge(mut self, delete_branch_on_merge: bool) -> Self {
+        self.delete_branch_on_merge = Some(delete_branch_on_merge);
+        self
+    }
+
+    pub fn archived(mut self, archived: bool) -> Self {
+        self.archived = Some(archived);
+        self
+    }
+}
+
+/// A builder for deleting a repository.
+pub struct DeleteRepositoryBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    accept: Option<String>,
+}
+
+impl<'a> DeleteRepositoryBuilder<'a> {
+    fn new(handler: &'a ReposHandler) -> Self {
+        DeleteRepositoryBuilder {
+            handler,
+            accept: None,
+        }
+    }
+
+    /// Delete a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#delete-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").delete().send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/repos/{}/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for transferring a repository.
+#[derive(Serialize)]
+pub struct TransferRepositoryBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReposHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    new_owner: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    new_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    team_ids: Option<Vec<u64>>,
+}
+
+impl<'a> TransferRepositoryBuilder<'a> {
+    fn new(handler: &'a ReposHandler, new_owner: impl Into<String>) -> Self {
+        TransferRepositoryBuilder {
+            handler,
+            accept: None,
+            new_owner: new_owner.into(),
+            new_name: None,
+            team_ids: None,
+        }
+    }
+
+    /// Transfer a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#transfer-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repository = client.repos("owner", "repo").transfer("new_owner").send();
+    /// ```
+    pub fn send(&self) -> Result<Repository, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/transfer",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let repository: Repository = request.send_json(self)?.into_json()?;
+        Ok(repository)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn new_name(mut self, new_name: impl Into<String>) -> Self {
+        self.new_name = Some(new_name.into());
+        self
+    }
+
+    pub fn team_ids(mut self, team_ids: impl Into<Vec<u64>>) -> Self {
+        self.team_ids = Some(team_ids.into());
+        self
+    }
+}
+
+/// A builder for listing forks.
+pub struct ListForksBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    accept: Option<String>,
+    sort: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListForksBuilder<'a> {
+    fn new(handler: &'a ReposHandler) -> Self {
+        ListForksBuilder {
+            handler,
+            accept: None,
+            sort: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List forks.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#list-forks>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let forks = client.repos("owner", "repo").list_forks().per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Repository>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/forks",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(sort) = self.sort.clone() {
+            request = request.query("sort", &sort);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let forks = Page::from_response(response)?;
+        Ok(forks)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for creating a fork.
+#[derive(Serialize)]
+pub struct CreateForkBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReposHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    organization: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    default_branch_only: Option<bool>,
+}
+
+impl<'a> CreateForkBuilder<'a> {
+    fn new(handler: &'a ReposHandler) -> Self {
+        CreateForkBuilder {
+            handler,
+            accept: None,
+            organization: None,
+            name: None,
+            default_branch_only: None,
+        }
+    }
+
+    /// Create a fork.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#create-a-fork>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repository = client.repos("owner", "repo").create_fork().send();
+    /// ```
+    pub fn send(&self) -> Result<Repository, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/forks",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let repository: Repository = request.send_json(self)?.into_json()?;
+        Ok(repository)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn organization(mut self, organization: impl Into<String>) -> Self {
+        self.organization = Some(organization.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn default_branch_only(mut self, default_branch_only: bool) -> Self {
+        self.default_branch_only = Some(default_branch_only);
+        self
+    }
+}
+
+/// A builder for getting all repository topics.
+pub struct GetTopicsBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    accept: Option<String>,
+}
+
+impl<'a> GetTopicsBuilder<'a> {
+    fn new(handler: &'a ReposHandler) -> Self {
+        GetTopicsBuilder {
+            handler,
+            accept: None,
+        }
+    }
+
+    /// Get all repository topics.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-all-repository-topics>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let topics = client.repos("owner", "repo").get_topics().send();
+    /// ```
+    pub fn send(&self) -> Result<Topics, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/topics",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let topics: Topics = request.call()?.into_json()?;
+        Ok(topics)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for replacing all repository topics.
+#[derive(Serialize)]
+pub struct ReplaceTopicsBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReposHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    names: Vec<String>,
+}
+
+impl<'a> ReplaceTopicsBuilder<'a> {
+    fn new(handler: &'a ReposHandler, names: impl Into<Vec<String>>) -> Self {
+        ReplaceTopicsBuilder {
+            handler,
+            accept: None,
+            names: names.into(),
+        }
+    }
+
+    /// Replace all repository topics.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#replace-all-repository-topics>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let topics = client.repos("owner", "repo").replace_topics(vec!["rust".to_string()]).send();
+    /// ```
+    pub fn send(&self) -> Result<Topics, ureq::Error> {
+        let mut request = ureq::put(&format!(
+            "{}/repos/{}/{}/topics",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let topics: Topics = request.send_json(self)?.into_json()?;
+        Ok(topics)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A client for repositories of the authenticated user.
+///
+/// See <https://docs.github.com/en/rest/reference/repos>.
+pub struct UserReposHandler<'a> {
+    client: &'a Client,
+}
+
+impl<'a> UserReposHandler<'a> {
+    pub fn new(client: &'a Client) -> UserReposHandler<'a> {
+        UserReposHandler { client }
+    }
+
+    /// List repositories for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#list-repositories-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repositories = client.token("your_token").user_repos().list().affiliation("owner").send();
+    /// ```
+    pub fn list(&self) -> ListUserRepositoriesBuilder<'_> {
+        ListUserRepositoriesBuilder::new(self)
+    }
+
+    /// Create a repository for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#create-a-repository-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repository = client.token("your_token").user_repos().create("repo").private(true).send();
+    /// ```
+    pub fn create(&self, name: impl Into<String>) -> CreateRepositoryBuilder<'_> {
+        CreateRepositoryBuilder::new(self.client, "user/repos".to_string(), name)
+    }
+
+    /// List repository invitations for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#list-repository-invitations-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let invitations = client.token("your_token").user_repos().list_invitations().send();
+    /// ```
+    pub fn list_invitations(&self) -> ListUserInvitationsBuilder<'_> {
+        ListUserInvitationsBuilder::new(self)
+    }
+
+    /// Accept a repository invitation.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#accept-a-repository-invitation>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").user_repos().accept_invitation(1).send();
+    /// ```
+    pub fn accept_invitation(&self, invitation_id: u64) -> AcceptInvitationBuilder<'_> {
+        AcceptInvitationBuilder::new(self, invitation_id)
+    }
+
+    /// Decline a repository invitation.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#decline-a-repository-invitation>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").user_repos().decline_invitation(1).send();
+    /// ```
+    pub fn decline_invitation(&self, invitation_id: u64) -> DeclineInvitationBuilder<'_> {
+        DeclineInvitationBuilder::new(self, invitation_id)
+    }
+}
+
+/// A builder for listing repositories for the authenticated user.
+pub struct ListUserRepositoriesBuilder<'a> {
+    handler: &'a UserReposHandler<'a>,
+    accept: Option<String>,
+    visibility: Option<String>,
+    affiliation: Option<String>,
+    r#type: Option<String>,
+    sort: Option<String>,
+    direction: Option<String>,
+    since: Option<String>,
+    before: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListUserRepositoriesBuilder<'a> {
+    fn new(handler: &'a UserReposHandler) -> Self {
+        ListUserRepositoriesBuilder {
+            handler,
+            accept: None,
+            visibility: None,
+            affiliation: None,
+            r#type: None,
+            sort: None,
+            direction: None,
+            since: None,
+            before: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List repositories for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#list-repositories-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repositories = client.token("your_token").user_repos().list().per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Repository>, ureq::Error> {
+        let mut request = ureq::get(&format!("{}/user/repos", self.handler.client.base_url));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(visibility) = self.visibility.clone() {
+            request = request.query("visibility", &visibility);
+        }
+        if let Some(affiliation) = self.affiliation.clone() {
+            request = request.query("affiliation", &affiliation);
+        }
+        if let Some(r#type) = self.r#type.clone() {
+            request = request.query("type", &r#type);
+        }
+        if let Some(sort) = self.sort.clone() {
+            request = request.query("sort", &sort);
+        }
+        if let Some(direction) = self.direction.clone() {
+            request = request.query("direction", &direction);
+        }
+        if let Some(since) = self.since.clone() {
+            request = request.query("since", &since);
+        }
+        if let Some(before) = self.before.clone() {
+            request = request.query("before", &before);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let repositories = Page::from_response(response)?;
+        Ok(repositories)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
+    pub fn affiliation(mut self, affiliation: impl Into<String>) -> Self {
+        self.affiliation = Some(affiliation.into());
+        self
+    }
+
+    pub fn r#type(mut self, r#type: impl Into<String>) -> Self {
+        self.r#type = Some(r#type.into());
+        self
+    }
+
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    pub fn direction(mut self, direction: impl Into<String>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    pub fn since(mut self, since: impl Into<String>) -> Self {
+        self.since = Some(since.into());
+        self
+    }
+
+    pub fn before(mut self, before: impl Into<String>) -> Self {
+        self.before = Some(before.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A client for repositories of an organization.
+///
+/// See <https://docs.github.com/en/rest/reference/repos>.
+pub struct OrgReposHandler<'a> {
+    client: &'a Client,
+    org: String,
+}
+
+impl<'a> OrgReposHandler<'a> {
+    pub fn new(client: &'a Client, org: impl Into<String>) -> OrgReposHandler<'a> {
+        OrgReposHandler {
+            client,
+            org: org.into(),
+        }
+    }
+
+    /// List organization repositories.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#list-organization-repositories>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repositories = client.org_repos("org").list().per_page(100).send();
+    /// ```
+    pub fn list(&self) -> ListOrgRepositoriesBuilder<'_> {
+        ListOrgRepositoriesBuilder::new(self)
+    }
+
+    /// Create an organization repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#create-an-organization-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repository = client.token("your_token").org_repos("org").create("repo").team_id(1).send();
+    /// ```
+    pub fn create(&self, name: impl Into<String>) -> CreateRepositoryBuilder<'_> {
+        CreateRepositoryBuilder::new(self.client, format!("orgs/{}/repos", self.org), name)
+    }
+}
+
+/// A builder for listing organization repositories.
+pub struct ListOrgRepositoriesBuilder<'a> {
+    handler: &'a OrgReposHandler<'a>,
+    accept: Option<String>,
+    r#type: Option<String>,
+    sort: Option<String>,
+    direction: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListOrgRepositoriesBuilder<'a> {
+    fn new(handler: &'a OrgReposHandler) -> Self {
+        ListOrgRepositoriesBuilder {
+            handler,
+            accept: None,
+            r#type: None,
+            sort: None,
+            direction: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List organization repositories.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#list-organization-repositories>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repositories = client.org_repos("org").list().r#type("sources").per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Repository>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/orgs/{}/repos",
+            self.handler.client.base_url, self.handler.org
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(r#type) = self.r#type.clone() {
+            request = request.query("type", &r#type);
+        }
+        if let Some(sort) = self.sort.clone() {
+            request = request.query("sort", &sort);
+        }
+        if let Some(direction) = self.direction.clone() {
+            request = request.query("direction", &direction);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let repositories = Page::from_response(response)?;
+        Ok(repositories)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn r#type(mut self, r#type: impl Into<String>) -> Self {
+        self.r#type = Some(r#type.into());
+        self
+    }
+
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    pub fn direction(mut self, direction: impl Into<String>) -> Self {
+        self.direction = Some(direction.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for creating a repository for the authenticated user or an organization.
+#[derive(Serialize)]
+pub struct CreateRepositoryBuilder<'a> {
+    #[serde(skip)]
+    client: &'a Client,
+    #[serde(skip)]
+    path: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    homepage: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    private: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_issues: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_projects: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    has_wiki: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    is_template: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    team_id: Option<u64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_init: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    gitignore_template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    license_template: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_squash_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_merge_commit: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_rebase_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_auto_merge: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    delete_branch_on_merge: Option<bool>,
+}
+
+impl<'a> CreateRepositoryBuilder<'a> {
+    fn new(client: &'a Client, path: String, name: impl Into<String>) -> Self {
+        CreateRepositoryBuilder {
+            client,
+            path,
+            accept: None,
+            name: name.into(),
+            description: None,
+            homepage: None,
+            private: None,
+            visibility: None,
+            has_issues: None,
+            has_projects: None,
+            has_wiki: None,
+            is_template: None,
+            team_id: None,
+            auto_init: None,
+            gitignore_template: None,
+            license_template: None,
+            allow_squash_merge: None,
+            allow_merge_commit: None,
+            allow_rebase_merge: None,
+            allow_auto_merge: None,
+            delete_branch_on_merge: None,
+        }
+    }
+
+    /// Create a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#create-a-repository-for-the-authenticated-user>
+    /// and <https://docs.github.com/en/rest/reference/repos#create-an-organization-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let repository = client.token("your_token").user_repos().create("repo").auto_init(true).send();
+    /// ```
+    pub fn send(&self) -> Result<Repository, ureq::Error> {
+        let mut request = ureq::post(&format!("{}/{}", self.client.base_url, self.path));
+
+        if let Some(token) = self.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let repository: Repository = request.send_json(self)?.into_json()?;
+        Ok(repository)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    pub fn homepage(mut self, homepage: impl Into<String>) -> Self {
+        self.homepage = Some(homepage.into());
+        self
+    }
+
+    pub fn private(mut self, private: bool) -> Self {
+        self.private = Some(private);
+        self
+    }
+
+    pub fn visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
+    pub fn has_issues(mut self, has_issues: bool) -> Self {
+        self.has_issues = Some(has_issues);
+        self
+    }
+
+    pub fn has_projects(mut self, has_projects: bool) -> Self {
+        self.has_projects = Some(has_projects);
+        self
+    }
+
+    pub fn has_wiki(mut self, has_wiki: bool) -> Self {
+        self.has_wiki = Some(has_wiki);
+        self
+    }
+
+    pub fn is_template(mut self, is_template: bool) -> Self {
+        self.is_template = Some(is_template);
+        self
+    }
+
+    /// The team which will be granted access. This is only valid for an organization.
+    pub fn team_id(mut self, team_id: u64) -> Self {
+        self.team_id = Some(team_id);
+        self
+    }
+
+    pub fn auto_init(mut self, auto_init: bool) -> Self {
+        self.auto_init = Some(auto_init);
+        self
+    }
+
+    pub fn gitignore_template(mut self, gitignore_template: impl Into<String>) -> Self {
+        self.gitignore_template = Some(gitignore_template.into());
+        self
+    }
+
+    pub fn license_template(mut self, license_template: impl Into<String>) -> Self {
+        self.license_template = Some(license_template.into());
+        self
+    }
+
+    pub fn allow_squash_merge(mut self, allow_squash_merge: bool) -> Self {
+        self.allow_squash_merge = Some(allow_squash_merge);
+        self
+    }
+
+    pub fn allow_merge_commit(mut self, allow_merge_commit: bool) -> Self {
+        self.allow_merge_commit = Some(allow_merge_commit);
+        self
+    }
+
+    pub fn allow_rebase_merge(mut self, allow_rebase_merge: bool) -> Self {
+        self.allow_rebase_merge = Some(allow_rebase_merge);
+        self
+    }
+
+    pub fn allow_auto_merge(mut self, allow_auto_merge: bool) -> Self {
+        self.allow_auto_merge = Some(allow_auto_merge);
+        self
+    }
+
+    pub fn delete_branch_on_merge(mut self, delete_branch_on_merge: bool) -> Self {
+        self.delete_branch_on_merge = Some(delete_branch_on_merge);
+        self
+    }
+}
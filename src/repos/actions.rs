@@ -0,0 +1,1242 @@
+//! The GitHub Actions API
+use super::{read_bytes, ReposHandler};
+use crate::model::actions::{Artifacts, Jobs, WorkflowRun, WorkflowRuns, Workflows};
+use crate::secrets::{SecretsHandler, VariablesHandler};
+use crate::Client;
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// A client for the GitHub Actions API.
+///
+/// See <https://docs.github.com/en/rest/reference/actions>.
+pub struct ActionsHandler<'a> {
+    client: &'a Client,
+    owner: String,
+    repo: String,
+}
+
+impl<'a> ActionsHandler<'a> {
+    pub fn new(handler: &ReposHandler<'a>) -> ActionsHandler<'a> {
+        ActionsHandler {
+            client: handler.client,
+            owner: handler.owner.clone(),
+            repo: handler.repo.clone(),
+        }
+    }
+
+    /// Create a [`SecretsHandler`] for repository secrets.
+    pub fn secrets(&self) -> SecretsHandler<'a> {
+        SecretsHandler::new(
+            self.client,
+            format!("repos/{}/{}/actions", self.owner, self.repo),
+        )
+    }
+
+    /// Create a [`SecretsHandler`] for secrets of an environment.
+    pub fn environment_secrets(&self, environment: impl Into<String>) -> SecretsHandler<'a> {
+        SecretsHandler::new(
+            self.client,
+            format!(
+                "repos/{}/{}/environments/{}",
+                self.owner,
+                self.repo,
+                environment.into()
+            ),
+        )
+    }
+
+    /// Create a [`VariablesHandler`] for repository variables.
+    pub fn variables(&self) -> VariablesHandler<'a> {
+        VariablesHandler::new(
+            self.client,
+            format!("repos/{}/{}/actions", self.owner, self.repo),
+        )
+    }
+
+    /// Create a [`VariablesHandler`] for variables of an environment.
+    pub fn environment_variables(&self, environment: impl Into<String>) -> VariablesHandler<'a> {
+        VariablesHandler::new(
+            self.client,
+            format!(
+                "repos/{}/{}/environments/{}",
+                self.owner,
+                self.repo,
+                environment.into()
+            ),
+        )
+    }
+
+    /// List repository workflows.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-repository-workflows>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let workflows = client.repos("owner", "repo").actions().list_workflows().send();
+    /// ```
+    pub fn list_workflows(&self) -> ListWorkflowsBuilder<'_> {
+        ListWorkflowsBuilder::new(self)
+    }
+
+    /// Create a workflow dispatch event.
+    ///
+    /// `workflow_id` may be the ID or the file name of the workflow, e.g. `main.yml`.
+    /// The workflow must have the `workflow_dispatch` trigger.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#create-a-workflow-dispatch-event>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .dispatch_workflow("deploy.yml", "main")
+    ///     .input("environment", "staging")
+    ///     .send();
+    /// ```
+    pub fn dispatch_workflow(
+        &self,
+        workflow_id: impl Into<String>,
+        r#ref: impl Into<String>,
+    ) -> DispatchWorkflowBuilder<'_> {
+        DispatchWorkflowBuilder::new(self, workflow_id, r#ref)
+    }
+
+    /// List workflow runs for a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-workflow-runs-for-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let runs = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .list_runs()
+    ///     .branch("main")
+    ///     .event("push")
+    ///     .status("failure")
+    ///     .send();
+    /// ```
+    pub fn list_runs(&self) -> ListWorkflowRunsBuilder<'_> {
+        ListWorkflowRunsBuilder::new(self)
+    }
+
+    /// List workflow runs.
+    ///
+    /// `workflow_id` may be the ID or the file name of the workflow, e.g. `main.yml`.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-workflow-runs>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let runs = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .list_workflow_runs("ci.yml")
+    ///     .branch("main")
+    ///     .send();
+    /// ```
+    pub fn list_workflow_runs(
+        &self,
+        workflow_id: impl Into<String>,
+    ) -> ListRunsForWorkflowBuilder<'_> {
+        ListRunsForWorkflowBuilder::new(self, workflow_id)
+    }
+
+    /// Get a workflow run.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#get-a-workflow-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let run = client.repos("owner", "repo").actions().get_run(30433642).send();
+    /// ```
+    pub fn get_run(&self, run_id: u64) -> GetWorkflowRunBuilder<'_> {
+        GetWorkflowRunBuilder::new(self, run_id)
+    }
+
+    /// Re-run a workflow.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#re-run-a-workflow>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().rerun(30433642).send();
+    /// ```
+    pub fn rerun(&self, run_id: u64) -> RerunWorkflowBuilder<'_> {
+        RerunWorkflowBuilder::new(self, run_id)
+    }
+
+    /// Re-run failed jobs from a workflow run.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#re-run-failed-jobs-from-a-workflow-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().rerun_failed_jobs(30433642).send();
+    /// ```
+    pub fn rerun_failed_jobs(&self, run_id: u64) -> RerunFailedJobsBuilder<'_> {
+        RerunFailedJobsBuilder::new(self, run_id)
+    }
+
+    /// Cancel a workflow run.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#cancel-a-workflow-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().cancel_run(30433642).send();
+    /// ```
+    pub fn cancel_run(&self, run_id: u64) -> CancelWorkflowRunBuilder<'_> {
+        CancelWorkflowRunBuilder::new(self, run_id)
+    }
+
+    /// List jobs for a workflow run.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-jobs-for-a-workflow-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let jobs = client.repos("owner", "repo").actions().list_jobs(30433642).filter("all").send();
+    /// ```
+    pub fn list_jobs(&self, run_id: u64) -> ListJobsForRunBuilder<'_> {
+        ListJobsForRunBuilder::new(self, run_id)
+    }
+
+    /// Download workflow run logs.
+    ///
+    /// GitHub redirects to a zip archive, which is followed without sending the token.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#download-workflow-run-logs>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let zip = client.repos("owner", "repo").actions().download_run_logs(30433642).send();
+    /// ```
+    pub fn download_run_logs(&self, run_id: u64) -> DownloadRunLogsBuilder<'_> {
+        DownloadRunLogsBuilder::new(self, run_id)
+    }
+
+    /// Download job logs for a workflow run.
+    ///
+    /// GitHub redirects to the plain text logs, which is followed without sending the token.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#download-job-logs-for-a-workflow-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let logs = client.repos("owner", "repo").actions().download_job_logs(399444496).send();
+    /// ```
+    pub fn download_job_logs(&self, job_id: u64) -> DownloadJobLogsBuilder<'_> {
+        DownloadJobLogsBuilder::new(self, job_id)
+    }
+
+    /// List artifacts for a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-artifacts-for-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let artifacts = client.repos("owner", "repo").actions().list_artifacts().name("coverage").send();
+    /// ```
+    pub fn list_artifacts(&self) -> ListArtifactsBuilder<'_> {
+        ListArtifactsBuilder::new(self)
+    }
+
+    /// List workflow run artifacts.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-workflow-run-artifacts>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let artifacts = client.repos("owner", "repo").actions().list_run_artifacts(30433642).send();
+    /// ```
+    pub fn list_run_artifacts(&self, run_id: u64) -> ListRunArtifactsBuilder<'_> {
+        ListRunArtifactsBuilder::new(self, run_id)
+    }
+
+    /// Download an artifact.
+    ///
+    /// GitHub redirects to a zip archive, which is followed without sending the token.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#download-an-artifact>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let zip = client.repos("owner", "repo").actions().download_artifact(11).send();
+    /// ```
+    pub fn download_artifact(&self, artifact_id: u64) -> DownloadArtifactBuilder<'_> {
+        DownloadArtifactBuilder::new(self, artifact_id)
+    }
+}
+
+/// A builder for listing repository workflows.
+pub struct ListWorkflowsBuilder<'a> {
+    handler: &'a ActionsHandler<'a>,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListWorkflowsBuilder<'a> {
+    fn new(handler: &'a ActionsHandler) -> Self {
+        ListWorkflowsBuilder {
+            handler,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List repository workflows.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-repository-workflows>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let workflows = client.repos("owner", "repo").actions().list_workflows().send();
+    /// ```
+    pub fn send(&self) -> Result<Workflows, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/actions/workflows",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let workflows: Workflows = request.call()?.into_json()?;
+        Ok(workflows)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for creating a workflow dispatch event.
+#[derive(Serialize)]
+pub struct DispatchWorkflowBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ActionsHandler<'a>,
+    #[serde(skip)]
+    workflow_id: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    r#ref: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    inputs: Option<HashMap<String, String>>,
+}
+
+impl<'a> DispatchWorkflowBuilder<'a> {
+    fn new(
+        handler: &'a ActionsHandler,
+        workflow_id: impl Into<String>,
+        r#ref: impl Into<String>,
+    ) -> Self {
+        DispatchWorkflowBuilder {
+            handler,
+            workflow_id: workflow_id.into(),
+            accept: None,
+            r#ref: r#ref.into(),
+            inputs: None,
+        }
+    }
+
+    /// Create a workflow dispatch event.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#create-a-workflow-dispatch-event>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .dispatch_workflow("deploy.yml", "main")
+    ///     .input("environment", "staging")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/actions/workflows/{}/dispatches",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.workflow_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.send_json(self)?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn inputs(mut self, inputs: HashMap<String, String>) -> Self {
+        self.inputs = Some(inputs);
+        self
+    }
+
+    /// Add an input defined in the workflow.
+    pub fn input(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.inputs
+            .get_or_insert_with(HashMap::new)
+            .insert(name.into(), value.into());
+        self
+    }
+}
+
+/// A builder for listing workflow runs for a repository.
+pub struct ListWorkflowRunsBuilder<'a> {
+    handler: &'a ActionsHandler<'a>,
+    accept: Option<String>,
+    actor: Option<String>,
+    branch: Option<String>,
+    event: Option<String>,
+    status: Option<String>,
+    created: Option<String>,
+    head_sha: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListWorkflowRunsBuilder<'a> {
+    fn new(handler: &'a ActionsHandler) -> Self {
+        ListWorkflowRunsBuilder {
+            handler,
+            accept: None,
+            actor: None,
+            branch: None,
+            event: None,
+            status: None,
+            created: None,
+            head_sha: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List workflow runs for a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-workflow-runs-for-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let runs = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .list_runs()
+    ///     .branch("main")
+    ///     .event("push")
+    ///     .status("failure")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<WorkflowRuns, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/actions/runs",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(actor) = self.actor.clone() {
+            request = request.query("actor", &actor);
+        }
+        if let Some(branch) = self.branch.clone() {
+            request = request.query("branch", &branch);
+        }
+        if let Some(event) = self.event.clone() {
+            request = request.query("event", &event);
+        }
+        if let Some(status) = self.status.clone() {
+            request = request.query("status", &status);
+        }
+        if let Some(created) = self.created.clone() {
+            request = request.query("created", &created);
+        }
+        if let Some(head_sha) = self.head_sha.clone() {
+            request = request.query("head_sha", &head_sha);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let workflow_runs: WorkflowRuns = request.call()?.into_json()?;
+        Ok(workflow_runs)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = Some(actor.into());
+        self
+    }
+
+    pub fn branch(mut self, branch: impl Into<String>) -> Self {
+        self.branch = Some(branch.into());
+        self
+    }
+
+    pub fn event(mut self, event: impl Into<String>) -> Self {
+        self.event = Some(event.into());
+        self
+    }
+
+    /// A status like `in_progress` or a conclusion like `failure`.
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// A date range in the search syntax, e.g. `>=2021-01-01`.
+    pub fn created(mut self, created: impl Into<String>) -> Self {
+        self.created = Some(created.into());
+        self
+    }
+
+    pub fn head_sha(mut self, head_sha: impl Into<String>) -> Self {
+        self.head_sha = Some(head_sha.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing workflow runs of a workflow.
+pub struct ListRunsForWorkflowBuilder<'a> {
+    handler: &'a ActionsHandler<'a>,
+    workflow_id: String,
+    accept: Option<String>,
+    actor: Option<String>,
+    branch: Option<String>,
+    event: Option<String>,
+    status: Option<String>,
+    created: Option<String>,
+    head_sha: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListRunsForWorkflowBuilder<'a> {
+    fn new(handler: &'a ActionsHandler, workflow_id: impl Into<String>) -> Self {
+        ListRunsForWorkflowBuilder {
+            handler,
+            workflow_id: workflow_id.into(),
+            accept: None,
+            actor: None,
+            branch: None,
+            event: None,
+            status: None,
+            created: None,
+            head_sha: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List workflow runs.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-workflow-runs>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let runs = client
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .list_workflow_runs("ci.yml")
+    ///     .branch("main")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<WorkflowRuns, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/actions/workflows/{}/runs",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.workflow_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(actor) = self.actor.clone() {
+            request = request.query("actor", &actor);
+        }
+        if let Some(branch) = self.branch.clone() {
+            request = request.query("branch", &branch);
+        }
+        if let Some(event) = self.event.clone() {
+            request = request.query("event", &event);
+        }
+        if let Some(status) = self.status.clone() {
+            request = request.query("status", &status);
+        }
+        if let Some(created) = self.created.clone() {
+            request = request.query("created", &created);
+        }
+        if let Some(head_sha) = self.head_sha.clone() {
+            request = request.query("head_sha", &head_sha);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let workflow_runs: WorkflowRuns = request.call()?.into_json()?;
+        Ok(workflow_runs)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn actor(mut self, actor: impl Into<String>) -> Self {
+        self.actor = Some(actor.into());
+        self
+    }
+
+    pub fn branch(mut self, branch: impl Into<String>) -> Self {
+        self.branch = Some(branch.into());
+        self
+    }
+
+    pub fn event(mut self, event: impl Into<String>) -> Self {
+        self.event = Some(event.into());
+        self
+    }
+
+    /// A status like `in_progress` or a conclusion like `failure`.
+    pub fn status(mut self, status: impl Into<String>) -> Self {
+        self.status = Some(status.into());
+        self
+    }
+
+    /// A date range in the search syntax, e.g. `>=2021-01-01`.
+    pub fn created(mut self, created: impl Into<String>) -> Self {
+        self.created = Some(created.into());
+        self
+    }
+
+    pub fn head_sha(mut self, head_sha: impl Into<String>) -> Self {
+        self.head_sha = Some(head_sha.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting a workflow run.
+pub struct GetWorkflowRunBuilder<'a> {
+    handler: &'a ActionsHandler<'a>,
+    run_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> GetWorkflowRunBuilder<'a> {
+    fn new(handler: &'a ActionsHandler, run_id: u64) -> Self {
+        GetWorkflowRunBuilder {
+            handler,
+            run_id,
+            accept: None,
+        }
+    }
+
+    /// Get a workflow run.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#get-a-workflow-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let run = client.repos("owner", "repo").actions().get_run(30433642).send();
+    /// ```
+    pub fn send(&self) -> Result<WorkflowRun, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/actions/runs/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.run_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let workflow_run: WorkflowRun = request.call()?.into_json()?;
+        Ok(workflow_run)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for re-running a workflow.
+#[derive(Serialize)]
+pub struct RerunWorkflowBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ActionsHandler<'a>,
+    #[serde(skip)]
+    run_id: u64,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_debug_logging: Option<bool>,
+}
+
+impl<'a> RerunWorkflowBuilder<'a> {
+    fn new(handler: &'a ActionsHandler, run_id: u64) -> Self {
+        RerunWorkflowBuilder {
+            handler,
+            run_id,
+            accept: None,
+            enable_debug_logging: None,
+        }
+    }
+
+    /// Re-run a workflow.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#re-run-a-workflow>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().rerun(30433642).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/actions/runs/{}/rerun",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.run_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.send_json(self)?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn enable_debug_logging(mut self, enable_debug_logging: bool) -> Self {
+        self.enable_debug_logging = Some(enable_debug_logging);
+        self
+    }
+}
+
+/// A builder for re-running failed jobs of a workflow run.
+#[derive(Serialize)]
+pub struct RerunFailedJobsBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ActionsHandler<'a>,
+    #[serde(skip)]
+    run_id: u64,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enable_debug_logging: Option<bool>,
+}
+
+impl<'a> RerunFailedJobsBuilder<'a> {
+    fn new(handler: &'a ActionsHandler, run_id: u64) -> Self {
+        RerunFailedJobsBuilder {
+            handler,
+            run_id,
+            accept: None,
+            enable_debug_logging: None,
+        }
+    }
+
+    /// Re-run failed jobs from a workflow run.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#re-run-failed-jobs-from-a-workflow-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().rerun_failed_jobs(30433642).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/actions/runs/{}/rerun-failed-jobs",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.run_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.send_json(self)?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn enable_debug_logging(mut self, enable_debug_logging: bool) -> Self {
+        self.enable_debug_logging = Some(enable_debug_logging);
+        self
+    }
+}
+
+/// A builder for cancelling a workflow run.
+pub struct CancelWorkflowRunBuilder<'a> {
+    handler: &'a ActionsHandler<'a>,
+    run_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> CancelWorkflowRunBuilder<'a> {
+    fn new(handler: &'a ActionsHandler, run_id: u64) -> Self {
+        CancelWorkflowRunBuilder {
+            handler,
+            run_id,
+            accept: None,
+        }
+    }
+
+    /// Cancel a workflow run.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#cancel-a-workflow-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().cancel_run(30433642).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/actions/runs/{}/cancel",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.run_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for listing jobs for a workflow run.
+pub struct ListJobsForRunBuilder<'a> {
+    handler: &'a ActionsHandler<'a>,
+    run_id: u64,
+    accept: Option<String>,
+    filter: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListJobsForRunBuilder<'a> {
+    fn new(handler: &'a ActionsHandler, run_id: u64) -> Self {
+        ListJobsForRunBuilder {
+            handler,
+            run_id,
+            accept: None,
+            filter: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List jobs for a workflow run.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-jobs-for-a-workflow-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let jobs = client.repos("owner", "repo").actions().list_jobs(30433642).filter("all").send();
+    /// ```
+    pub fn send(&self) -> Result<Jobs, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/actions/runs/{}/jobs",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.run_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(filter) = self.filter.clone() {
+            request = request.query("filter", &filter);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let jobs: Jobs = request.call()?.into_json()?;
+        Ok(jobs)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// `latest` (default) to return jobs of the latest attempt, or `all`.
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for downloading logs of a workflow run.
+pub struct DownloadRunLogsBuilder<'a> {
+    handler: &'a ActionsHandler<'a>,
+    run_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> DownloadRunLogsBuilder<'a> {
+    fn new(handler: &'a ActionsHandler, run_id: u64) -> Self {
+        DownloadRunLogsBuilder {
+            handler,
+            run_id,
+            accept: None,
+        }
+    }
+
+    /// Download workflow run logs.
+    ///
+    /// Returns the zip archive of the logs.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#download-workflow-run-logs>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let zip = client.repos("owner", "repo").actions().download_run_logs(30433642).send();
+    /// ```
+    pub fn send(&self) -> Result<Vec<u8>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/actions/runs/{}/logs",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.run_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let response = request.call()?;
+        read_bytes(response)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for downloading logs of a job.
+pub struct DownloadJobLogsBuilder<'a> {
+    handler: &'a ActionsHandler<'a>,
+    job_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> DownloadJobLogsBuilder<'a> {
+    fn new(handler: &'a ActionsHandler, job_id: u64) -> Self {
+        DownloadJobLogsBuilder {
+            handler,
+            job_id,
+            accept: None,
+        }
+    }
+
+    /// Download job logs for a workflow run.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#download-job-logs-for-a-workflow-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let logs = client.repos("owner", "repo").actions().download_job_logs(399444496).send();
+    /// ```
+    pub fn send(&self) -> Result<Vec<u8>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/actions/jobs/{}/logs",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.job_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let response = request.call()?;
+        read_bytes(response)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for listing artifacts for a repository.
+pub struct ListArtifactsBuilder<'a> {
+    handler: &'a ActionsHandler<'a>,
+    accept: Option<String>,
+    name: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListArtifactsBuilder<'a> {
+    fn new(handler: &'a ActionsHandler) -> Self {
+        ListArtifactsBuilder {
+            handler,
+            accept: None,
+            name: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List artifacts for a repository.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-artifacts-for-a-repository>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let artifacts = client.repos("owner", "repo").actions().list_artifacts().name("coverage").send();
+    /// ```
+    pub fn send(&self) -> Result<Artifacts, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/actions/artifacts",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(name) = self.name.clone() {
+            request = request.query("name", &name);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let artifacts: Artifacts = request.call()?.into_json()?;
+        Ok(artifacts)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing artifacts of a workflow run.
+pub struct ListRunArtifactsBuilder<'a> {
+    handler: &'a ActionsHandler<'a>,
+    run_id: u64,
+    accept: Option<String>,
+    name: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListRunArtifactsBuilder<'a> {
+    fn new(handler: &'a ActionsHandler, run_id: u64) -> Self {
+        ListRunArtifactsBuilder {
+            handler,
+            run_id,
+            accept: None,
+            name: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List workflow run artifacts.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-workflow-run-artifacts>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let artifacts = client.repos("owner", "repo").actions().list_run_artifacts(30433642).send();
+    /// ```
+    pub fn send(&self) -> Result<Artifacts, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/actions/runs/{}/artifacts",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.run_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(name) = self.name.clone() {
+            request = request.query("name", &name);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let artifacts: Artifacts = request.call()?.into_json()?;
+        Ok(artifacts)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for downloading an artifact.
+pub struct DownloadArtifactBuilder<'a> {
+    handler: &'a ActionsHandler<'a>,
+    artifact_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> DownloadArtifactBuilder<'a> {
+    fn new(handler: &'a ActionsHandler, artifact_id: u64) -> Self {
+        DownloadArtifactBuilder {
+            handler,
+            artifact_id,
+            accept: None,
+        }
+    }
+
+    /// Download an artifact.
+    ///
+    /// Returns the zip archive of the artifact.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#download-an-artifact>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let zip = client.repos("owner", "repo").actions().download_artifact(11).send();
+    /// ```
+    pub fn send(&self) -> Result<Vec<u8>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/actions/artifacts/{}/zip",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.artifact_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let response = request.call()?;
+        read_bytes(response)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
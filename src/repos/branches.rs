@@ -0,0 +1,495 @@
+//! The Branches API
+use super::ReposHandler;
+use crate::model::branches::{
+    Branch, BranchProtection, NewRequiredReviews, NewRequiredStatusChecks, NewRestrictions,
+};
+use crate::model::rulesets::BranchRule;
+use crate::Page;
+
+use serde::Serialize;
+
+/// A builder for listing branches.
+pub struct ListBranchesBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    accept: Option<String>,
+    protected: Option<bool>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListBranchesBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler) -> Self {
+        ListBranchesBuilder {
+            handler,
+            accept: None,
+            protected: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List branches.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#list-branches>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let branches = client.repos("owner", "repo").list_branches().protected(true).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Branch>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/branches",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(protected) = self.protected {
+            request = request.query("protected", &protected.to_string());
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let branches = Page::from_response(response)?;
+        Ok(branches)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Return only protected branches if `true`, or only unprotected branches if `false`.
+    pub fn protected(mut self, protected: bool) -> Self {
+        self.protected = Some(protected);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting a branch.
+pub struct GetBranchBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    branch: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetBranchBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, branch: impl Into<String>) -> Self {
+        GetBranchBuilder {
+            handler,
+            branch: branch.into(),
+            accept: None,
+        }
+    }
+
+    /// Get a branch.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#get-a-branch>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let branch = client.repos("owner", "repo").get_branch("main").send();
+    /// ```
+    pub fn send(&self) -> Result<Branch, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/branches/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.branch
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let branch: Branch = request.call()?.into_json()?;
+        Ok(branch)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for renaming a branch.
+#[derive(Serialize)]
+pub struct RenameBranchBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReposHandler<'a>,
+    #[serde(skip)]
+    branch: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    new_name: String,
+}
+
+impl<'a> RenameBranchBuilder<'a> {
+    pub(crate) fn new(
+        handler: &'a ReposHandler,
+        branch: impl Into<String>,
+        new_name: impl Into<String>,
+    ) -> Self {
+        RenameBranchBuilder {
+            handler,
+            branch: branch.into(),
+            accept: None,
+            new_name: new_name.into(),
+        }
+    }
+
+    /// Rename a branch.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#rename-a-branch>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let branch = client.repos("owner", "repo").rename_branch("master", "main").send();
+    /// ```
+    pub fn send(&self) -> Result<Branch, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/branches/{}/rename",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.branch
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let branch: Branch = request.send_json(self)?.into_json()?;
+        Ok(branch)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for getting branch protection.
+pub struct GetBranchProtectionBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    branch: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetBranchProtectionBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, branch: impl Into<String>) -> Self {
+        GetBranchProtectionBuilder {
+            handler,
+            branch: branch.into(),
+            accept: None,
+        }
+    }
+
+    /// Get branch protection.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#get-branch-protection>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let protection = client.repos("owner", "repo").get_branch_protection("main").send();
+    /// ```
+    pub fn send(&self) -> Result<BranchProtection, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/branches/{}/protection",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.branch
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let protection: BranchProtection = request.call()?.into_json()?;
+        Ok(protection)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for updating branch protection.
+#[derive(Serialize)]
+pub struct UpdateBranchProtectionBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReposHandler<'a>,
+    #[serde(skip)]
+    branch: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    required_status_checks: Option<NewRequiredStatusChecks>,
+    enforce_admins: Option<bool>,
+    required_pull_request_reviews: Option<NewRequiredReviews>,
+    restrictions: Option<NewRestrictions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_linear_history: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_force_pushes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    allow_deletions: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    required_conversation_resolution: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    block_creations: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    lock_branch: Option<bool>,
+}
+
+impl<'a> UpdateBranchProtectionBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, branch: impl Into<String>) -> Self {
+        UpdateBranchProtectionBuilder {
+            handler,
+            branch: branch.into(),
+            accept: None,
+            required_status_checks: None,
+            enforce_admins: None,
+            required_pull_request_reviews: None,
+            restrictions: None,
+            required_linear_history: None,
+            allow_force_pushes: None,
+            allow_deletions: None,
+            required_conversation_resolution: None,
+            block_creations: None,
+            lock_branch: None,
+        }
+    }
+
+    /// Update branch protection.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#update-branch-protection>.
+    /// ```no_run
+    /// use ghrs::model::branches::{NewRequiredReviews, NewRequiredStatusChecks};
+    ///
+    /// let client = ghrs::Client::new();
+    /// let protection = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .update_branch_protection("main")
+    ///     .required_status_checks(NewRequiredStatusChecks::new(true, &["ci/test"]))
+    ///     .required_pull_request_reviews(NewRequiredReviews::new(1))
+    ///     .enforce_admins(true)
+    ///     .required_linear_history(true)
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<BranchProtection, ureq::Error> {
+        let mut request = ureq::put(&format!(
+            "{}/repos/{}/{}/branches/{}/protection",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.branch
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let protection: BranchProtection = request.send_json(self)?.into_json()?;
+        Ok(protection)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn required_linear_history(mut self, required_linear_history: bool) -> Self {
+        self.required_linear_history = Some(required_linear_history);
+        self
+    }
+
+    pub fn allow_force_pushes(mut self, allow_force_pushes: bool) -> Self {
+        self.allow_force_pushes = Some(allow_force_pushes);
+        self
+    }
+
+    pub fn allow_deletions(mut self, allow_deletions: bool) -> Self {
+        self.allow_deletions = Some(allow_deletions);
+        self
+    }
+
+    pub fn required_conversation_resolution(
+        mut self,
+        required_conversation_resolution: bool,
+    ) -> Self {
+        self.required_conversation_resolution = Some(required_conversation_resolution);
+        self
+    }
+
+    pub fn block_creations(mut self, block_creations: bool) -> Self {
+        self.block_creations = Some(block_creations);
+        self
+    }
+
+    pub fn lock_branch(mut self, lock_branch: bool) -> Self {
+        self.lock_branch = Some(lock_branch);
+        self
+    }
+
+    /// Require status checks to pass before merging.
+    pub fn required_status_checks(
+        mut self,
+        required_status_checks: NewRequiredStatusChecks,
+    ) -> Self {
+        self.required_status_checks = Some(required_status_checks);
+        self
+    }
+
+    /// Enforce the protection for administrators as well.
+    pub fn enforce_admins(mut self, enforce_admins: bool) -> Self {
+        self.enforce_admins = Some(enforce_admins);
+        self
+    }
+
+    /// Require approving reviews before merging.
+    pub fn required_pull_request_reviews(
+        mut self,
+        required_pull_request_reviews: NewRequiredReviews,
+    ) -> Self {
+        self.required_pull_request_reviews = Some(required_pull_request_reviews);
+        self
+    }
+
+    /// Restrict who can push to the branch, which is only available for organization repositories.
+    pub fn restrictions(mut self, restrictions: NewRestrictions) -> Self {
+        self.restrictions = Some(restrictions);
+        self
+    }
+}
+
+/// A builder for deleting branch protection.
+pub struct DeleteBranchProtectionBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    branch: String,
+    accept: Option<String>,
+}
+
+impl<'a> DeleteBranchProtectionBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, branch: impl Into<String>) -> Self {
+        DeleteBranchProtectionBuilder {
+            handler,
+            branch: branch.into(),
+            accept: None,
+        }
+    }
+
+    /// Delete branch protection.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/branches#delete-branch-protection>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").delete_branch_protection("main").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/repos/{}/{}/branches/{}/protection",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.branch
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for getting rules for a branch.
+pub struct GetBranchRulesBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    branch: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> GetBranchRulesBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, branch: impl Into<String>) -> Self {
+        GetBranchRulesBuilder {
+            handler,
+            branch: branch.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Get rules for a branch.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#get-rules-for-a-branch>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let rules = client.repos("owner", "repo").get_branch_rules("main").send();
+    /// ```
+    pub fn send(&self) -> Result<Page<BranchRule>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/rules/branches/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.branch
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let rules = Page::from_response(response)?;
+        Ok(rules)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
@@ -0,0 +1,768 @@
+//! The Checks API
+use super::ReposHandler;
+use crate::model::checks::{
+    CheckAnnotation, CheckConclusion, CheckRun, CheckRuns, CheckStatus, CheckSuite,
+    NewCheckRunOutput,
+};
+use crate::{Client, Page};
+
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Serializer};
+
+/// A client for the Checks API.
+///
+/// See <https://docs.github.com/en/rest/reference/checks>.
+pub struct ChecksHandler<'a> {
+    client: &'a Client,
+    owner: String,
+    repo: String,
+}
+
+impl<'a> ChecksHandler<'a> {
+    pub fn new(handler: &ReposHandler<'a>) -> ChecksHandler<'a> {
+        ChecksHandler {
+            client: handler.client,
+            owner: handler.owner.clone(),
+            repo: handler.repo.clone(),
+        }
+    }
+
+    /// Create a check run.
+    ///
+    /// If the output has more than 50 annotations, the rest are added by updating the check run,
+    /// as GitHub accepts at most 50 annotations per request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#create-a-check-run>.
+    /// ```no_run
+    /// use ghrs::model::checks::{
+    ///     AnnotationLevel, CheckAnnotation, CheckConclusion, CheckStatus, NewCheckRunOutput,
+    /// };
+    ///
+    /// let client = ghrs::Client::new();
+    /// let check_run = client
+    ///     .repos("owner", "repo")
+    ///     .checks()
+    ///     .create_run("lint", "ce587453ced02b1526dfb4cb910479d431683101")
+    ///     .status(CheckStatus::Completed)
+    ///     .conclusion(CheckConclusion::Failure)
+    ///     .output(
+    ///         NewCheckRunOutput::new("Lint", "1 warning").annotations(vec![CheckAnnotation::new(
+    ///             "src/lib.rs",
+    ///             1,
+    ///             1,
+    ///             AnnotationLevel::Warning,
+    ///             "unused import",
+    ///         )]),
+    ///     )
+    ///     .send();
+    /// ```
+    pub fn create_run(
+        &self,
+        name: impl Into<String>,
+        head_sha: impl Into<String>,
+    ) -> CreateCheckRunBuilder<'_> {
+        CreateCheckRunBuilder::new(self, name, head_sha)
+    }
+
+    /// Update a check run.
+    ///
+    /// If the output has more than 50 annotations, the rest are added by updating the check run,
+    /// as GitHub accepts at most 50 annotations per request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#update-a-check-run>.
+    /// ```no_run
+    /// use ghrs::model::checks::CheckConclusion;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let check_run = client
+    ///     .repos("owner", "repo")
+    ///     .checks()
+    ///     .update_run(4)
+    ///     .conclusion(CheckConclusion::Success)
+    ///     .send();
+    /// ```
+    pub fn update_run(&self, check_run_id: u64) -> UpdateCheckRunBuilder<'_> {
+        UpdateCheckRunBuilder::new(self, check_run_id)
+    }
+
+    /// Get a check run.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#get-a-check-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let check_run = client.repos("owner", "repo").checks().get_run(4).send();
+    /// ```
+    pub fn get_run(&self, check_run_id: u64) -> GetCheckRunBuilder<'_> {
+        GetCheckRunBuilder::new(self, check_run_id)
+    }
+
+    /// List check run annotations.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#list-check-run-annotations>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let annotations = client.repos("owner", "repo").checks().list_annotations(4).send();
+    /// ```
+    pub fn list_annotations(&self, check_run_id: u64) -> ListCheckRunAnnotationsBuilder<'_> {
+        ListCheckRunAnnotationsBuilder::new(self, check_run_id)
+    }
+
+    /// List check runs for a Git reference.
+    ///
+    /// `ref` may be a SHA, a branch or a tag.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#list-check-runs-for-a-git-reference>.
+    /// ```no_run
+    /// use ghrs::model::checks::CheckStatus;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let check_runs = client
+    ///     .repos("owner", "repo")
+    ///     .checks()
+    ///     .list_runs_for_ref("main")
+    ///     .check_name("lint")
+    ///     .status(CheckStatus::Completed)
+    ///     .send();
+    /// ```
+    pub fn list_runs_for_ref(&self, r#ref: impl Into<String>) -> ListCheckRunsForRefBuilder<'_> {
+        ListCheckRunsForRefBuilder::new(self, r#ref)
+    }
+
+    /// Get a check suite.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#get-a-check-suite>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let check_suite = client.repos("owner", "repo").checks().get_suite(5).send();
+    /// ```
+    pub fn get_suite(&self, check_suite_id: u64) -> GetCheckSuiteBuilder<'_> {
+        GetCheckSuiteBuilder::new(self, check_suite_id)
+    }
+
+    /// Rerequest a check suite.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#rerequest-a-check-suite>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").checks().rerequest_suite(5).send();
+    /// ```
+    pub fn rerequest_suite(&self, check_suite_id: u64) -> RerequestCheckSuiteBuilder<'_> {
+        RerequestCheckSuiteBuilder::new(self, check_suite_id)
+    }
+
+    fn add_annotations(
+        &self,
+        mut check_run: CheckRun,
+        output: Option<&NewCheckRunOutput>,
+    ) -> Result<CheckRun, ureq::Error> {
+        if let Some(output) = output {
+            for batch in output.batches().into_iter().skip(1) {
+                check_run = self.update_run(check_run.id).output(batch).send()?;
+            }
+        }
+        Ok(check_run)
+    }
+}
+
+// Only the first batch of annotations is sent with the output, see `ChecksHandler::add_annotations`.
+fn serialize_first_batch<S>(
+    output: &Option<NewCheckRunOutput>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    output
+        .as_ref()
+        .and_then(|output| output.batches().into_iter().next())
+        .serialize(serializer)
+}
+
+/// A builder for creating a check run.
+#[derive(Serialize)]
+pub struct CreateCheckRunBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ChecksHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    name: String,
+    head_sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<CheckStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    started_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conclusion: Option<CheckConclusion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    completed_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_first_batch")]
+    output: Option<NewCheckRunOutput>,
+}
+
+impl<'a> CreateCheckRunBuilder<'a> {
+    fn new(
+        handler: &'a ChecksHandler,
+        name: impl Into<String>,
+        head_sha: impl Into<String>,
+    ) -> Self {
+        CreateCheckRunBuilder {
+            handler,
+            accept: None,
+            name: name.into(),
+            head_sha: head_sha.into(),
+            details_url: None,
+            external_id: None,
+            status: None,
+            started_at: None,
+            conclusion: None,
+            completed_at: None,
+            output: None,
+        }
+    }
+
+    /// Create a check run.
+    ///
+    /// If the output has more than 50 annotations, the rest are added by updating the check run,
+    /// as GitHub accepts at most 50 annotations per request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#create-a-check-run>.
+    /// ```no_run
+    /// use ghrs::model::checks::{
+    ///     AnnotationLevel, CheckAnnotation, CheckConclusion, CheckStatus, NewCheckRunOutput,
+    /// };
+    ///
+    /// let client = ghrs::Client::new();
+    /// let check_run = client
+    ///     .repos("owner", "repo")
+    ///     .checks()
+    ///     .create_run("lint", "ce587453ced02b1526dfb4cb910479d431683101")
+    ///     .status(CheckStatus::Completed)
+    ///     .conclusion(CheckConclusion::Failure)
+    ///     .output(
+    ///         NewCheckRunOutput::new("Lint", "1 warning").annotations(vec![CheckAnnotation::new(
+    ///             "src/lib.rs",
+    ///             1,
+    ///             1,
+    ///             AnnotationLevel::Warning,
+    ///             "unused import",
+    ///         )]),
+    ///     )
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<CheckRun, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/check-runs",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let check_run: CheckRun = request.send_json(self)?.into_json()?;
+        self.handler
+            .add_annotations(check_run, self.output.as_ref())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn details_url(mut self, details_url: impl Into<String>) -> Self {
+        self.details_url = Some(details_url.into());
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    pub fn status(mut self, status: CheckStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn started_at(mut self, started_at: DateTime<Utc>) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+
+    /// Setting a conclusion also sets the status to `completed`.
+    pub fn conclusion(mut self, conclusion: CheckConclusion) -> Self {
+        self.conclusion = Some(conclusion);
+        self
+    }
+
+    pub fn completed_at(mut self, completed_at: DateTime<Utc>) -> Self {
+        self.completed_at = Some(completed_at);
+        self
+    }
+
+    pub fn output(mut self, output: NewCheckRunOutput) -> Self {
+        self.output = Some(output);
+        self
+    }
+}
+
+/// A builder for updating a check run.
+#[derive(Serialize)]
+pub struct UpdateCheckRunBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ChecksHandler<'a>,
+    #[serde(skip)]
+    check_run_id: u64,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    details_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    external_id: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    status: Option<CheckStatus>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    started_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conclusion: Option<CheckConclusion>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    completed_at: Option<DateTime<Utc>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(serialize_with = "serialize_first_batch")]
+    output: Option<NewCheckRunOutput>,
+}
+
+impl<'a> UpdateCheckRunBuilder<'a> {
+    fn new(handler: &'a ChecksHandler, check_run_id: u64) -> Self {
+        UpdateCheckRunBuilder {
+            handler,
+            check_run_id,
+            accept: None,
+            name: None,
+            details_url: None,
+            external_id: None,
+            status: None,
+            started_at: None,
+            conclusion: None,
+            completed_at: None,
+            output: None,
+        }
+    }
+
+    /// Update a check run.
+    ///
+    /// If the output has more than 50 annotations, the rest are added by updating the check run,
+    /// as GitHub accepts at most 50 annotations per request.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#update-a-check-run>.
+    /// ```no_run
+    /// use ghrs::model::checks::CheckConclusion;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let check_run = client
+    ///     .repos("owner", "repo")
+    ///     .checks()
+    ///     .update_run(4)
+    ///     .conclusion(CheckConclusion::Success)
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<CheckRun, ureq::Error> {
+        let mut request = ureq::patch(&format!(
+            "{}/repos/{}/{}/check-runs/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.check_run_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let check_run: CheckRun = request.send_json(self)?.into_json()?;
+        self.handler
+            .add_annotations(check_run, self.output.as_ref())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn details_url(mut self, details_url: impl Into<String>) -> Self {
+        self.details_url = Some(details_url.into());
+        self
+    }
+
+    pub fn external_id(mut self, external_id: impl Into<String>) -> Self {
+        self.external_id = Some(external_id.into());
+        self
+    }
+
+    pub fn status(mut self, status: CheckStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    pub fn started_at(mut self, started_at: DateTime<Utc>) -> Self {
+        self.started_at = Some(started_at);
+        self
+    }
+
+    /// Setting a conclusion also sets the status to `completed`.
+    pub fn conclusion(mut self, conclusion: CheckConclusion) -> Self {
+        self.conclusion = Some(conclusion);
+        self
+    }
+
+    pub fn completed_at(mut self, completed_at: DateTime<Utc>) -> Self {
+        self.completed_at = Some(completed_at);
+        self
+    }
+
+    pub fn output(mut self, output: NewCheckRunOutput) -> Self {
+        self.output = Some(output);
+        self
+    }
+}
+
+/// A builder for getting a check run.
+pub struct GetCheckRunBuilder<'a> {
+    handler: &'a ChecksHandler<'a>,
+    check_run_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> GetCheckRunBuilder<'a> {
+    fn new(handler: &'a ChecksHandler, check_run_id: u64) -> Self {
+        GetCheckRunBuilder {
+            handler,
+            check_run_id,
+            accept: None,
+        }
+    }
+
+    /// Get a check run.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#get-a-check-run>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let check_run = client.repos("owner", "repo").checks().get_run(4).send();
+    /// ```
+    pub fn send(&self) -> Result<CheckRun, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/check-runs/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.check_run_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let check_run: CheckRun = request.call()?.into_json()?;
+        Ok(check_run)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for listing check run annotations.
+pub struct ListCheckRunAnnotationsBuilder<'a> {
+    handler: &'a ChecksHandler<'a>,
+    check_run_id: u64,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListCheckRunAnnotationsBuilder<'a> {
+    fn new(handler: &'a ChecksHandler, check_run_id: u64) -> Self {
+        ListCheckRunAnnotationsBuilder {
+            handler,
+            check_run_id,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List check run annotations.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#list-check-run-annotations>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let annotations = client.repos("owner", "repo").checks().list_annotations(4).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<CheckAnnotation>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/check-runs/{}/annotations",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.check_run_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let annotations = Page::from_response(response)?;
+        Ok(annotations)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for listing check runs for a reference.
+pub struct ListCheckRunsForRefBuilder<'a> {
+    handler: &'a ChecksHandler<'a>,
+    r#ref: String,
+    accept: Option<String>,
+    check_name: Option<String>,
+    status: Option<CheckStatus>,
+    filter: Option<String>,
+    app_id: Option<u64>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListCheckRunsForRefBuilder<'a> {
+    fn new(handler: &'a ChecksHandler, r#ref: impl Into<String>) -> Self {
+        ListCheckRunsForRefBuilder {
+            handler,
+            r#ref: r#ref.into(),
+            accept: None,
+            check_name: None,
+            status: None,
+            filter: None,
+            app_id: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List check runs for a Git reference.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#list-check-runs-for-a-git-reference>.
+    /// ```no_run
+    /// use ghrs::model::checks::CheckStatus;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let check_runs = client
+    ///     .repos("owner", "repo")
+    ///     .checks()
+    ///     .list_runs_for_ref("main")
+    ///     .check_name("lint")
+    ///     .status(CheckStatus::Completed)
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<CheckRuns, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/commits/{}/check-runs",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.r#ref
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(check_name) = self.check_name.clone() {
+            request = request.query("check_name", &check_name);
+        }
+        if let Some(status) = self.status {
+            request = request.query("status", &status.to_string());
+        }
+        if let Some(filter) = self.filter.clone() {
+            request = request.query("filter", &filter);
+        }
+        if let Some(app_id) = self.app_id {
+            request = request.query("app_id", &app_id.to_string());
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let check_runs: CheckRuns = request.call()?.into_json()?;
+        Ok(check_runs)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn check_name(mut self, check_name: impl Into<String>) -> Self {
+        self.check_name = Some(check_name.into());
+        self
+    }
+
+    pub fn status(mut self, status: CheckStatus) -> Self {
+        self.status = Some(status);
+        self
+    }
+
+    /// `latest` (default) to return only the most recent check runs, or `all`.
+    pub fn filter(mut self, filter: impl Into<String>) -> Self {
+        self.filter = Some(filter.into());
+        self
+    }
+
+    pub fn app_id(mut self, app_id: u64) -> Self {
+        self.app_id = Some(app_id);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting a check suite.
+pub struct GetCheckSuiteBuilder<'a> {
+    handler: &'a ChecksHandler<'a>,
+    check_suite_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> GetCheckSuiteBuilder<'a> {
+    fn new(handler: &'a ChecksHandler, check_suite_id: u64) -> Self {
+        GetCheckSuiteBuilder {
+            handler,
+            check_suite_id,
+            accept: None,
+        }
+    }
+
+    /// Get a check suite.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#get-a-check-suite>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let check_suite = client.repos("owner", "repo").checks().get_suite(5).send();
+    /// ```
+    pub fn send(&self) -> Result<CheckSuite, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/check-suites/{}",
+            self.handler.client.base_url,
+            self.handler.owner,
+            self.handler.repo,
+            self.check_suite_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let check_suite: CheckSuite = request.call()?.into_json()?;
+        Ok(check_suite)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for rerequesting a check suite.
+pub struct RerequestCheckSuiteBuilder<'a> {
+    handler: &'a ChecksHandler<'a>,
+    check_suite_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> RerequestCheckSuiteBuilder<'a> {
+    fn new(handler: &'a ChecksHandler, check_suite_id: u64) -> Self {
+        RerequestCheckSuiteBuilder {
+            handler,
+            check_suite_id,
+            accept: None,
+        }
+    }
+
+    /// Rerequest a check suite.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/checks#rerequest-a-check-suite>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").checks().rerequest_suite(5).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/check-suites/{}/rerequest",
+            self.handler.client.base_url,
+            self.handler.owner,
+            self.handler.repo,
+            self.check_suite_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
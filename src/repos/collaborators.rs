@@ -0,0 +1,644 @@
+//! The Collaborators API
+use super::{ReposHandler, UserReposHandler};
+use crate::model::collaborators::{Collaborator, Invitation, RepositoryPermission};
+use crate::Page;
+
+use serde::Serialize;
+
+/// A builder for listing repository collaborators.
+pub struct ListCollaboratorsBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    accept: Option<String>,
+    affiliation: Option<String>,
+    permission: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListCollaboratorsBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler) -> Self {
+        ListCollaboratorsBuilder {
+            handler,
+            accept: None,
+            affiliation: None,
+            permission: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List repository collaborators.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/collaborators#list-repository-collaborators>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let collaborators = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .list_collaborators()
+    ///     .affiliation("direct")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Collaborator>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/collaborators",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(affiliation) = self.affiliation.clone() {
+            request = request.query("affiliation", &affiliation);
+        }
+        if let Some(permission) = self.permission.clone() {
+            request = request.query("permission", &permission);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let collaborators = Page::from_response(response)?;
+        Ok(collaborators)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// `outside`, `direct` or `all` by default.
+    pub fn affiliation(mut self, affiliation: impl Into<String>) -> Self {
+        self.affiliation = Some(affiliation.into());
+        self
+    }
+
+    /// Return only collaborators with the permission, e.g. `push`.
+    pub fn permission(mut self, permission: impl Into<String>) -> Self {
+        self.permission = Some(permission.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for checking if a user is a collaborator.
+pub struct CheckCollaboratorBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    username: String,
+    accept: Option<String>,
+}
+
+impl<'a> CheckCollaboratorBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, username: impl Into<String>) -> Self {
+        CheckCollaboratorBuilder {
+            handler,
+            username: username.into(),
+            accept: None,
+        }
+    }
+
+    /// Check if a user is a repository collaborator.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/collaborators#check-if-a-user-is-a-repository-collaborator>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let is_collaborator = client.token("your_token").repos("owner", "repo").is_collaborator("octocat").send();
+    /// ```
+    pub fn send(&self) -> Result<bool, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/collaborators/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.username
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        match request.call() {
+            Ok(_) => Ok(true),
+            Err(ureq::Error::Status(404, _)) => Ok(false),
+            Err(error) => Err(error),
+        }
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for adding a repository collaborator.
+#[derive(Serialize)]
+pub struct AddCollaboratorBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReposHandler<'a>,
+    #[serde(skip)]
+    username: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permission: Option<String>,
+}
+
+impl<'a> AddCollaboratorBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, username: impl Into<String>) -> Self {
+        AddCollaboratorBuilder {
+            handler,
+            username: username.into(),
+            accept: None,
+            permission: None,
+        }
+    }
+
+    /// Add a repository collaborator.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/collaborators#add-a-repository-collaborator>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let invitation = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .add_collaborator("octocat")
+    ///     .permission("maintain")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Option<Invitation>, ureq::Error> {
+        let mut request = ureq::put(&format!(
+            "{}/repos/{}/{}/collaborators/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.username
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let response = request.send_json(self)?;
+        if response.status() == 201 {
+            let invitation: Invitation = response.into_json()?;
+            Ok(Some(invitation))
+        } else {
+            Ok(None)
+        }
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// `pull`, `triage`, `push` by default, `maintain`, `admin` or the name of a custom role.
+    pub fn permission(mut self, permission: impl Into<String>) -> Self {
+        self.permission = Some(permission.into());
+        self
+    }
+}
+
+/// A builder for removing a repository collaborator.
+pub struct RemoveCollaboratorBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    username: String,
+    accept: Option<String>,
+}
+
+impl<'a> RemoveCollaboratorBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, username: impl Into<String>) -> Self {
+        RemoveCollaboratorBuilder {
+            handler,
+            username: username.into(),
+            accept: None,
+        }
+    }
+
+    /// Remove a repository collaborator.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/collaborators#remove-a-repository-collaborator>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").remove_collaborator("octocat").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/repos/{}/{}/collaborators/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.username
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for getting repository permissions for a user.
+pub struct GetCollaboratorPermissionBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    username: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetCollaboratorPermissionBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, username: impl Into<String>) -> Self {
+        GetCollaboratorPermissionBuilder {
+            handler,
+            username: username.into(),
+            accept: None,
+        }
+    }
+
+    /// Get repository permissions for a user.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/collaborators#get-repository-permissions-for-a-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let permission = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .get_collaborator_permission("octocat")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<RepositoryPermission, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/collaborators/{}/permission",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.username
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let permission: RepositoryPermission = request.call()?.into_json()?;
+        Ok(permission)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for listing repository invitations.
+pub struct ListInvitationsBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListInvitationsBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler) -> Self {
+        ListInvitationsBuilder {
+            handler,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List repository invitations.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#list-repository-invitations>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let invitations = client.token("your_token").repos("owner", "repo").list_invitations().send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Invitation>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/invitations",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let invitations = Page::from_response(response)?;
+        Ok(invitations)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for updating a repository invitation.
+#[derive(Serialize)]
+pub struct UpdateInvitationBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReposHandler<'a>,
+    #[serde(skip)]
+    invitation_id: u64,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    permissions: Option<String>,
+}
+
+impl<'a> UpdateInvitationBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, invitation_id: u64) -> Self {
+        UpdateInvitationBuilder {
+            handler,
+            invitation_id,
+            accept: None,
+            permissions: None,
+        }
+    }
+
+    /// Update a repository invitation.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#update-a-repository-invitation>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let invitation = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .update_invitation(1)
+    ///     .permissions("write")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Invitation, ureq::Error> {
+        let mut request = ureq::patch(&format!(
+            "{}/repos/{}/{}/invitations/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.invitation_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let invitation: Invitation = request.send_json(self)?.into_json()?;
+        Ok(invitation)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// `read`, `write`, `maintain`, `triage` or `admin`.
+    pub fn permissions(mut self, permissions: impl Into<String>) -> Self {
+        self.permissions = Some(permissions.into());
+        self
+    }
+}
+
+/// A builder for deleting a repository invitation.
+pub struct DeleteInvitationBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    invitation_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> DeleteInvitationBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, invitation_id: u64) -> Self {
+        DeleteInvitationBuilder {
+            handler,
+            invitation_id,
+            accept: None,
+        }
+    }
+
+    /// Delete a repository invitation.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#delete-a-repository-invitation>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").repos("owner", "repo").delete_invitation(1).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/repos/{}/{}/invitations/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.invitation_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for listing repository invitations for the authenticated user.
+pub struct ListUserInvitationsBuilder<'a> {
+    handler: &'a UserReposHandler<'a>,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListUserInvitationsBuilder<'a> {
+    pub(crate) fn new(handler: &'a UserReposHandler) -> Self {
+        ListUserInvitationsBuilder {
+            handler,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List repository invitations for the authenticated user.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#list-repository-invitations-for-the-authenticated-user>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let invitations = client.token("your_token").user_repos().list_invitations().send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Invitation>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/user/repository_invitations",
+            self.handler.client.base_url
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let invitations = Page::from_response(response)?;
+        Ok(invitations)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for accepting a repository invitation.
+pub struct AcceptInvitationBuilder<'a> {
+    handler: &'a UserReposHandler<'a>,
+    invitation_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> AcceptInvitationBuilder<'a> {
+    pub(crate) fn new(handler: &'a UserReposHandler, invitation_id: u64) -> Self {
+        AcceptInvitationBuilder {
+            handler,
+            invitation_id,
+            accept: None,
+        }
+    }
+
+    /// Accept a repository invitation.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#accept-a-repository-invitation>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").user_repos().accept_invitation(1).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::patch(&format!(
+            "{}/user/repository_invitations/{}",
+            self.handler.client.base_url, self.invitation_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for declining a repository invitation.
+pub struct DeclineInvitationBuilder<'a> {
+    handler: &'a UserReposHandler<'a>,
+    invitation_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> DeclineInvitationBuilder<'a> {
+    pub(crate) fn new(handler: &'a UserReposHandler, invitation_id: u64) -> Self {
+        DeclineInvitationBuilder {
+            handler,
+            invitation_id,
+            accept: None,
+        }
+    }
+
+    /// Decline a repository invitation.
+    ///
+    /// See <https://docs.github.com/en/rest/collaborators/invitations#decline-a-repository-invitation>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.token("your_token").user_repos().decline_invitation(1).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/user/repository_invitations/{}",
+            self.handler.client.base_url, self.invitation_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
@@ -0,0 +1,256 @@
+//! The Commits API
+use super::ReposHandler;
+use crate::model::commits::{Comparison, RepoCommit};
+use crate::Page;
+
+/// A builder for listing commits.
+pub struct ListCommitsBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    accept: Option<String>,
+    sha: Option<String>,
+    path: Option<String>,
+    author: Option<String>,
+    committer: Option<String>,
+    since: Option<String>,
+    until: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListCommitsBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler) -> Self {
+        ListCommitsBuilder {
+            handler,
+            accept: None,
+            sha: None,
+            path: None,
+            author: None,
+            committer: None,
+            since: None,
+            until: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List commits.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#list-commits>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let commits = client.repos("owner", "repo").commits().author("octocat").send();
+    /// ```
+    pub fn send(&self) -> Result<Page<RepoCommit>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/commits",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(sha) = self.sha.clone() {
+            request = request.query("sha", &sha);
+        }
+        if let Some(path) = self.path.clone() {
+            request = request.query("path", &path);
+        }
+        if let Some(author) = self.author.clone() {
+            request = request.query("author", &author);
+        }
+        if let Some(committer) = self.committer.clone() {
+            request = request.query("committer", &committer);
+        }
+        if let Some(since) = self.since.clone() {
+            request = request.query("since", &since);
+        }
+        if let Some(until) = self.until.clone() {
+            request = request.query("until", &until);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let commits = Page::from_response(response)?;
+        Ok(commits)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// SHA or branch to start listing commits from.
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.sha = Some(sha.into());
+        self
+    }
+
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// GitHub login or email address.
+    pub fn author(mut self, author: impl Into<String>) -> Self {
+        self.author = Some(author.into());
+        self
+    }
+
+    /// GitHub login or email address.
+    pub fn committer(mut self, committer: impl Into<String>) -> Self {
+        self.committer = Some(committer.into());
+        self
+    }
+
+    /// ISO 8601 timestamp like `2021-01-01T00:00:00Z`.
+    pub fn since(mut self, since: impl Into<String>) -> Self {
+        self.since = Some(since.into());
+        self
+    }
+
+    /// ISO 8601 timestamp like `2021-01-01T00:00:00Z`.
+    pub fn until(mut self, until: impl Into<String>) -> Self {
+        self.until = Some(until.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting a commit.
+pub struct GetCommitBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    r#ref: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetCommitBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, r#ref: impl Into<String>) -> Self {
+        GetCommitBuilder {
+            handler,
+            r#ref: r#ref.into(),
+            accept: None,
+        }
+    }
+
+    /// Get a commit.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-a-commit>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let commit = client.repos("owner", "repo").commit("main").send();
+    /// ```
+    pub fn send(&self) -> Result<RepoCommit, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/commits/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.r#ref
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let commit: RepoCommit = request.call()?.into_json()?;
+        Ok(commit)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for comparing two commits.
+pub struct CompareCommitsBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    base: String,
+    head: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> CompareCommitsBuilder<'a> {
+    pub(crate) fn new(
+        handler: &'a ReposHandler,
+        base: impl Into<String>,
+        head: impl Into<String>,
+    ) -> Self {
+        CompareCommitsBuilder {
+            handler,
+            base: base.into(),
+            head: head.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Compare two commits.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#compare-two-commits>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let comparison = client.repos("owner", "repo").compare("v1.0.0", "main").send();
+    /// ```
+    pub fn send(&self) -> Result<Comparison, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/compare/{}...{}",
+            self.handler.client.base_url,
+            self.handler.owner,
+            self.handler.repo,
+            self.base,
+            self.head
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let comparison: Comparison = request.call()?.into_json()?;
+        Ok(comparison)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
@@ -0,0 +1,368 @@
+//! The Repository Contents API
+use super::{read_bytes, ReposHandler};
+use crate::model::contents::{Content, ContentFile, FileCommit};
+use crate::model::git::CommitAuthor;
+use crate::model::serialize_base64;
+
+use serde::Serialize;
+
+const RAW_MEDIA_TYPE: &str = "application/vnd.github.raw";
+
+/// A builder for getting repository content.
+pub struct GetContentBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    path: String,
+    accept: Option<String>,
+    r#ref: Option<String>,
+}
+
+impl<'a> GetContentBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, path: impl Into<String>) -> Self {
+        GetContentBuilder {
+            handler,
+            path: path.into(),
+            accept: None,
+            r#ref: None,
+        }
+    }
+
+    fn request(&self) -> ureq::Request {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/contents/{}",
+            self.handler.client.base_url,
+            self.handler.owner,
+            self.handler.repo,
+            self.path.trim_start_matches('/')
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(r#ref) = self.r#ref.clone() {
+            request = request.query("ref", &r#ref);
+        }
+        request
+    }
+
+    /// Get repository content. The content of a file is decoded from Base64.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-repository-content>.
+    /// ```no_run
+    /// use ghrs::model::contents::Content;
+    ///
+    /// let client = ghrs::Client::new();
+    /// if let Ok(Content::File(file)) = client.repos("owner", "repo").contents("Cargo.toml").send() {
+    ///     println!("{}", file.text().unwrap_or_default());
+    /// }
+    /// ```
+    pub fn send(&self) -> Result<Content, ureq::Error> {
+        let mut request = self.request();
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let content: Content = request.call()?.into_json()?;
+        Ok(content)
+    }
+
+    /// Download the raw content of a file, which also works for files larger than 1 MB.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-repository-content>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let bytes = client.repos("owner", "repo").contents("assets/logo.png").download();
+    /// ```
+    pub fn download(&self) -> Result<Vec<u8>, ureq::Error> {
+        let response = self.request().set("Accept", RAW_MEDIA_TYPE).call()?;
+        read_bytes(response)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn r#ref(mut self, r#ref: impl Into<String>) -> Self {
+        self.r#ref = Some(r#ref.into());
+        self
+    }
+}
+
+/// A builder for getting a repository README.
+pub struct GetReadmeBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    accept: Option<String>,
+    dir: Option<String>,
+    r#ref: Option<String>,
+}
+
+impl<'a> GetReadmeBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler) -> Self {
+        GetReadmeBuilder {
+            handler,
+            accept: None,
+            dir: None,
+            r#ref: None,
+        }
+    }
+
+    fn request(&self) -> ureq::Request {
+        let url = match self.dir.as_ref() {
+            Some(dir) => format!(
+                "{}/repos/{}/{}/readme/{}",
+                self.handler.client.base_url,
+                self.handler.owner,
+                self.handler.repo,
+                dir.trim_matches('/')
+            ),
+            None => format!(
+                "{}/repos/{}/{}/readme",
+                self.handler.client.base_url, self.handler.owner, self.handler.repo
+            ),
+        };
+        let mut request = ureq::get(&url);
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(r#ref) = self.r#ref.clone() {
+            request = request.query("ref", &r#ref);
+        }
+        request
+    }
+
+    /// Get a repository README.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-a-repository-readme>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let readme = client.repos("owner", "repo").readme().send();
+    /// ```
+    pub fn send(&self) -> Result<ContentFile, ureq::Error> {
+        let mut request = self.request();
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let readme: ContentFile = request.call()?.into_json()?;
+        Ok(readme)
+    }
+
+    /// Download the raw content of a repository README.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-a-repository-readme>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let bytes = client.repos("owner", "repo").readme().dir("docs").download();
+    /// ```
+    pub fn download(&self) -> Result<Vec<u8>, ureq::Error> {
+        let response = self.request().set("Accept", RAW_MEDIA_TYPE).call()?;
+        read_bytes(response)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Get the README in a directory instead of the repository root.
+    pub fn dir(mut self, dir: impl Into<String>) -> Self {
+        self.dir = Some(dir.into());
+        self
+    }
+
+    pub fn r#ref(mut self, r#ref: impl Into<String>) -> Self {
+        self.r#ref = Some(r#ref.into());
+        self
+    }
+}
+
+/// A builder for creating or updating file contents.
+#[derive(Serialize)]
+pub struct CreateOrUpdateFileBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReposHandler<'a>,
+    #[serde(skip)]
+    path: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    message: String,
+    #[serde(serialize_with = "serialize_base64")]
+    content: Vec<u8>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    sha: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    committer: Option<CommitAuthor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<CommitAuthor>,
+}
+
+impl<'a> CreateOrUpdateFileBuilder<'a> {
+    pub(crate) fn new(
+        handler: &'a ReposHandler,
+        path: impl Into<String>,
+        message: impl Into<String>,
+        content: impl Into<Vec<u8>>,
+    ) -> Self {
+        CreateOrUpdateFileBuilder {
+            handler,
+            path: path.into(),
+            accept: None,
+            message: message.into(),
+            content: content.into(),
+            sha: None,
+            branch: None,
+            committer: None,
+            author: None,
+        }
+    }
+
+    /// Create or update file contents. The content is encoded in Base64.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#create-or-update-file-contents>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let file_commit = client
+    ///     .repos("owner", "repo")
+    ///     .create_file("README.md", "Add README", "# repo\n")
+    ///     .branch("main")
+    ///     .committer("octocat", "octocat@github.com")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<FileCommit, ureq::Error> {
+        let mut request = ureq::put(&format!(
+            "{}/repos/{}/{}/contents/{}",
+            self.handler.client.base_url,
+            self.handler.owner,
+            self.handler.repo,
+            self.path.trim_start_matches('/')
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let file_commit: FileCommit = request.send_json(self)?.into_json()?;
+        Ok(file_commit)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// The blob SHA of the file being replaced, which is required to update a file.
+    pub fn sha(mut self, sha: impl Into<String>) -> Self {
+        self.sha = Some(sha.into());
+        self
+    }
+
+    pub fn branch(mut self, branch: impl Into<String>) -> Self {
+        self.branch = Some(branch.into());
+        self
+    }
+
+    pub fn committer(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.committer = Some(CommitAuthor::new(name, email));
+        self
+    }
+
+    pub fn author(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.author = Some(CommitAuthor::new(name, email));
+        self
+    }
+}
+
+/// A builder for deleting a file.
+#[derive(Serialize)]
+pub struct DeleteFileBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReposHandler<'a>,
+    #[serde(skip)]
+    path: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    message: String,
+    sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    branch: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    committer: Option<CommitAuthor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<CommitAuthor>,
+}
+
+impl<'a> DeleteFileBuilder<'a> {
+    pub(crate) fn new(
+        handler: &'a ReposHandler,
+        path: impl Into<String>,
+        message: impl Into<String>,
+        sha: impl Into<String>,
+    ) -> Self {
+        DeleteFileBuilder {
+            handler,
+            path: path.into(),
+            accept: None,
+            message: message.into(),
+            sha: sha.into(),
+            branch: None,
+            committer: None,
+            author: None,
+        }
+    }
+
+    /// Delete a file.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#delete-a-file>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let file_commit = client
+    ///     .repos("owner", "repo")
+    ///     .delete_file("README.md", "Remove README", "95b966ae1c166bd92f8ae7d1c313e738c731dfc3")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<FileCommit, ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/repos/{}/{}/contents/{}",
+            self.handler.client.base_url,
+            self.handler.owner,
+            self.handler.repo,
+            self.path.trim_start_matches('/')
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let file_commit: FileCommit = request.send_json(self)?.into_json()?;
+        Ok(file_commit)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn branch(mut self, branch: impl Into<String>) -> Self {
+        self.branch = Some(branch.into());
+        self
+    }
+
+    pub fn committer(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.committer = Some(CommitAuthor::new(name, email));
+        self
+    }
+
+    pub fn author(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.author = Some(CommitAuthor::new(name, email));
+        self
+    }
+}
@@ -0,0 +1,1150 @@
+//! The Git Database API
+use super::ReposHandler;
+use crate::model::git::{
+    Blob, BlobRef, Commit, CommitAuthor, NewTreeEntry, Reference, Tag, Tree, TreeEntry,
+};
+use crate::model::serialize_base64;
+use crate::{Client, Page};
+
+use serde::Serialize;
+use std::collections::HashMap;
+
+/// A client for the Git Database API.
+///
+/// See <https://docs.github.com/en/rest/reference/git>.
+pub struct GitHandler<'a> {
+    client: &'a Client,
+    owner: String,
+    repo: String,
+}
+
+impl<'a> GitHandler<'a> {
+    pub fn new(handler: &ReposHandler<'a>) -> GitHandler<'a> {
+        GitHandler {
+            client: handler.client,
+            owner: handler.owner.clone(),
+            repo: handler.repo.clone(),
+        }
+    }
+
+    /// Get a blob.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#get-a-blob>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let blob = client.repos("owner", "repo").git().get_blob("3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15").send();
+    /// ```
+    pub fn get_blob(&self, file_sha: impl Into<String>) -> GetBlobBuilder<'_> {
+        GetBlobBuilder::new(self, file_sha)
+    }
+
+    /// Create a blob.
+    ///
+    /// The content is sent encoded in Base64, so it may be binary.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#create-a-blob>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let blob = client.repos("owner", "repo").git().create_blob("Content of the blob").send();
+    /// ```
+    pub fn create_blob(&self, content: impl Into<Vec<u8>>) -> CreateBlobBuilder<'_> {
+        CreateBlobBuilder::new(self, content)
+    }
+
+    /// Get a tree.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#get-a-tree>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let tree = client.repos("owner", "repo").git().get_tree("main").recursive(true).send();
+    /// ```
+    pub fn get_tree(&self, tree_sha: impl Into<String>) -> GetTreeBuilder<'_> {
+        GetTreeBuilder::new(self, tree_sha)
+    }
+
+    /// Create a tree.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#create-a-tree>.
+    /// ```no_run
+    /// use ghrs::model::git::NewTreeEntry;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let tree = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .create_tree(vec![NewTreeEntry::text("README.md", "# repo\n")])
+    ///     .base_tree("9fb037999f264ba9a7fc6274d15fa3ae2ab98312")
+    ///     .send();
+    /// ```
+    pub fn create_tree(&self, tree: Vec<NewTreeEntry>) -> CreateTreeBuilder<'_> {
+        CreateTreeBuilder::new(self, tree)
+    }
+
+    /// Get a commit.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#get-a-commit>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let commit = client.repos("owner", "repo").git().get_commit("7638417db6d59f3c431d3e1f261cc637155684cd").send();
+    /// ```
+    pub fn get_commit(&self, commit_sha: impl Into<String>) -> GetCommitBuilder<'_> {
+        GetCommitBuilder::new(self, commit_sha)
+    }
+
+    /// Create a commit.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#create-a-commit>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let commit = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .create_commit("my commit message", "827efc6d56897b048c772eb4087f854f46256132")
+    ///     .parents(vec!["7d1b31e74ee336d15cbd21741bc88a537ed063a0".to_string()])
+    ///     .author("Mona Octocat", "octocat@github.com")
+    ///     .send();
+    /// ```
+    pub fn create_commit(
+        &self,
+        message: impl Into<String>,
+        tree: impl Into<String>,
+    ) -> CreateCommitBuilder<'_> {
+        CreateCommitBuilder::new(self, message, tree)
+    }
+
+    /// List matching references.
+    ///
+    /// `ref` is like `heads/feature` or `tags/v1`.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#list-matching-references>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let refs = client.repos("owner", "repo").git().list_matching_refs("tags/v1").send();
+    /// ```
+    pub fn list_matching_refs(&self, r#ref: impl Into<String>) -> ListMatchingRefsBuilder<'_> {
+        ListMatchingRefsBuilder::new(self, r#ref)
+    }
+
+    /// Get a reference.
+    ///
+    /// `ref` is like `heads/main` or `tags/v1.0.0`.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#get-a-reference>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let reference = client.repos("owner", "repo").git().get_ref("heads/main").send();
+    /// ```
+    pub fn get_ref(&self, r#ref: impl Into<String>) -> GetRefBuilder<'_> {
+        GetRefBuilder::new(self, r#ref)
+    }
+
+    /// Create a reference.
+    ///
+    /// `ref` is a fully qualified reference like `refs/heads/feature`.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#create-a-reference>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let reference = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .create_ref("refs/heads/feature", "aa218f56b14c9653891f9e74264a383fa43fefbd")
+    ///     .send();
+    /// ```
+    pub fn create_ref(
+        &self,
+        r#ref: impl Into<String>,
+        sha: impl Into<String>,
+    ) -> CreateRefBuilder<'_> {
+        CreateRefBuilder::new(self, r#ref, sha)
+    }
+
+    /// Update a reference.
+    ///
+    /// `ref` is like `heads/main`. Unless `force` is set, only a fast-forward update is allowed.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#update-a-reference>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let reference = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .update_ref("heads/main", "aa218f56b14c9653891f9e74264a383fa43fefbd")
+    ///     .send();
+    /// ```
+    pub fn update_ref(
+        &self,
+        r#ref: impl Into<String>,
+        sha: impl Into<String>,
+    ) -> UpdateRefBuilder<'_> {
+        UpdateRefBuilder::new(self, r#ref, sha)
+    }
+
+    /// Delete a reference.
+    ///
+    /// `ref` is like `heads/feature`.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#delete-a-reference>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").git().delete_ref("heads/feature").send();
+    /// ```
+    pub fn delete_ref(&self, r#ref: impl Into<String>) -> DeleteRefBuilder<'_> {
+        DeleteRefBuilder::new(self, r#ref)
+    }
+
+    /// Get a tag.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#get-a-tag>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let tag = client.repos("owner", "repo").git().get_tag("940bd336248efae0f9ee5bc7b2d5c985887b16ac").send();
+    /// ```
+    pub fn get_tag(&self, tag_sha: impl Into<String>) -> GetTagBuilder<'_> {
+        GetTagBuilder::new(self, tag_sha)
+    }
+
+    /// Create a tag object.
+    ///
+    /// This creates an annotated tag object only, create a `refs/tags/<tag>` reference to publish it.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#create-a-tag-object>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let tag = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .create_tag("v0.0.1", "initial version", "c3d0be41ecbe669545ee3e94d31ed9a4bc91ee3c")
+    ///     .tagger("Monalisa Octocat", "octocat@github.com")
+    ///     .send();
+    /// ```
+    pub fn create_tag(
+        &self,
+        tag: impl Into<String>,
+        message: impl Into<String>,
+        object: impl Into<String>,
+    ) -> CreateTagBuilder<'_> {
+        CreateTagBuilder::new(self, tag, message, object)
+    }
+
+    /// Commit `files` on top of `branch` in a single commit, and fast-forward `branch` to it.
+    ///
+    /// Blobs are created for the files, then a tree based on the tree of the current head.
+    /// Overwritten files keep their modes, e.g. executables and symlinks, unless a mode is given with
+    /// [`FileChange::executable`] or [`FileChange::write_with_mode`]. New files are regular files by default.
+    /// Updating `branch` fails if it was moved in the meantime.
+    /// ```no_run
+    /// use ghrs::repos::git::FileChange;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let commit = client.repos("owner", "repo").git().commit_files(
+    ///     "main",
+    ///     vec![
+    ///         FileChange::write("config/a.toml", "key = 1\n"),
+    ///         FileChange::write("config/b.toml", "key = 2\n"),
+    ///         FileChange::delete("config/c.toml"),
+    ///     ],
+    ///     "Sync config",
+    /// );
+    /// ```
+    pub fn commit_files(
+        &self,
+        branch: &str,
+        files: impl IntoIterator<Item = FileChange>,
+        message: impl Into<String>,
+    ) -> Result<Commit, ureq::Error> {
+        let branch = branch.strip_prefix("refs/heads/").unwrap_or(branch);
+        let head = self.get_ref(format!("heads/{}", branch)).send()?;
+        let parent = self.get_commit(head.object.sha).send()?;
+
+        let files: Vec<FileChange> = files.into_iter().collect();
+        let needs_modes = files
+            .iter()
+            .any(|file| matches!(file, FileChange::Write { mode: None, .. }));
+        let mut modes: HashMap<String, String> = HashMap::new();
+        if needs_modes {
+            let tree = self
+                .get_tree(parent.tree.sha.clone())
+                .recursive(true)
+                .send()?;
+            if tree.truncated {
+                // GitHub truncates recursive trees of large repositories, so walk down to each file instead.
+                let mut trees = HashMap::new();
+                for file in &files {
+                    if let FileChange::Write {
+                        path, mode: None, ..
+                    } = file
+                    {
+                        if let Some(mode) = self.file_mode(&parent.tree.sha, path, &mut trees)? {
+                            modes.insert(path.clone(), mode);
+                        }
+                    }
+                }
+            } else {
+                modes.extend(
+                    tree.tree
+                        .into_iter()
+                        .filter(|entry| entry.r#type == "blob")
+                        .map(|entry| (entry.path, entry.mode)),
+                );
+            }
+        }
+
+        let mut entries = Vec::new();
+        for file in files {
+            match file {
+                FileChange::Write {
+                    path,
+                    content,
+                    mode,
+                } => {
+                    let mode = mode.or_else(|| modes.get(&path).cloned());
+                    let blob = self.create_blob(content).send()?;
+                    let entry = NewTreeEntry::blob(path, blob.sha);
+                    entries.push(match mode {
+                        Some(mode) => entry.mode(mode),
+                        None => entry,
+                    });
+                }
+                FileChange::Delete { path } => entries.push(NewTreeEntry::delete(path)),
+            }
+        }
+        let tree = self
+            .create_tree(entries)
+            .base_tree(parent.tree.sha)
+            .send()?;
+        let commit = self
+            .create_commit(message, tree.sha)
+            .parents(vec![parent.sha])
+            .send()?;
+        self.update_ref(format!("heads/{}", branch), commit.sha.clone())
+            .send()?;
+        Ok(commit)
+    }
+
+    /// Find the mode of the file at `path` in the tree `tree_sha` by fetching its directories one by one.
+    ///
+    /// Fetched trees are cached in `trees` by their SHAs.
+    fn file_mode(
+        &self,
+        tree_sha: &str,
+        path: &str,
+        trees: &mut HashMap<String, Vec<TreeEntry>>,
+    ) -> Result<Option<String>, ureq::Error> {
+        let mut sha = tree_sha.to_string();
+        let mut components = path.split('/').peekable();
+        while let Some(name) = components.next() {
+            if !trees.contains_key(&sha) {
+                let tree = self.get_tree(sha.clone()).send()?;
+                trees.insert(sha.clone(), tree.tree);
+            }
+            let is_file = components.peek().is_none();
+            let r#type = if is_file { "blob" } else { "tree" };
+            let entry = trees[&sha]
+                .iter()
+                .find(|entry| entry.path == name && entry.r#type == r#type);
+            match entry {
+                Some(entry) if is_file => return Ok(Some(entry.mode.clone())),
+                Some(entry) => sha = entry.sha.clone(),
+                None => return Ok(None),
+            }
+        }
+        Ok(None)
+    }
+}
+
+/// A change of a file for [`GitHandler::commit_files`].
+#[derive(Clone, Debug)]
+pub enum FileChange {
+    Write {
+        path: String,
+        content: Vec<u8>,
+        /// `None` keeps the mode of an existing file, or `100644` for a new file.
+        mode: Option<String>,
+    },
+    Delete {
+        path: String,
+    },
+}
+
+impl FileChange {
+    /// Create or overwrite a file, keeping the mode of an existing file.
+    pub fn write(path: impl Into<String>, content: impl Into<Vec<u8>>) -> FileChange {
+        FileChange::Write {
+            path: path.into(),
+            content: content.into(),
+            mode: None,
+        }
+    }
+
+    /// Create or overwrite an executable file.
+    pub fn executable(path: impl Into<String>, content: impl Into<Vec<u8>>) -> FileChange {
+        FileChange::write_with_mode(path, content, "100755")
+    }
+
+    /// Create or overwrite a file with `mode`, e.g. `120000` for a symlink whose content is the target.
+    pub fn write_with_mode(
+        path: impl Into<String>,
+        content: impl Into<Vec<u8>>,
+        mode: impl Into<String>,
+    ) -> FileChange {
+        FileChange::Write {
+            path: path.into(),
+            content: content.into(),
+            mode: Some(mode.into()),
+        }
+    }
+
+    /// Delete a file.
+    pub fn delete(path: impl Into<String>) -> FileChange {
+        FileChange::Delete { path: path.into() }
+    }
+}
+
+/// A builder for getting a blob.
+pub struct GetBlobBuilder<'a> {
+    handler: &'a GitHandler<'a>,
+    file_sha: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetBlobBuilder<'a> {
+    fn new(handler: &'a GitHandler, file_sha: impl Into<String>) -> Self {
+        GetBlobBuilder {
+            handler,
+            file_sha: file_sha.into(),
+            accept: None,
+        }
+    }
+
+    /// Get a blob.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#get-a-blob>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let blob = client.repos("owner", "repo").git().get_blob("3a0f86fb8db8eea7ccbb9a95f325ddbedfb25e15").send();
+    /// ```
+    pub fn send(&self) -> Result<Blob, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/git/blobs/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.file_sha
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let blob: Blob = request.call()?.into_json()?;
+        Ok(blob)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for creating a blob.
+#[derive(Serialize)]
+pub struct CreateBlobBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a GitHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(serialize_with = "serialize_base64")]
+    content: Vec<u8>,
+    encoding: &'static str,
+}
+
+impl<'a> CreateBlobBuilder<'a> {
+    fn new(handler: &'a GitHandler, content: impl Into<Vec<u8>>) -> Self {
+        CreateBlobBuilder {
+            handler,
+            accept: None,
+            content: content.into(),
+            encoding: "base64",
+        }
+    }
+
+    /// Create a blob.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#create-a-blob>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let blob = client.repos("owner", "repo").git().create_blob("Content of the blob").send();
+    /// ```
+    pub fn send(&self) -> Result<BlobRef, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/git/blobs",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let blob: BlobRef = request.send_json(self)?.into_json()?;
+        Ok(blob)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for getting a tree.
+pub struct GetTreeBuilder<'a> {
+    handler: &'a GitHandler<'a>,
+    tree_sha: String,
+    accept: Option<String>,
+    recursive: Option<bool>,
+}
+
+impl<'a> GetTreeBuilder<'a> {
+    fn new(handler: &'a GitHandler, tree_sha: impl Into<String>) -> Self {
+        GetTreeBuilder {
+            handler,
+            tree_sha: tree_sha.into(),
+            accept: None,
+            recursive: None,
+        }
+    }
+
+    /// Get a tree.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#get-a-tree>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let tree = client.repos("owner", "repo").git().get_tree("main").recursive(true).send();
+    /// ```
+    pub fn send(&self) -> Result<Tree, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/git/trees/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.tree_sha
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(recursive) = self.recursive {
+            request = request.query("recursive", &recursive.to_string());
+        }
+
+        let tree: Tree = request.call()?.into_json()?;
+        Ok(tree)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn recursive(mut self, recursive: bool) -> Self {
+        self.recursive = Some(recursive);
+        self
+    }
+}
+
+/// A builder for creating a tree.
+#[derive(Serialize)]
+pub struct CreateTreeBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a GitHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    tree: Vec<NewTreeEntry>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    base_tree: Option<String>,
+}
+
+impl<'a> CreateTreeBuilder<'a> {
+    fn new(handler: &'a GitHandler, tree: Vec<NewTreeEntry>) -> Self {
+        CreateTreeBuilder {
+            handler,
+            accept: None,
+            tree,
+            base_tree: None,
+        }
+    }
+
+    /// Create a tree.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#create-a-tree>.
+    /// ```no_run
+    /// use ghrs::model::git::NewTreeEntry;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let tree = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .create_tree(vec![NewTreeEntry::text("README.md", "# repo\n")])
+    ///     .base_tree("9fb037999f264ba9a7fc6274d15fa3ae2ab98312")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Tree, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/git/trees",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let tree: Tree = request.send_json(self)?.into_json()?;
+        Ok(tree)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn base_tree(mut self, base_tree: impl Into<String>) -> Self {
+        self.base_tree = Some(base_tree.into());
+        self
+    }
+}
+
+/// A builder for getting a commit.
+pub struct GetCommitBuilder<'a> {
+    handler: &'a GitHandler<'a>,
+    commit_sha: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetCommitBuilder<'a> {
+    fn new(handler: &'a GitHandler, commit_sha: impl Into<String>) -> Self {
+        GetCommitBuilder {
+            handler,
+            commit_sha: commit_sha.into(),
+            accept: None,
+        }
+    }
+
+    /// Get a commit.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#get-a-commit>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let commit = client.repos("owner", "repo").git().get_commit("7638417db6d59f3c431d3e1f261cc637155684cd").send();
+    /// ```
+    pub fn send(&self) -> Result<Commit, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/git/commits/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.commit_sha
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let commit: Commit = request.call()?.into_json()?;
+        Ok(commit)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for creating a commit.
+#[derive(Serialize)]
+pub struct CreateCommitBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a GitHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    message: String,
+    tree: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    parents: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    author: Option<CommitAuthor>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    committer: Option<CommitAuthor>,
+}
+
+impl<'a> CreateCommitBuilder<'a> {
+    fn new(handler: &'a GitHandler, message: impl Into<String>, tree: impl Into<String>) -> Self {
+        CreateCommitBuilder {
+            handler,
+            accept: None,
+            message: message.into(),
+            tree: tree.into(),
+            parents: None,
+            author: None,
+            committer: None,
+        }
+    }
+
+    /// Create a commit.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#create-a-commit>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let commit = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .create_commit("my commit message", "827efc6d56897b048c772eb4087f854f46256132")
+    ///     .parents(vec!["7d1b31e74ee336d15cbd21741bc88a537ed063a0".to_string()])
+    ///     .author("Mona Octocat", "octocat@github.com")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Commit, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/git/commits",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let commit: Commit = request.send_json(self)?.into_json()?;
+        Ok(commit)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn parents(mut self, parents: impl Into<Vec<String>>) -> Self {
+        self.parents = Some(parents.into());
+        self
+    }
+
+    pub fn author(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.author = Some(CommitAuthor::new(name, email));
+        self
+    }
+
+    pub fn committer(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.committer = Some(CommitAuthor::new(name, email));
+        self
+    }
+}
+
+/// A builder for listing matching references.
+pub struct ListMatchingRefsBuilder<'a> {
+    handler: &'a GitHandler<'a>,
+    r#ref: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListMatchingRefsBuilder<'a> {
+    fn new(handler: &'a GitHandler, r#ref: impl Into<String>) -> Self {
+        ListMatchingRefsBuilder {
+            handler,
+            r#ref: r#ref.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List matching references.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#list-matching-references>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let refs = client.repos("owner", "repo").git().list_matching_refs("tags/v1").send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Reference>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/git/matching-refs/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.r#ref
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let refs = Page::from_response(response)?;
+        Ok(refs)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting a reference.
+pub struct GetRefBuilder<'a> {
+    handler: &'a GitHandler<'a>,
+    r#ref: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetRefBuilder<'a> {
+    fn new(handler: &'a GitHandler, r#ref: impl Into<String>) -> Self {
+        GetRefBuilder {
+            handler,
+            r#ref: r#ref.into(),
+            accept: None,
+        }
+    }
+
+    /// Get a reference.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#get-a-reference>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let reference = client.repos("owner", "repo").git().get_ref("heads/main").send();
+    /// ```
+    pub fn send(&self) -> Result<Reference, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/git/ref/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.r#ref
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let reference: Reference = request.call()?.into_json()?;
+        Ok(reference)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for creating a reference.
+#[derive(Serialize)]
+pub struct CreateRefBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a GitHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    r#ref: String,
+    sha: String,
+}
+
+impl<'a> CreateRefBuilder<'a> {
+    fn new(handler: &'a GitHandler, r#ref: impl Into<String>, sha: impl Into<String>) -> Self {
+        CreateRefBuilder {
+            handler,
+            accept: None,
+            r#ref: r#ref.into(),
+            sha: sha.into(),
+        }
+    }
+
+    /// Create a reference.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#create-a-reference>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let reference = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .create_ref("refs/heads/feature", "aa218f56b14c9653891f9e74264a383fa43fefbd")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Reference, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/git/refs",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let reference: Reference = request.send_json(self)?.into_json()?;
+        Ok(reference)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for updating a reference.
+#[derive(Serialize)]
+pub struct UpdateRefBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a GitHandler<'a>,
+    #[serde(skip)]
+    r#ref: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    sha: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    force: Option<bool>,
+}
+
+impl<'a> UpdateRefBuilder<'a> {
+    fn new(handler: &'a GitHandler, r#ref: impl Into<String>, sha: impl Into<String>) -> Self {
+        UpdateRefBuilder {
+            handler,
+            r#ref: r#ref.into(),
+            accept: None,
+            sha: sha.into(),
+            force: None,
+        }
+    }
+
+    /// Update a reference.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#update-a-reference>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let reference = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .update_ref("heads/main", "aa218f56b14c9653891f9e74264a383fa43fefbd")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Reference, ureq::Error> {
+        let mut request = ureq::patch(&format!(
+            "{}/repos/{}/{}/git/refs/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.r#ref
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let reference: Reference = request.send_json(self)?.into_json()?;
+        Ok(reference)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn force(mut self, force: bool) -> Self {
+        self.force = Some(force);
+        self
+    }
+}
+
+/// A builder for deleting a reference.
+pub struct DeleteRefBuilder<'a> {
+    handler: &'a GitHandler<'a>,
+    r#ref: String,
+    accept: Option<String>,
+}
+
+impl<'a> DeleteRefBuilder<'a> {
+    fn new(handler: &'a GitHandler, r#ref: impl Into<String>) -> Self {
+        DeleteRefBuilder {
+            handler,
+            r#ref: r#ref.into(),
+            accept: None,
+        }
+    }
+
+    /// Delete a reference.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#delete-a-reference>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").git().delete_ref("heads/feature").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/repos/{}/{}/git/refs/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.r#ref
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for getting a tag.
+pub struct GetTagBuilder<'a> {
+    handler: &'a GitHandler<'a>,
+    tag_sha: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetTagBuilder<'a> {
+    fn new(handler: &'a GitHandler, tag_sha: impl Into<String>) -> Self {
+        GetTagBuilder {
+            handler,
+            tag_sha: tag_sha.into(),
+            accept: None,
+        }
+    }
+
+    /// Get a tag.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#get-a-tag>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let tag = client.repos("owner", "repo").git().get_tag("940bd336248efae0f9ee5bc7b2d5c985887b16ac").send();
+    /// ```
+    pub fn send(&self) -> Result<Tag, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/git/tags/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.tag_sha
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let tag: Tag = request.call()?.into_json()?;
+        Ok(tag)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for creating a tag object.
+#[derive(Serialize)]
+pub struct CreateTagBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a GitHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    tag: String,
+    message: String,
+    object: String,
+    r#type: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tagger: Option<CommitAuthor>,
+}
+
+impl<'a> CreateTagBuilder<'a> {
+    fn new(
+        handler: &'a GitHandler,
+        tag: impl Into<String>,
+        message: impl Into<String>,
+        object: impl Into<String>,
+    ) -> Self {
+        CreateTagBuilder {
+            handler,
+            accept: None,
+            tag: tag.into(),
+            message: message.into(),
+            object: object.into(),
+            r#type: "commit".to_string(),
+            tagger: None,
+        }
+    }
+
+    /// Create a tag object.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/git#create-a-tag-object>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let tag = client
+    ///     .repos("owner", "repo")
+    ///     .git()
+    ///     .create_tag("v0.0.1", "initial version", "c3d0be41ecbe669545ee3e94d31ed9a4bc91ee3c")
+    ///     .tagger("Monalisa Octocat", "octocat@github.com")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Tag, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/git/tags",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let tag: Tag = request.send_json(self)?.into_json()?;
+        Ok(tag)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn tagger(mut self, name: impl Into<String>, email: impl Into<String>) -> Self {
+        self.tagger = Some(CommitAuthor::new(name, email));
+        self
+    }
+
+    /// The type of the tagged object, which is `commit` by default.
+    pub fn r#type(mut self, r#type: impl Into<String>) -> Self {
+        self.r#type = r#type.into();
+        self
+    }
+}
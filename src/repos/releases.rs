@@ -0,0 +1,1115 @@
+//! The Releases API
+use super::{read_bytes, ReposHandler};
+use crate::model::releases::{Release, ReleaseAsset, ReleaseNotes};
+use crate::{Client, Page};
+
+use serde::Serialize;
+use std::fs::File;
+use std::io::Read;
+use std::path::Path;
+
+/// A client for the Releases API.
+///
+/// See <https://docs.github.com/en/rest/reference/releases>.
+pub struct ReleasesHandler<'a> {
+    client: &'a Client,
+    owner: String,
+    repo: String,
+}
+
+impl<'a> ReleasesHandler<'a> {
+    pub fn new(handler: &ReposHandler<'a>) -> ReleasesHandler<'a> {
+        ReleasesHandler {
+            client: handler.client,
+            owner: handler.owner.clone(),
+            repo: handler.repo.clone(),
+        }
+    }
+
+    /// List releases.
+    ///
+    /// Draft releases are only returned to users with push access.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#list-releases>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let releases = client.repos("owner", "repo").releases().list().per_page(100).send();
+    /// ```
+    pub fn list(&self) -> ListReleasesBuilder<'_> {
+        ListReleasesBuilder::new(self)
+    }
+
+    /// Get a release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#get-a-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let release = client.repos("owner", "repo").releases().get(1).send();
+    /// ```
+    pub fn get(&self, release_id: u64) -> GetReleaseBuilder<'_> {
+        GetReleaseBuilder::new(self, release_id)
+    }
+
+    /// Get the latest release.
+    ///
+    /// The latest release is the most recent non-prerelease, non-draft release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#get-the-latest-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let release = client.repos("owner", "repo").releases().latest().send();
+    /// ```
+    pub fn latest(&self) -> GetLatestReleaseBuilder<'_> {
+        GetLatestReleaseBuilder::new(self)
+    }
+
+    /// Get a release by tag name.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#get-a-release-by-tag-name>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let release = client.repos("owner", "repo").releases().get_by_tag("v1.0.0").send();
+    /// ```
+    pub fn get_by_tag(&self, tag: impl Into<String>) -> GetReleaseByTagBuilder<'_> {
+        GetReleaseByTagBuilder::new(self, tag)
+    }
+
+    /// Create a release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#create-a-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let release = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .create("v1.0.0")
+    ///     .name("v1.0.0")
+    ///     .generate_release_notes(true)
+    ///     .send();
+    /// ```
+    pub fn create(&self, tag_name: impl Into<String>) -> CreateReleaseBuilder<'_> {
+        CreateReleaseBuilder::new(self, tag_name)
+    }
+
+    /// Update a release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#update-a-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let release = client.repos("owner", "repo").releases().update(1).draft(false).send();
+    /// ```
+    pub fn update(&self, release_id: u64) -> UpdateReleaseBuilder<'_> {
+        UpdateReleaseBuilder::new(self, release_id)
+    }
+
+    /// Delete a release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#delete-a-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").releases().delete(1).send();
+    /// ```
+    pub fn delete(&self, release_id: u64) -> DeleteReleaseBuilder<'_> {
+        DeleteReleaseBuilder::new(self, release_id)
+    }
+
+    /// Generate release notes content for a release.
+    ///
+    /// The notes are not saved anywhere, which is useful to preview them before creating a release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#generate-release-notes-content-for-a-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let notes = client.repos("owner", "repo").releases()
+    ///     .generate_release_notes("v1.1.0")
+    ///     .previous_tag_name("v1.0.0")
+    ///     .send();
+    /// ```
+    pub fn generate_release_notes(
+        &self,
+        tag_name: impl Into<String>,
+    ) -> GenerateReleaseNotesBuilder<'_> {
+        GenerateReleaseNotesBuilder::new(self, tag_name)
+    }
+
+    /// List release assets.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#list-release-assets>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let assets = client.repos("owner", "repo").releases().list_assets(1).send();
+    /// ```
+    pub fn list_assets(&self, release_id: u64) -> ListReleaseAssetsBuilder<'_> {
+        ListReleaseAssetsBuilder::new(self, release_id)
+    }
+
+    /// Get a release asset.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#get-a-release-asset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let asset = client.repos("owner", "repo").releases().get_asset(1).send();
+    /// ```
+    pub fn get_asset(&self, asset_id: u64) -> GetReleaseAssetBuilder<'_> {
+        GetReleaseAssetBuilder::new(self, asset_id)
+    }
+
+    /// Download a release asset.
+    ///
+    /// GitHub redirects to the binary content, which is followed without sending the token.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#get-a-release-asset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let bytes = client.repos("owner", "repo").releases().download_asset(1).send();
+    /// ```
+    pub fn download_asset(&self, asset_id: u64) -> DownloadReleaseAssetBuilder<'_> {
+        DownloadReleaseAssetBuilder::new(self, asset_id)
+    }
+
+    /// Delete a release asset.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#delete-a-release-asset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").releases().delete_asset(1).send();
+    /// ```
+    pub fn delete_asset(&self, asset_id: u64) -> DeleteReleaseAssetBuilder<'_> {
+        DeleteReleaseAssetBuilder::new(self, asset_id)
+    }
+
+    /// Upload a release asset.
+    ///
+    /// The asset is uploaded to [`Client::uploads_url`] instead of the API.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#upload-a-release-asset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let asset = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .upload_asset(1, "app-x86_64-unknown-linux-gnu.tar.gz")
+    ///     .label("Linux (x86_64)")
+    ///     .send_file("target/dist/app-x86_64-unknown-linux-gnu.tar.gz");
+    /// ```
+    pub fn upload_asset(
+        &self,
+        release_id: u64,
+        name: impl Into<String>,
+    ) -> UploadReleaseAssetBuilder<'_> {
+        UploadReleaseAssetBuilder::new(self, release_id, name)
+    }
+}
+
+/// A builder for listing releases.
+pub struct ListReleasesBuilder<'a> {
+    handler: &'a ReleasesHandler<'a>,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListReleasesBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler) -> Self {
+        ListReleasesBuilder {
+            handler,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List releases.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#list-releases>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let releases = client.repos("owner", "repo").releases().list().per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Release>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/releases",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let releases = Page::from_response(response)?;
+        Ok(releases)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting a release.
+pub struct GetReleaseBuilder<'a> {
+    handler: &'a ReleasesHandler<'a>,
+    release_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> GetReleaseBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler, release_id: u64) -> Self {
+        GetReleaseBuilder {
+            handler,
+            release_id,
+            accept: None,
+        }
+    }
+
+    /// Get a release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#get-a-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let release = client.repos("owner", "repo").releases().get(1).send();
+    /// ```
+    pub fn send(&self) -> Result<Release, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/releases/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.release_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let release: Release = request.call()?.into_json()?;
+        Ok(release)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for getting the latest release.
+pub struct GetLatestReleaseBuilder<'a> {
+    handler: &'a ReleasesHandler<'a>,
+    accept: Option<String>,
+}
+
+impl<'a> GetLatestReleaseBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler) -> Self {
+        GetLatestReleaseBuilder {
+            handler,
+            accept: None,
+        }
+    }
+
+    /// Get the latest release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#get-the-latest-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let release = client.repos("owner", "repo").releases().latest().send();
+    /// ```
+    pub fn send(&self) -> Result<Release, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/releases/latest",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let release: Release = request.call()?.into_json()?;
+        Ok(release)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for getting a release by tag name.
+pub struct GetReleaseByTagBuilder<'a> {
+    handler: &'a ReleasesHandler<'a>,
+    tag: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetReleaseByTagBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler, tag: impl Into<String>) -> Self {
+        GetReleaseByTagBuilder {
+            handler,
+            tag: tag.into(),
+            accept: None,
+        }
+    }
+
+    /// Get a release by tag name.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#get-a-release-by-tag-name>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let release = client.repos("owner", "repo").releases().get_by_tag("v1.0.0").send();
+    /// ```
+    pub fn send(&self) -> Result<Release, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/releases/tags/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.tag
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let release: Release = request.call()?.into_json()?;
+        Ok(release)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for creating a release.
+#[derive(Serialize)]
+pub struct CreateReleaseBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReleasesHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    tag_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_commitish: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    draft: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prerelease: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discussion_category_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    generate_release_notes: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    make_latest: Option<String>,
+}
+
+impl<'a> CreateReleaseBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler, tag_name: impl Into<String>) -> Self {
+        CreateReleaseBuilder {
+            handler,
+            accept: None,
+            tag_name: tag_name.into(),
+            target_commitish: None,
+            name: None,
+            body: None,
+            draft: None,
+            prerelease: None,
+            discussion_category_name: None,
+            generate_release_notes: None,
+            make_latest: None,
+        }
+    }
+
+    /// Create a release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#create-a-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let release = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .create("v1.0.0")
+    ///     .name("v1.0.0")
+    ///     .generate_release_notes(true)
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Release, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/releases",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let release: Release = request.send_json(self)?.into_json()?;
+        Ok(release)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// The branch or commit SHA the tag is created from if it does not exist yet.
+    pub fn target_commitish(mut self, target_commitish: impl Into<String>) -> Self {
+        self.target_commitish = Some(target_commitish.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.draft = Some(draft);
+        self
+    }
+
+    pub fn prerelease(mut self, prerelease: bool) -> Self {
+        self.prerelease = Some(prerelease);
+        self
+    }
+
+    pub fn discussion_category_name(mut self, discussion_category_name: impl Into<String>) -> Self {
+        self.discussion_category_name = Some(discussion_category_name.into());
+        self
+    }
+
+    pub fn generate_release_notes(mut self, generate_release_notes: bool) -> Self {
+        self.generate_release_notes = Some(generate_release_notes);
+        self
+    }
+
+    /// `true`, `false` or `legacy`, which is the default and decides by the creation date and semantic version.
+    pub fn make_latest(mut self, make_latest: impl Into<String>) -> Self {
+        self.make_latest = Some(make_latest.into());
+        self
+    }
+}
+
+/// A builder for updating a release.
+#[derive(Serialize)]
+pub struct UpdateReleaseBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReleasesHandler<'a>,
+    #[serde(skip)]
+    release_id: u64,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tag_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_commitish: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    body: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    draft: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    prerelease: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    discussion_category_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    make_latest: Option<String>,
+}
+
+impl<'a> UpdateReleaseBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler, release_id: u64) -> Self {
+        UpdateReleaseBuilder {
+            handler,
+            release_id,
+            accept: None,
+            tag_name: None,
+            target_commitish: None,
+            name: None,
+            body: None,
+            draft: None,
+            prerelease: None,
+            discussion_category_name: None,
+            make_latest: None,
+        }
+    }
+
+    /// Update a release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#update-a-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let release = client.repos("owner", "repo").releases().update(1).draft(false).send();
+    /// ```
+    pub fn send(&self) -> Result<Release, ureq::Error> {
+        let mut request = ureq::patch(&format!(
+            "{}/repos/{}/{}/releases/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.release_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let release: Release = request.send_json(self)?.into_json()?;
+        Ok(release)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn tag_name(mut self, tag_name: impl Into<String>) -> Self {
+        self.tag_name = Some(tag_name.into());
+        self
+    }
+
+    pub fn target_commitish(mut self, target_commitish: impl Into<String>) -> Self {
+        self.target_commitish = Some(target_commitish.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = Some(body.into());
+        self
+    }
+
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.draft = Some(draft);
+        self
+    }
+
+    pub fn prerelease(mut self, prerelease: bool) -> Self {
+        self.prerelease = Some(prerelease);
+        self
+    }
+
+    pub fn discussion_category_name(mut self, discussion_category_name: impl Into<String>) -> Self {
+        self.discussion_category_name = Some(discussion_category_name.into());
+        self
+    }
+
+    /// `true`, `false` or `legacy`, which is the default and decides by the creation date and semantic version.
+    pub fn make_latest(mut self, make_latest: impl Into<String>) -> Self {
+        self.make_latest = Some(make_latest.into());
+        self
+    }
+}
+
+/// A builder for deleting a release.
+pub struct DeleteReleaseBuilder<'a> {
+    handler: &'a ReleasesHandler<'a>,
+    release_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> DeleteReleaseBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler, release_id: u64) -> Self {
+        DeleteReleaseBuilder {
+            handler,
+            release_id,
+            accept: None,
+        }
+    }
+
+    /// Delete a release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#delete-a-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").releases().delete(1).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/repos/{}/{}/releases/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.release_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for generating release notes.
+#[derive(Serialize)]
+pub struct GenerateReleaseNotesBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReleasesHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    tag_name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_commitish: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    previous_tag_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    configuration_file_path: Option<String>,
+}
+
+impl<'a> GenerateReleaseNotesBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler, tag_name: impl Into<String>) -> Self {
+        GenerateReleaseNotesBuilder {
+            handler,
+            accept: None,
+            tag_name: tag_name.into(),
+            target_commitish: None,
+            previous_tag_name: None,
+            configuration_file_path: None,
+        }
+    }
+
+    /// Generate release notes content for a release.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#generate-release-notes-content-for-a-release>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let notes = client.repos("owner", "repo").releases()
+    ///     .generate_release_notes("v1.1.0")
+    ///     .previous_tag_name("v1.0.0")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<ReleaseNotes, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/releases/generate-notes",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let notes: ReleaseNotes = request.send_json(self)?.into_json()?;
+        Ok(notes)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn target_commitish(mut self, target_commitish: impl Into<String>) -> Self {
+        self.target_commitish = Some(target_commitish.into());
+        self
+    }
+
+    pub fn previous_tag_name(mut self, previous_tag_name: impl Into<String>) -> Self {
+        self.previous_tag_name = Some(previous_tag_name.into());
+        self
+    }
+
+    pub fn configuration_file_path(mut self, configuration_file_path: impl Into<String>) -> Self {
+        self.configuration_file_path = Some(configuration_file_path.into());
+        self
+    }
+}
+
+/// A builder for listing release assets.
+pub struct ListReleaseAssetsBuilder<'a> {
+    handler: &'a ReleasesHandler<'a>,
+    release_id: u64,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListReleaseAssetsBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler, release_id: u64) -> Self {
+        ListReleaseAssetsBuilder {
+            handler,
+            release_id,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List release assets.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#list-release-assets>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let assets = client.repos("owner", "repo").releases().list_assets(1).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<ReleaseAsset>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/releases/{}/assets",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.release_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let assets = Page::from_response(response)?;
+        Ok(assets)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting a release asset.
+pub struct GetReleaseAssetBuilder<'a> {
+    handler: &'a ReleasesHandler<'a>,
+    asset_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> GetReleaseAssetBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler, asset_id: u64) -> Self {
+        GetReleaseAssetBuilder {
+            handler,
+            asset_id,
+            accept: None,
+        }
+    }
+
+    /// Get a release asset.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#get-a-release-asset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let asset = client.repos("owner", "repo").releases().get_asset(1).send();
+    /// ```
+    pub fn send(&self) -> Result<ReleaseAsset, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/releases/assets/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.asset_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let asset: ReleaseAsset = request.call()?.into_json()?;
+        Ok(asset)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for downloading a release asset.
+pub struct DownloadReleaseAssetBuilder<'a> {
+    handler: &'a ReleasesHandler<'a>,
+    asset_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> DownloadReleaseAssetBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler, asset_id: u64) -> Self {
+        DownloadReleaseAssetBuilder {
+            handler,
+            asset_id,
+            accept: None,
+        }
+    }
+
+    /// Download a release asset.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#get-a-release-asset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let bytes = client.repos("owner", "repo").releases().download_asset(1).send();
+    /// ```
+    pub fn send(&self) -> Result<Vec<u8>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/releases/assets/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.asset_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        // The asset is returned as JSON unless `application/octet-stream` is accepted.
+        let accept = self
+            .accept
+            .clone()
+            .unwrap_or_else(|| "application/octet-stream".to_string());
+        request = request.set("Accept", &accept);
+
+        let response = request.call()?;
+        read_bytes(response)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for deleting a release asset.
+pub struct DeleteReleaseAssetBuilder<'a> {
+    handler: &'a ReleasesHandler<'a>,
+    asset_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> DeleteReleaseAssetBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler, asset_id: u64) -> Self {
+        DeleteReleaseAssetBuilder {
+            handler,
+            asset_id,
+            accept: None,
+        }
+    }
+
+    /// Delete a release asset.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#delete-a-release-asset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").releases().delete_asset(1).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/repos/{}/{}/releases/assets/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.asset_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for uploading a release asset.
+pub struct UploadReleaseAssetBuilder<'a> {
+    handler: &'a ReleasesHandler<'a>,
+    release_id: u64,
+    name: String,
+    accept: Option<String>,
+    label: Option<String>,
+    content_type: Option<String>,
+}
+
+impl<'a> UploadReleaseAssetBuilder<'a> {
+    fn new(handler: &'a ReleasesHandler, release_id: u64, name: impl Into<String>) -> Self {
+        UploadReleaseAssetBuilder {
+            handler,
+            release_id,
+            name: name.into(),
+            accept: None,
+            label: None,
+            content_type: None,
+        }
+    }
+
+    /// Upload a release asset, streaming `content_length` bytes from `reader`.
+    ///
+    /// GitHub doesn't accept chunked uploads, so the length must be known in advance.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/releases#upload-a-release-asset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let file = std::fs::File::open("app.zip").unwrap();
+    /// let content_length = file.metadata().unwrap().len();
+    /// let asset = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .releases()
+    ///     .upload_asset(1, "app.zip")
+    ///     .content_type("application/zip")
+    ///     .send(file, content_length);
+    /// ```
+    pub fn send(
+        &self,
+        reader: impl Read,
+        content_length: u64,
+    ) -> Result<ReleaseAsset, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/releases/{}/assets",
+            self.handler.client.uploads_url(),
+            self.handler.owner,
+            self.handler.repo,
+            self.release_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        let content_type = self
+            .content_type
+            .clone()
+            .unwrap_or_else(|| content_type_for(&self.name).to_string());
+        request = request
+            .set("Content-Type", &content_type)
+            .set("Content-Length", &content_length.to_string())
+            .query("name", &self.name);
+        if let Some(label) = self.label.clone() {
+            request = request.query("label", &label);
+        }
+
+        let asset: ReleaseAsset = request.send(reader)?.into_json()?;
+        Ok(asset)
+    }
+
+    /// Upload a release asset from memory.
+    pub fn send_bytes(&self, bytes: impl AsRef<[u8]>) -> Result<ReleaseAsset, ureq::Error> {
+        let bytes = bytes.as_ref();
+        self.send(bytes, bytes.len() as u64)
+    }
+
+    /// Upload a file as a release asset, streaming it from disk.
+    pub fn send_file(&self, path: impl AsRef<Path>) -> Result<ReleaseAsset, ureq::Error> {
+        let file = File::open(path)?;
+        let content_length = file.metadata()?.len();
+        self.send(file, content_length)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// A short description shown instead of the file name.
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// The media type of the asset, guessed from the extension of the name by default.
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+}
+
+/// Returns the media type for common kinds of release assets, `application/octet-stream` otherwise.
+///
+/// ```
+/// use ghrs::repos::releases::content_type_for;
+///
+/// assert_eq!(content_type_for("app-v1.0.0-x86_64.tar.gz"), "application/gzip");
+/// assert_eq!(content_type_for("app.exe"), "application/octet-stream");
+/// ```
+pub fn content_type_for(name: &str) -> &'static str {
+    let name = name.to_ascii_lowercase();
+    let types = [
+        (".tar.gz", "application/gzip"),
+        (".tgz", "application/gzip"),
+        (".gz", "application/gzip"),
+        (".tar.xz", "application/x-xz"),
+        (".xz", "application/x-xz"),
+        (".tar.zst", "application/zstd"),
+        (".zst", "application/zstd"),
+        (".tar.bz2", "application/x-bzip2"),
+        (".tar", "application/x-tar"),
+        (".zip", "application/zip"),
+        (".deb", "application/vnd.debian.binary-package"),
+        (".rpm", "application/x-rpm"),
+        (".dmg", "application/x-apple-diskimage"),
+        (".msi", "application/x-msi"),
+        (".json", "application/json"),
+        (".txt", "text/plain"),
+        (".sha256", "text/plain"),
+        (".asc", "text/plain"),
+        (".sig", "application/pgp-signature"),
+    ];
+    types
+        .iter()
+        .find(|(extension, _)| name.ends_with(extension))
+        .map(|(_, content_type)| *content_type)
+        .unwrap_or("application/octet-stream")
+}
@@ -0,0 +1,228 @@
+//! The Commit Statuses API
+use super::ReposHandler;
+use crate::model::statuses::{CombinedStatus, Status, StatusState};
+use crate::Page;
+
+use serde::Serialize;
+
+/// A builder for creating a commit status.
+#[derive(Serialize)]
+pub struct CreateStatusBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a ReposHandler<'a>,
+    #[serde(skip)]
+    sha: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    state: StatusState,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target_url: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    description: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    context: Option<String>,
+}
+
+impl<'a> CreateStatusBuilder<'a> {
+    pub(crate) fn new(
+        handler: &'a ReposHandler,
+        sha: impl Into<String>,
+        state: StatusState,
+    ) -> Self {
+        CreateStatusBuilder {
+            handler,
+            sha: sha.into(),
+            accept: None,
+            state,
+            target_url: None,
+            description: None,
+            context: None,
+        }
+    }
+
+    /// Create a commit status.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#create-a-commit-status>.
+    /// ```no_run
+    /// use ghrs::model::statuses::StatusState;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let status = client
+    ///     .repos("owner", "repo")
+    ///     .create_status("6dcb09b5b57875f334f61aebed695e2e4193db5e", StatusState::Success)
+    ///     .target_url("https://ci.example.com/builds/1")
+    ///     .description("The build succeeded!")
+    ///     .context("continuous-integration/ci")
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Status, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/repos/{}/{}/statuses/{}",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.sha
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let status: Status = request.send_json(self)?.into_json()?;
+        Ok(status)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn target_url(mut self, target_url: impl Into<String>) -> Self {
+        self.target_url = Some(target_url.into());
+        self
+    }
+
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// A label to differentiate this status from the statuses of other systems, `default` by default.
+    pub fn context(mut self, context: impl Into<String>) -> Self {
+        self.context = Some(context.into());
+        self
+    }
+}
+
+/// A builder for listing commit statuses for a reference.
+pub struct ListStatusesBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    r#ref: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListStatusesBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, r#ref: impl Into<String>) -> Self {
+        ListStatusesBuilder {
+            handler,
+            r#ref: r#ref.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List commit statuses for a reference.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#list-commit-statuses-for-a-reference>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let statuses = client.repos("owner", "repo").list_statuses("main").per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Status>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/commits/{}/statuses",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.r#ref
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let statuses = Page::from_response(response)?;
+        Ok(statuses)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting the combined status for a reference.
+pub struct GetCombinedStatusBuilder<'a> {
+    handler: &'a ReposHandler<'a>,
+    r#ref: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> GetCombinedStatusBuilder<'a> {
+    pub(crate) fn new(handler: &'a ReposHandler, r#ref: impl Into<String>) -> Self {
+        GetCombinedStatusBuilder {
+            handler,
+            r#ref: r#ref.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Get the combined status for a specific reference.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/repos#get-the-combined-status-for-a-specific-reference>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let status = client.repos("owner", "repo").combined_status("main").send();
+    /// ```
+    pub fn send(&self) -> Result<CombinedStatus, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/repos/{}/{}/commits/{}/status",
+            self.handler.client.base_url, self.handler.owner, self.handler.repo, self.r#ref
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let combined_status: CombinedStatus = request.call()?.into_json()?;
+        Ok(combined_status)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
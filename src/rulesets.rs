@@ -0,0 +1,679 @@
+//! The Rulesets API
+//!
+//! Rulesets exist for repositories and organizations. The handlers are created with
+//! [`crate::repos::ReposHandler::rulesets`] and [`crate::Client::org_rulesets`].
+use crate::model::branches::{BranchProtection, ProtectionSetting};
+use crate::model::rulesets::{BypassActor, Enforcement, Rule, Ruleset, RulesetConditions};
+use crate::{Client, Page};
+
+use serde::Serialize;
+
+/// A client for the rulesets of a repository or an organization.
+///
+/// See <https://docs.github.com/en/rest/repos/rules>.
+pub struct RulesetsHandler<'a> {
+    client: &'a Client,
+    path: String,
+}
+
+impl<'a> RulesetsHandler<'a> {
+    /// `path` is a path of the repository or the organization without the base URL, e.g. `orgs/org`.
+    pub fn new(client: &'a Client, path: impl Into<String>) -> RulesetsHandler<'a> {
+        RulesetsHandler {
+            client,
+            path: path.into(),
+        }
+    }
+
+    /// Get all rulesets.
+    ///
+    /// Rulesets in the list don't have rules, use [`RulesetsHandler::get`] to get them.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#get-all-repository-rulesets>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let rulesets = client.repos("owner", "repo").rulesets().list().includes_parents(true).send();
+    /// ```
+    pub fn list(&self) -> ListRulesetsBuilder<'_> {
+        ListRulesetsBuilder::new(self)
+    }
+
+    /// Get a ruleset.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#get-a-repository-ruleset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let ruleset = client.repos("owner", "repo").rulesets().get(42).send();
+    /// ```
+    pub fn get(&self, ruleset_id: u64) -> GetRulesetBuilder<'_> {
+        GetRulesetBuilder::new(self, ruleset_id)
+    }
+
+    /// Create a ruleset.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#create-a-repository-ruleset>.
+    /// ```no_run
+    /// use ghrs::model::rulesets::{Enforcement, NameCondition, Rule, RulesetConditions};
+    ///
+    /// let client = ghrs::Client::new();
+    /// let ruleset = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .rulesets()
+    ///     .create("main", Enforcement::Active)
+    ///     .target("branch")
+    ///     .conditions(RulesetConditions {
+    ///         ref_name: Some(NameCondition {
+    ///             include: vec!["~DEFAULT_BRANCH".to_string()],
+    ///             ..Default::default()
+    ///         }),
+    ///         ..Default::default()
+    ///     })
+    ///     .rules(vec![Rule::Deletion, Rule::NonFastForward])
+    ///     .send();
+    /// ```
+    pub fn create(
+        &self,
+        name: impl Into<String>,
+        enforcement: Enforcement,
+    ) -> CreateRulesetBuilder<'_> {
+        CreateRulesetBuilder::new(self, name, enforcement)
+    }
+
+    /// Update a ruleset.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#update-a-repository-ruleset>.
+    /// ```no_run
+    /// use ghrs::model::rulesets::Enforcement;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let ruleset = client.repos("owner", "repo").rulesets().update(42).enforcement(Enforcement::Disabled).send();
+    /// ```
+    pub fn update(&self, ruleset_id: u64) -> UpdateRulesetBuilder<'_> {
+        UpdateRulesetBuilder::new(self, ruleset_id)
+    }
+
+    /// Delete a ruleset.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#delete-a-repository-ruleset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").rulesets().delete(42).send();
+    /// ```
+    pub fn delete(&self, ruleset_id: u64) -> DeleteRulesetBuilder<'_> {
+        DeleteRulesetBuilder::new(self, ruleset_id)
+    }
+}
+
+/// Requirements for a branch, which are the same for branch protection and rulesets.
+///
+/// ```
+/// use ghrs::model::rulesets::{PullRequestParameters, Rule};
+/// use ghrs::rulesets::BranchPolicy;
+///
+/// let rules = vec![
+///     Rule::NonFastForward,
+///     Rule::PullRequest(PullRequestParameters {
+///         dismiss_stale_reviews_on_push: false,
+///         require_code_owner_review: true,
+///         require_last_push_approval: false,
+///         required_approving_review_count: 1,
+///         required_review_thread_resolution: false,
+///         allowed_merge_methods: Vec::new(),
+///     }),
+/// ];
+/// let policy = BranchPolicy::from_rules(&rules);
+///
+/// let required = BranchPolicy {
+///     required_approving_review_count: 2,
+///     block_force_pushes: true,
+///     ..Default::default()
+/// };
+/// assert_eq!(
+///     policy.violations(&required),
+///     vec!["requires 1 approving reviews instead of 2"]
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BranchPolicy {
+    pub required_approving_review_count: u8,
+    pub require_code_owner_review: bool,
+    pub dismiss_stale_reviews: bool,
+    /// Contexts of required status checks, sorted.
+    pub required_status_checks: Vec<String>,
+    /// `true` if branches must be up to date before merging.
+    pub strict_status_checks: bool,
+    pub required_linear_history: bool,
+    pub required_signatures: bool,
+    pub block_force_pushes: bool,
+    pub block_deletions: bool,
+}
+
+impl BranchPolicy {
+    /// The policy enforced by branch protection.
+    pub fn from_protection(protection: &BranchProtection) -> BranchPolicy {
+        let enabled = |setting: &Option<ProtectionSetting>| {
+            setting.as_ref().is_some_and(|setting| setting.enabled)
+        };
+        let mut policy = BranchPolicy {
+            required_linear_history: enabled(&protection.required_linear_history),
+            required_signatures: enabled(&protection.required_signatures),
+            block_force_pushes: !enabled(&protection.allow_force_pushes),
+            block_deletions: !enabled(&protection.allow_deletions),
+            ..Default::default()
+        };
+        if let Some(reviews) = protection.required_pull_request_reviews.as_ref() {
+            policy.required_approving_review_count = reviews.required_approving_review_count;
+            policy.require_code_owner_review = reviews.require_code_owner_reviews;
+            policy.dismiss_stale_reviews = reviews.dismiss_stale_reviews;
+        }
+        if let Some(checks) = protection.required_status_checks.as_ref() {
+            policy.required_status_checks = checks.contexts.clone();
+            policy.strict_status_checks = checks.strict;
+        }
+        policy.required_status_checks.sort();
+        policy.required_status_checks.dedup();
+        policy
+    }
+
+    /// The policy enforced by `rules`, e.g. rules for a branch.
+    ///
+    /// If there are several rules of the same type, which is possible with several rulesets,
+    /// the strictest one wins like GitHub does.
+    pub fn from_rules<'a>(rules: impl IntoIterator<Item = &'a Rule>) -> BranchPolicy {
+        let mut policy = BranchPolicy::default();
+        for rule in rules {
+            match rule {
+                Rule::Deletion => policy.block_deletions = true,
+                Rule::NonFastForward => policy.block_force_pushes = true,
+                Rule::RequiredLinearHistory => policy.required_linear_history = true,
+                Rule::RequiredSignatures => policy.required_signatures = true,
+                Rule::PullRequest(parameters) => {
+                    policy.required_approving_review_count = policy
+                        .required_approving_review_count
+                        .max(parameters.required_approving_review_count);
+                    policy.require_code_owner_review |= parameters.require_code_owner_review;
+                    policy.dismiss_stale_reviews |= parameters.dismiss_stale_reviews_on_push;
+                }
+                Rule::RequiredStatusChecks(parameters) => {
+                    policy.required_status_checks.extend(
+                        parameters
+                            .required_status_checks
+                            .iter()
+                            .map(|check| check.context.clone()),
+                    );
+                    policy.strict_status_checks |= parameters.strict_required_status_checks_policy;
+                }
+                _ => {}
+            }
+        }
+        policy.required_status_checks.sort();
+        policy.required_status_checks.dedup();
+        policy
+    }
+
+    /// Combine two policies which are enforced together, e.g. branch protection and rulesets.
+    pub fn merge(&self, other: &BranchPolicy) -> BranchPolicy {
+        let mut required_status_checks = self.required_status_checks.clone();
+        required_status_checks.extend(other.required_status_checks.iter().cloned());
+        required_status_checks.sort();
+        required_status_checks.dedup();
+        BranchPolicy {
+            required_approving_review_count: self
+                .required_approving_review_count
+                .max(other.required_approving_review_count),
+            require_code_owner_review: self.require_code_owner_review
+                || other.require_code_owner_review,
+            dismiss_stale_reviews: self.dismiss_stale_reviews || other.dismiss_stale_reviews,
+            required_status_checks,
+            strict_status_checks: self.strict_status_checks || other.strict_status_checks,
+            required_linear_history: self.required_linear_history || other.required_linear_history,
+            required_signatures: self.required_signatures || other.required_signatures,
+            block_force_pushes: self.block_force_pushes || other.block_force_pushes,
+            block_deletions: self.block_deletions || other.block_deletions,
+        }
+    }
+
+    /// Returns descriptions of requirements of `required` which the policy doesn't meet.
+    pub fn violations(&self, required: &BranchPolicy) -> Vec<String> {
+        let mut violations = Vec::new();
+        if self.required_approving_review_count < required.required_approving_review_count {
+            violations.push(format!(
+                "requires {} approving reviews instead of {}",
+                self.required_approving_review_count, required.required_approving_review_count
+            ));
+        }
+        let flags = [
+            (
+                self.require_code_owner_review,
+                required.require_code_owner_review,
+                "doesn't require code owner reviews",
+            ),
+            (
+                self.dismiss_stale_reviews,
+                required.dismiss_stale_reviews,
+                "doesn't dismiss stale reviews",
+            ),
+            (
+                self.strict_status_checks,
+                required.strict_status_checks,
+                "doesn't require branches to be up to date",
+            ),
+            (
+                self.required_linear_history,
+                required.required_linear_history,
+                "doesn't require linear history",
+            ),
+            (
+                self.required_signatures,
+                required.required_signatures,
+                "doesn't require signed commits",
+            ),
+            (
+                self.block_force_pushes,
+                required.block_force_pushes,
+                "allows force pushes",
+            ),
+            (
+                self.block_deletions,
+                required.block_deletions,
+                "allows deletions",
+            ),
+        ];
+        for (actual, expected, message) in flags.iter() {
+            if *expected && !*actual {
+                violations.push(message.to_string());
+            }
+        }
+        for context in required.required_status_checks.iter() {
+            if !self.required_status_checks.contains(context) {
+                violations.push(format!("doesn't require status check `{}`", context));
+            }
+        }
+        violations
+    }
+}
+
+/// A builder for listing rulesets.
+pub struct ListRulesetsBuilder<'a> {
+    handler: &'a RulesetsHandler<'a>,
+    accept: Option<String>,
+    includes_parents: Option<bool>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListRulesetsBuilder<'a> {
+    fn new(handler: &'a RulesetsHandler) -> Self {
+        ListRulesetsBuilder {
+            handler,
+            accept: None,
+            includes_parents: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Get all rulesets.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#get-all-repository-rulesets>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let rulesets = client.repos("owner", "repo").rulesets().list().includes_parents(true).send();
+    /// ```
+    pub fn send(&self) -> Result<Page<Ruleset>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/{}/rulesets",
+            self.handler.client.base_url, self.handler.path
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(includes_parents) = self.includes_parents {
+            request = request.query("includes_parents", &includes_parents.to_string());
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let response = request.call()?;
+        let rulesets = Page::from_response(response)?;
+        Ok(rulesets)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Only for repositories, `true` to include rulesets of the organization as well.
+    pub fn includes_parents(mut self, includes_parents: bool) -> Self {
+        self.includes_parents = Some(includes_parents);
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting a ruleset.
+pub struct GetRulesetBuilder<'a> {
+    handler: &'a RulesetsHandler<'a>,
+    ruleset_id: u64,
+    accept: Option<String>,
+    includes_parents: Option<bool>,
+}
+
+impl<'a> GetRulesetBuilder<'a> {
+    fn new(handler: &'a RulesetsHandler, ruleset_id: u64) -> Self {
+        GetRulesetBuilder {
+            handler,
+            ruleset_id,
+            accept: None,
+            includes_parents: None,
+        }
+    }
+
+    /// Get a ruleset.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#get-a-repository-ruleset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let ruleset = client.repos("owner", "repo").rulesets().get(42).send();
+    /// ```
+    pub fn send(&self) -> Result<Ruleset, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/{}/rulesets/{}",
+            self.handler.client.base_url, self.handler.path, self.ruleset_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(includes_parents) = self.includes_parents {
+            request = request.query("includes_parents", &includes_parents.to_string());
+        }
+
+        let ruleset: Ruleset = request.call()?.into_json()?;
+        Ok(ruleset)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Only for repositories, `true` to include rulesets of the organization as well.
+    pub fn includes_parents(mut self, includes_parents: bool) -> Self {
+        self.includes_parents = Some(includes_parents);
+        self
+    }
+}
+
+/// A builder for creating a ruleset.
+#[derive(Serialize)]
+pub struct CreateRulesetBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a RulesetsHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    name: String,
+    enforcement: Enforcement,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bypass_actors: Option<Vec<BypassActor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conditions: Option<RulesetConditions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rules: Option<Vec<Rule>>,
+}
+
+impl<'a> CreateRulesetBuilder<'a> {
+    fn new(
+        handler: &'a RulesetsHandler,
+        name: impl Into<String>,
+        enforcement: Enforcement,
+    ) -> Self {
+        CreateRulesetBuilder {
+            handler,
+            accept: None,
+            name: name.into(),
+            enforcement,
+            target: None,
+            bypass_actors: None,
+            conditions: None,
+            rules: None,
+        }
+    }
+
+    /// Create a ruleset.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#create-a-repository-ruleset>.
+    /// ```no_run
+    /// use ghrs::model::rulesets::{Enforcement, NameCondition, Rule, RulesetConditions};
+    ///
+    /// let client = ghrs::Client::new();
+    /// let ruleset = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .rulesets()
+    ///     .create("main", Enforcement::Active)
+    ///     .target("branch")
+    ///     .conditions(RulesetConditions {
+    ///         ref_name: Some(NameCondition {
+    ///             include: vec!["~DEFAULT_BRANCH".to_string()],
+    ///             ..Default::default()
+    ///         }),
+    ///         ..Default::default()
+    ///     })
+    ///     .rules(vec![Rule::Deletion, Rule::NonFastForward])
+    ///     .send();
+    /// ```
+    pub fn send(&self) -> Result<Ruleset, ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/{}/rulesets",
+            self.handler.client.base_url, self.handler.path
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let ruleset: Ruleset = request.send_json(self)?.into_json()?;
+        Ok(ruleset)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// `branch` by default, `tag` or `push`.
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    pub fn bypass_actors(mut self, bypass_actors: Vec<BypassActor>) -> Self {
+        self.bypass_actors = Some(bypass_actors);
+        self
+    }
+
+    pub fn conditions(mut self, conditions: RulesetConditions) -> Self {
+        self.conditions = Some(conditions);
+        self
+    }
+
+    pub fn rules(mut self, rules: Vec<Rule>) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+}
+
+/// A builder for updating a ruleset.
+#[derive(Serialize)]
+pub struct UpdateRulesetBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a RulesetsHandler<'a>,
+    #[serde(skip)]
+    ruleset_id: u64,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    enforcement: Option<Enforcement>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    target: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    bypass_actors: Option<Vec<BypassActor>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    conditions: Option<RulesetConditions>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rules: Option<Vec<Rule>>,
+}
+
+impl<'a> UpdateRulesetBuilder<'a> {
+    fn new(handler: &'a RulesetsHandler, ruleset_id: u64) -> Self {
+        UpdateRulesetBuilder {
+            handler,
+            ruleset_id,
+            accept: None,
+            name: None,
+            enforcement: None,
+            target: None,
+            bypass_actors: None,
+            conditions: None,
+            rules: None,
+        }
+    }
+
+    /// Update a ruleset.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#update-a-repository-ruleset>.
+    /// ```no_run
+    /// use ghrs::model::rulesets::Enforcement;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let ruleset = client.repos("owner", "repo").rulesets().update(42).enforcement(Enforcement::Disabled).send();
+    /// ```
+    pub fn send(&self) -> Result<Ruleset, ureq::Error> {
+        let mut request = ureq::put(&format!(
+            "{}/{}/rulesets/{}",
+            self.handler.client.base_url, self.handler.path, self.ruleset_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let ruleset: Ruleset = request.send_json(self)?.into_json()?;
+        Ok(ruleset)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn enforcement(mut self, enforcement: Enforcement) -> Self {
+        self.enforcement = Some(enforcement);
+        self
+    }
+
+    pub fn target(mut self, target: impl Into<String>) -> Self {
+        self.target = Some(target.into());
+        self
+    }
+
+    pub fn bypass_actors(mut self, bypass_actors: Vec<BypassActor>) -> Self {
+        self.bypass_actors = Some(bypass_actors);
+        self
+    }
+
+    pub fn conditions(mut self, conditions: RulesetConditions) -> Self {
+        self.conditions = Some(conditions);
+        self
+    }
+
+    /// Replace the rules of the ruleset.
+    pub fn rules(mut self, rules: Vec<Rule>) -> Self {
+        self.rules = Some(rules);
+        self
+    }
+}
+
+/// A builder for deleting a ruleset.
+pub struct DeleteRulesetBuilder<'a> {
+    handler: &'a RulesetsHandler<'a>,
+    ruleset_id: u64,
+    accept: Option<String>,
+}
+
+impl<'a> DeleteRulesetBuilder<'a> {
+    fn new(handler: &'a RulesetsHandler, ruleset_id: u64) -> Self {
+        DeleteRulesetBuilder {
+            handler,
+            ruleset_id,
+            accept: None,
+        }
+    }
+
+    /// Delete a ruleset.
+    ///
+    /// See <https://docs.github.com/en/rest/repos/rules#delete-a-repository-ruleset>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").rulesets().delete(42).send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/{}/rulesets/{}",
+            self.handler.client.base_url, self.handler.path, self.ruleset_id
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
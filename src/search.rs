@@ -0,0 +1,1134 @@
+//! The Search API
+//!
+//! Search has a stricter rate limit than other APIs, 30 requests per minute with a token and 10 without.
+//! [`SearchHandler`] waits for the rate limit to be reset and retries, up to [`SearchHandler::max_wait`].
+use crate::model::search::{CodeSearchItem, CommitSearchItem};
+use crate::model::{Issue, Label, Repository, User};
+use crate::{Client, Page};
+
+use chrono::{DateTime, SubsecRound, TimeZone, Utc};
+use serde::de::DeserializeOwned;
+use serde::Deserialize;
+use std::collections::HashSet;
+use std::fmt;
+use std::hash::Hash;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// How long to wait for the rate limit to be reset until [`SearchHandler::max_wait`] is set,
+/// which covers the one-minute window of the search rate limit.
+const DEFAULT_MAX_WAIT: u64 = 60;
+
+/// The number of results GitHub returns for a search at most.
+pub const MAX_SEARCH_RESULTS: u64 = 1000;
+
+/// The number of times [`search_all`] retries a page whose search timed out.
+pub const MAX_INCOMPLETE_RETRIES: usize = 2;
+
+/// A client for the Search API.
+///
+/// See <https://docs.github.com/en/rest/search/search>.
+pub struct SearchHandler<'a> {
+    client: &'a Client,
+    max_wait: Duration,
+}
+
+impl<'a> SearchHandler<'a> {
+    pub fn new(client: &'a Client) -> SearchHandler<'a> {
+        SearchHandler {
+            client,
+            max_wait: Duration::from_secs(DEFAULT_MAX_WAIT),
+        }
+    }
+
+    /// Set how long to wait in total for the rate limit to be reset, 60 seconds by default.
+    ///
+    /// An error is returned without waiting if the rate limit is reset later than that.
+    /// ```no_run
+    /// use std::time::Duration;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let results = client.search().max_wait(Duration::from_secs(0)).issues("is:pr is:open").send();
+    /// ```
+    pub fn max_wait(mut self, max_wait: Duration) -> Self {
+        self.max_wait = max_wait;
+        self
+    }
+
+    /// Search issues and pull requests.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-issues-and-pull-requests>.
+    /// ```no_run
+    /// use ghrs::search::SearchQuery;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let query = SearchQuery::new().repo("owner", "repo").is("pr").is("open").label("bug");
+    /// let results = client.search().issues(query).sort("updated").send();
+    /// ```
+    pub fn issues(&self, query: impl Into<String>) -> SearchIssuesBuilder<'_> {
+        SearchIssuesBuilder::new(self, query)
+    }
+
+    /// Search repositories.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-repositories>.
+    /// ```no_run
+    /// use ghrs::search::SearchQuery;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let query = SearchQuery::new().term("rust").qualifier("language", "rust");
+    /// let results = client.search().repositories(query).sort("stars").send();
+    /// ```
+    pub fn repositories(&self, query: impl Into<String>) -> SearchRepositoriesBuilder<'_> {
+        SearchRepositoriesBuilder::new(self, query)
+    }
+
+    /// Search code.
+    ///
+    /// Code search requires a token.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-code>.
+    /// ```no_run
+    /// use ghrs::search::SearchQuery;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let query = SearchQuery::new().term("ureq").repo("owner", "repo");
+    /// let results = client.token("your_token").search().code(query).send();
+    /// ```
+    pub fn code(&self, query: impl Into<String>) -> SearchCodeBuilder<'_> {
+        SearchCodeBuilder::new(self, query)
+    }
+
+    /// Search commits.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-commits>.
+    /// ```no_run
+    /// use ghrs::search::SearchQuery;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let query = SearchQuery::new().term("fix").repo("owner", "repo");
+    /// let results = client.search().commits(query).sort("author-date").send();
+    /// ```
+    pub fn commits(&self, query: impl Into<String>) -> SearchCommitsBuilder<'_> {
+        SearchCommitsBuilder::new(self, query)
+    }
+
+    /// Search users.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-users>.
+    /// ```no_run
+    /// use ghrs::search::SearchQuery;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let query = SearchQuery::new().term("octo").qualifier("type", "user");
+    /// let results = client.search().users(query).send();
+    /// ```
+    pub fn users(&self, query: impl Into<String>) -> SearchUsersBuilder<'_> {
+        SearchUsersBuilder::new(self, query)
+    }
+
+    /// Search labels.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-labels>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let results = client.search().labels(1296269, "bug").send();
+    /// ```
+    pub fn labels(&self, repository_id: u64, query: impl Into<String>) -> SearchLabelsBuilder<'_> {
+        SearchLabelsBuilder::new(self, repository_id, query)
+    }
+
+    /// Search all issues and pull requests created in `start..end`, beyond [`MAX_SEARCH_RESULTS`].
+    ///
+    /// See [`search_all`] for how the search is split, and check [`AllSearchResults::is_complete`]
+    /// for missing results. `query` must not have a `created:` qualifier.
+    /// ```no_run
+    /// use chrono::{TimeZone, Utc};
+    /// use ghrs::search::SearchQuery;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let results = client
+    ///     .token("your_token")
+    ///     .search()
+    ///     .all_issues(
+    ///         SearchQuery::new().org("octo-org").is("issue"),
+    ///         Utc.with_ymd_and_hms(2020, 1, 1, 0, 0, 0).unwrap(),
+    ///         Utc::now(),
+    ///     )
+    ///     .unwrap();
+    /// if !results.is_complete() {
+    ///     eprintln!("missing results in {:?} {:?}", results.truncated, results.incomplete);
+    /// }
+    /// ```
+    pub fn all_issues(
+        &self,
+        query: SearchQuery,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<AllSearchResults<Issue>, ureq::Error> {
+        search_all(
+            start,
+            end,
+            |issue: &Issue| issue.id,
+            |range, page| {
+                let results = self
+                    .issues(query.clone().created(range))
+                    .sort("created")
+                    .order("asc")
+                    .per_page(100)
+                    .page(page)
+                    .send()?;
+                Ok(SearchPage::from(results))
+            },
+        )
+    }
+
+    /// Send a search request, and retry it if the rate limit is exceeded.
+    fn send<T: DeserializeOwned>(
+        &self,
+        request: ureq::Request,
+    ) -> Result<SearchResults<T>, ureq::Error> {
+        let started = Instant::now();
+        loop {
+            match request.clone().call() {
+                Ok(response) => return SearchResults::from_response(response),
+                Err(ureq::Error::Status(status, response)) if status == 403 || status == 429 => {
+                    match retry_after(&response) {
+                        Some(wait) if started.elapsed() + wait <= self.max_wait => {
+                            thread::sleep(wait)
+                        }
+                        _ => return Err(ureq::Error::Status(status, response)),
+                    }
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+}
+
+/// Fetch all results of a search across `start..end` by splitting it into `created:` ranges.
+///
+/// `search` is called with a range and a page number from 1, and returns the page.
+/// A range is bisected while it has more than [`MAX_SEARCH_RESULTS`] results, and pages of each range are fetched
+/// until all of its results are. Results are deduplicated by `key`, since results can move between pages
+/// while they are fetched, and returned from the oldest range.
+///
+/// Ranges are split by seconds, so a second with more results than the cap is fetched up to the cap,
+/// and it's reported in [`AllSearchResults::truncated`]. A page which timed out is retried
+/// [`MAX_INCOMPLETE_RETRIES`] times, and its range is reported in [`AllSearchResults::incomplete`] if it still times out.
+///
+/// ```
+/// use chrono::{Duration, TimeZone, Utc};
+/// use ghrs::search::{search_all, DateRange, SearchPage};
+///
+/// // 2500 results created every minute.
+/// let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+/// let created: Vec<_> = (0..2500).map(|i| start + Duration::minutes(i)).collect();
+/// let results = search_all(
+///     start,
+///     start + Duration::days(7),
+///     |time| *time,
+///     |range, page| {
+///         let (from, to) = match range {
+///             DateRange::Between(from, to) => (from, to),
+///             range => panic!("unexpected range: {}", range),
+///         };
+///         let matched: Vec<_> = created.iter().filter(|time| from <= **time && **time <= to).collect();
+///         Ok::<_, ()>(SearchPage {
+///             total_count: matched.len() as u64,
+///             incomplete_results: false,
+///             items: matched.iter().skip((page as usize - 1) * 100).take(100).map(|time| **time).collect(),
+///         })
+///     },
+/// )
+/// .unwrap();
+/// assert!(results.is_complete());
+/// assert_eq!(results.items, created);
+/// ```
+pub fn search_all<T, K, E, F, S>(
+    start: DateTime<Utc>,
+    end: DateTime<Utc>,
+    key: F,
+    mut search: S,
+) -> Result<AllSearchResults<T>, E>
+where
+    K: Eq + Hash,
+    F: Fn(&T) -> K,
+    S: FnMut(DateRange, u8) -> Result<SearchPage<T>, E>,
+{
+    let mut results = AllSearchResults {
+        items: Vec::new(),
+        truncated: Vec::new(),
+        incomplete: Vec::new(),
+    };
+    let mut seen = HashSet::new();
+    // Retry a page while it times out, and record the range if it never completes.
+    let mut fetch = |range: DateRange, page: u8, incomplete: &mut Vec<DateRange>| {
+        let mut retries = 0;
+        loop {
+            let results = search(range, page)?;
+            if !results.incomplete_results {
+                return Ok(results);
+            }
+            if retries == MAX_INCOMPLETE_RETRIES {
+                if !incomplete.contains(&range) {
+                    incomplete.push(range);
+                }
+                return Ok(results);
+            }
+            retries += 1;
+        }
+    };
+
+    // Ranges are `start..end`, and the earliest one is at the end.
+    let mut ranges = vec![(start.trunc_subsecs(0), end.trunc_subsecs(0))];
+    while let Some((start, end)) = ranges.pop() {
+        let seconds = (end - start).num_seconds();
+        if seconds <= 0 {
+            continue;
+        }
+        // `Between` is inclusive and searched by seconds.
+        let range = DateRange::Between(start, end - chrono::Duration::seconds(1));
+        let first = fetch(range, 1, &mut results.incomplete)?;
+        if first.total_count > MAX_SEARCH_RESULTS {
+            if seconds > 1 {
+                let middle = start + chrono::Duration::seconds(seconds / 2);
+                ranges.push((middle, end));
+                ranges.push((start, middle));
+                continue;
+            }
+            results.truncated.push(range);
+        }
+
+        let total_count = first.total_count.min(MAX_SEARCH_RESULTS) as usize;
+        let mut items = first.items;
+        let mut fetched = items.len();
+        let mut page = 1;
+        loop {
+            for item in items {
+                if seen.insert(key(&item)) {
+                    results.items.push(item);
+                }
+            }
+            if fetched == 0 || fetched >= total_count || page == u8::MAX {
+                break;
+            }
+            page += 1;
+            let next = fetch(range, page, &mut results.incomplete)?;
+            fetched += next.items.len();
+            if next.items.is_empty() {
+                break;
+            }
+            items = next.items;
+        }
+    }
+    Ok(results)
+}
+
+/// A page of a search for [`search_all`].
+#[derive(Clone, Debug)]
+pub struct SearchPage<T> {
+    pub total_count: u64,
+    pub incomplete_results: bool,
+    pub items: Vec<T>,
+}
+
+impl<T> From<SearchResults<T>> for SearchPage<T> {
+    fn from(results: SearchResults<T>) -> SearchPage<T> {
+        SearchPage {
+            total_count: results.total_count,
+            incomplete_results: results.incomplete_results,
+            items: results.items.into_iter().collect(),
+        }
+    }
+}
+
+/// Results of [`search_all`].
+#[derive(Clone, Debug)]
+pub struct AllSearchResults<T> {
+    pub items: Vec<T>,
+    /// Ranges of one second with more results than [`MAX_SEARCH_RESULTS`], of which the rest are missing.
+    pub truncated: Vec<DateRange>,
+    /// Ranges whose search timed out even after retries, of which some results may be missing.
+    pub incomplete: Vec<DateRange>,
+}
+
+impl<T> AllSearchResults<T> {
+    /// Returns `true` if no result is missing.
+    pub fn is_complete(&self) -> bool {
+        self.truncated.is_empty() && self.incomplete.is_empty()
+    }
+}
+
+/// Results of a search.
+#[derive(Debug)]
+pub struct SearchResults<T> {
+    /// The number of all matching items, though only the first 1000 of them can be fetched.
+    pub total_count: u64,
+    /// `true` if the search timed out and some matching items may be missing.
+    pub incomplete_results: bool,
+    /// Items of the page. [`Page::get_next_page`] can't parse search results,
+    /// so fetch the next page with `page` of the builder instead.
+    pub items: Page<T>,
+    /// The rate limit after the search.
+    pub rate_limit: Option<RateLimit>,
+}
+
+impl<T: DeserializeOwned> SearchResults<T> {
+    /// Create [`SearchResults`] from response.
+    pub fn from_response(response: ureq::Response) -> Result<SearchResults<T>, ureq::Error> {
+        #[derive(Deserialize)]
+        struct Body<T> {
+            total_count: u64,
+            incomplete_results: bool,
+            items: Vec<T>,
+        }
+
+        let rate_limit = RateLimit::from_response(&response);
+        let (prev, next) = crate::links(&response);
+        let body: Body<T> = response.into_json()?;
+        Ok(SearchResults {
+            total_count: body.total_count,
+            incomplete_results: body.incomplete_results,
+            items: Page {
+                items: body.items,
+                prev,
+                next,
+            },
+            rate_limit,
+        })
+    }
+}
+
+/// A rate limit in `X-RateLimit-*` headers.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct RateLimit {
+    pub limit: u32,
+    pub remaining: u32,
+    /// When the rate limit is reset.
+    pub reset: DateTime<Utc>,
+    /// e.g. `search` or `core`.
+    pub resource: Option<String>,
+}
+
+impl RateLimit {
+    /// Returns the rate limit of a response, or `None` if the response doesn't have it.
+    ///
+    /// ```
+    /// use ghrs::search::RateLimit;
+    ///
+    /// let response: ureq::Response = "HTTP/1.1 200 OK\r\n\
+    ///     X-RateLimit-Limit: 30\r\n\
+    ///     X-RateLimit-Remaining: 29\r\n\
+    ///     X-RateLimit-Reset: 1704067200\r\n\
+    ///     X-RateLimit-Resource: search\r\n\r\n"
+    ///     .parse()
+    ///     .unwrap();
+    /// let rate_limit = RateLimit::from_response(&response).unwrap();
+    /// assert_eq!(rate_limit.remaining, 29);
+    /// assert_eq!(rate_limit.reset.to_rfc3339(), "2024-01-01T00:00:00+00:00");
+    /// ```
+    pub fn from_response(response: &ureq::Response) -> Option<RateLimit> {
+        let header = |name: &str| response.header(name)?.parse::<u32>().ok();
+        let reset = response.header("X-RateLimit-Reset")?.parse::<i64>().ok()?;
+        Some(RateLimit {
+            limit: header("X-RateLimit-Limit")?,
+            remaining: header("X-RateLimit-Remaining")?,
+            reset: Utc.timestamp_opt(reset, 0).single()?,
+            resource: response
+                .header("X-RateLimit-Resource")
+                .map(|resource| resource.to_string()),
+        })
+    }
+}
+
+/// Returns how long to wait before retrying a request which exceeded a rate limit.
+///
+/// `Retry-After` is used for secondary rate limits, and `X-RateLimit-Reset` is used if no request remains.
+/// `None` is returned if the response isn't about a rate limit.
+pub fn retry_after(response: &ureq::Response) -> Option<Duration> {
+    if let Some(seconds) = response
+        .header("Retry-After")
+        .and_then(|seconds| seconds.parse().ok())
+    {
+        return Some(Duration::from_secs(seconds));
+    }
+    let rate_limit = RateLimit::from_response(response)?;
+    if rate_limit.remaining > 0 {
+        return None;
+    }
+    // The reset time is in seconds, so wait for one more second not to retry too early.
+    let wait = (rate_limit.reset - Utc::now()).num_seconds().max(0) as u64 + 1;
+    Some(Duration::from_secs(wait))
+}
+
+/// A search query with qualifiers.
+///
+/// Values are quoted if they have spaces, and the query is URL encoded when it's sent.
+/// ```
+/// use chrono::{TimeZone, Utc};
+/// use ghrs::search::{DateRange, SearchQuery};
+///
+/// let query = SearchQuery::new()
+///     .term("crash")
+///     .repo("owner", "repo")
+///     .is("pr")
+///     .label("help wanted")
+///     .exclude("author", "app/dependabot")
+///     .updated(DateRange::Before(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()));
+/// assert_eq!(
+///     query.to_string(),
+///     r#"crash repo:owner/repo is:pr label:"help wanted" -author:app/dependabot updated:<2024-01-01"#
+/// );
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct SearchQuery {
+    terms: Vec<String>,
+}
+
+impl SearchQuery {
+    pub fn new() -> SearchQuery {
+        SearchQuery::default()
+    }
+
+    /// Add a search keyword, which is quoted if it has spaces to match the phrase.
+    pub fn term(mut self, term: impl AsRef<str>) -> Self {
+        self.terms.push(quote(term.as_ref()));
+        self
+    }
+
+    /// Add a qualifier, e.g. `language:rust`.
+    pub fn qualifier(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        self.terms
+            .push(format!("{}:{}", name.as_ref(), quote(value.as_ref())));
+        self
+    }
+
+    /// Add a negated qualifier, e.g. `-label:bug`.
+    pub fn exclude(mut self, name: impl AsRef<str>, value: impl AsRef<str>) -> Self {
+        self.terms
+            .push(format!("-{}:{}", name.as_ref(), quote(value.as_ref())));
+        self
+    }
+
+    /// Add a date qualifier, e.g. `created:>=2024-01-01`.
+    pub fn date(mut self, name: impl AsRef<str>, range: DateRange) -> Self {
+        self.terms.push(format!("{}:{}", name.as_ref(), range));
+        self
+    }
+
+    pub fn repo(self, owner: impl AsRef<str>, repo: impl AsRef<str>) -> Self {
+        self.qualifier("repo", format!("{}/{}", owner.as_ref(), repo.as_ref()))
+    }
+
+    pub fn org(self, org: impl AsRef<str>) -> Self {
+        self.qualifier("org", org)
+    }
+
+    pub fn user(self, user: impl AsRef<str>) -> Self {
+        self.qualifier("user", user)
+    }
+
+    /// e.g. `pr`, `issue`, `open` or `merged`.
+    pub fn is(self, value: impl AsRef<str>) -> Self {
+        self.qualifier("is", value)
+    }
+
+    pub fn label(self, label: impl AsRef<str>) -> Self {
+        self.qualifier("label", label)
+    }
+
+    pub fn author(self, author: impl AsRef<str>) -> Self {
+        self.qualifier("author", author)
+    }
+
+    pub fn assignee(self, assignee: impl AsRef<str>) -> Self {
+        self.qualifier("assignee", assignee)
+    }
+
+    pub fn created(self, range: DateRange) -> Self {
+        self.date("created", range)
+    }
+
+    pub fn updated(self, range: DateRange) -> Self {
+        self.date("updated", range)
+    }
+
+    pub fn closed(self, range: DateRange) -> Self {
+        self.date("closed", range)
+    }
+}
+
+impl fmt::Display for SearchQuery {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.terms.join(" "))
+    }
+}
+
+impl From<SearchQuery> for String {
+    fn from(query: SearchQuery) -> String {
+        query.to_string()
+    }
+}
+
+/// Quote a value with spaces. GitHub can't escape quotes, so they are removed.
+fn quote(value: &str) -> String {
+    let value = value.replace('"', "");
+    if value.is_empty() || value.contains(char::is_whitespace) {
+        format!("\"{}\"", value)
+    } else {
+        value
+    }
+}
+
+/// A range of times for date qualifiers.
+///
+/// Times at midnight are written as dates if it doesn't change the range, e.g. `<2024-01-01`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DateRange {
+    /// `<time`
+    Before(DateTime<Utc>),
+    /// `<=time`
+    AtOrBefore(DateTime<Utc>),
+    /// `>time`
+    After(DateTime<Utc>),
+    /// `>=time`
+    AtOrAfter(DateTime<Utc>),
+    /// `start..end`, both inclusive.
+    Between(DateTime<Utc>, DateTime<Utc>),
+}
+
+impl fmt::Display for DateRange {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DateRange::Before(time) => write!(f, "<{}", format_time(time, true)),
+            DateRange::AtOrBefore(time) => write!(f, "<={}", format_time(time, false)),
+            DateRange::After(time) => write!(f, ">{}", format_time(time, false)),
+            DateRange::AtOrAfter(time) => write!(f, ">={}", format_time(time, true)),
+            DateRange::Between(start, end) => write!(
+                f,
+                "{}..{}",
+                format_time(start, true),
+                format_time(end, false)
+            ),
+        }
+    }
+}
+
+/// A date only means the whole day, so it's used for a start of a range only.
+fn format_time(time: &DateTime<Utc>, is_start: bool) -> String {
+    if is_start && time.timestamp() % 86400 == 0 {
+        time.format("%Y-%m-%d").to_string()
+    } else {
+        time.format("%Y-%m-%dT%H:%M:%SZ").to_string()
+    }
+}
+
+/// A builder for searching issues and pull requests.
+pub struct SearchIssuesBuilder<'a> {
+    handler: &'a SearchHandler<'a>,
+    query: String,
+    accept: Option<String>,
+    sort: Option<String>,
+    order: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> SearchIssuesBuilder<'a> {
+    fn new(handler: &'a SearchHandler, query: impl Into<String>) -> Self {
+        SearchIssuesBuilder {
+            handler,
+            query: query.into(),
+            accept: None,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Search issues and pull requests.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-issues-and-pull-requests>.
+    /// ```no_run
+    /// use ghrs::search::SearchQuery;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let query = SearchQuery::new().repo("owner", "repo").is("pr").is("open").label("bug");
+    /// let results = client.search().issues(query).sort("updated").send();
+    /// ```
+    pub fn send(&self) -> Result<SearchResults<Issue>, ureq::Error> {
+        let mut request = ureq::get(&format!("{}/search/issues", self.handler.client.base_url));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        request = request.query("q", &self.query);
+        if let Some(sort) = self.sort.clone() {
+            request = request.query("sort", &sort);
+        }
+        if let Some(order) = self.order.clone() {
+            request = request.query("order", &order);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        self.handler.send(request)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// e.g. `created`, `updated` or `comments`. The best match by default.
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// `desc` by default, or `asc`. Ignored unless `sort` is set.
+    pub fn order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for searching repositories.
+pub struct SearchRepositoriesBuilder<'a> {
+    handler: &'a SearchHandler<'a>,
+    query: String,
+    accept: Option<String>,
+    sort: Option<String>,
+    order: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> SearchRepositoriesBuilder<'a> {
+    fn new(handler: &'a SearchHandler, query: impl Into<String>) -> Self {
+        SearchRepositoriesBuilder {
+            handler,
+            query: query.into(),
+            accept: None,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Search repositories.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-repositories>.
+    /// ```no_run
+    /// use ghrs::search::SearchQuery;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let query = SearchQuery::new().term("rust").qualifier("language", "rust");
+    /// let results = client.search().repositories(query).sort("stars").send();
+    /// ```
+    pub fn send(&self) -> Result<SearchResults<Repository>, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/search/repositories",
+            self.handler.client.base_url
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        request = request.query("q", &self.query);
+        if let Some(sort) = self.sort.clone() {
+            request = request.query("sort", &sort);
+        }
+        if let Some(order) = self.order.clone() {
+            request = request.query("order", &order);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        self.handler.send(request)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// e.g. `stars`, `forks` or `updated`. The best match by default.
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// `desc` by default, or `asc`. Ignored unless `sort` is set.
+    pub fn order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for searching code.
+pub struct SearchCodeBuilder<'a> {
+    handler: &'a SearchHandler<'a>,
+    query: String,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> SearchCodeBuilder<'a> {
+    fn new(handler: &'a SearchHandler, query: impl Into<String>) -> Self {
+        SearchCodeBuilder {
+            handler,
+            query: query.into(),
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Search code.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-code>.
+    /// ```no_run
+    /// use ghrs::search::SearchQuery;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let query = SearchQuery::new().term("ureq").repo("owner", "repo");
+    /// let results = client.token("your_token").search().code(query).send();
+    /// ```
+    pub fn send(&self) -> Result<SearchResults<CodeSearchItem>, ureq::Error> {
+        let mut request = ureq::get(&format!("{}/search/code", self.handler.client.base_url));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        request = request.query("q", &self.query);
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        self.handler.send(request)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for searching commits.
+pub struct SearchCommitsBuilder<'a> {
+    handler: &'a SearchHandler<'a>,
+    query: String,
+    accept: Option<String>,
+    sort: Option<String>,
+    order: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> SearchCommitsBuilder<'a> {
+    fn new(handler: &'a SearchHandler, query: impl Into<String>) -> Self {
+        SearchCommitsBuilder {
+            handler,
+            query: query.into(),
+            accept: None,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Search commits.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-commits>.
+    /// ```no_run
+    /// use ghrs::search::SearchQuery;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let query = SearchQuery::new().term("fix").repo("owner", "repo");
+    /// let results = client.search().commits(query).sort("author-date").send();
+    /// ```
+    pub fn send(&self) -> Result<SearchResults<CommitSearchItem>, ureq::Error> {
+        let mut request = ureq::get(&format!("{}/search/commits", self.handler.client.base_url));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        request = request.query("q", &self.query);
+        if let Some(sort) = self.sort.clone() {
+            request = request.query("sort", &sort);
+        }
+        if let Some(order) = self.order.clone() {
+            request = request.query("order", &order);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        self.handler.send(request)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// e.g. `author-date` or `committer-date`. The best match by default.
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// `desc` by default, or `asc`. Ignored unless `sort` is set.
+    pub fn order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for searching users.
+pub struct SearchUsersBuilder<'a> {
+    handler: &'a SearchHandler<'a>,
+    query: String,
+    accept: Option<String>,
+    sort: Option<String>,
+    order: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> SearchUsersBuilder<'a> {
+    fn new(handler: &'a SearchHandler, query: impl Into<String>) -> Self {
+        SearchUsersBuilder {
+            handler,
+            query: query.into(),
+            accept: None,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Search users.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-users>.
+    /// ```no_run
+    /// use ghrs::search::SearchQuery;
+    ///
+    /// let client = ghrs::Client::new();
+    /// let query = SearchQuery::new().term("octo").qualifier("type", "user");
+    /// let results = client.search().users(query).send();
+    /// ```
+    pub fn send(&self) -> Result<SearchResults<User>, ureq::Error> {
+        let mut request = ureq::get(&format!("{}/search/users", self.handler.client.base_url));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        request = request.query("q", &self.query);
+        if let Some(sort) = self.sort.clone() {
+            request = request.query("sort", &sort);
+        }
+        if let Some(order) = self.order.clone() {
+            request = request.query("order", &order);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        self.handler.send(request)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// e.g. `followers`, `repositories` or `joined`. The best match by default.
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// `desc` by default, or `asc`. Ignored unless `sort` is set.
+    pub fn order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for searching labels.
+pub struct SearchLabelsBuilder<'a> {
+    handler: &'a SearchHandler<'a>,
+    repository_id: u64,
+    query: String,
+    accept: Option<String>,
+    sort: Option<String>,
+    order: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> SearchLabelsBuilder<'a> {
+    fn new(handler: &'a SearchHandler, repository_id: u64, query: impl Into<String>) -> Self {
+        SearchLabelsBuilder {
+            handler,
+            repository_id,
+            query: query.into(),
+            accept: None,
+            sort: None,
+            order: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// Search labels.
+    ///
+    /// See <https://docs.github.com/en/rest/search/search#search-labels>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let results = client.search().labels(1296269, "bug").send();
+    /// ```
+    pub fn send(&self) -> Result<SearchResults<Label>, ureq::Error> {
+        let mut request = ureq::get(&format!("{}/search/labels", self.handler.client.base_url));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        request = request.query("repository_id", &self.repository_id.to_string());
+        request = request.query("q", &self.query);
+        if let Some(sort) = self.sort.clone() {
+            request = request.query("sort", &sort);
+        }
+        if let Some(order) = self.order.clone() {
+            request = request.query("order", &order);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        self.handler.send(request)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// e.g. `created` or `updated`. The best match by default.
+    pub fn sort(mut self, sort: impl Into<String>) -> Self {
+        self.sort = Some(sort.into());
+        self
+    }
+
+    /// `desc` by default, or `asc`. Ignored unless `sort` is set.
+    pub fn order(mut self, order: impl Into<String>) -> Self {
+        self.order = Some(order.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
@@ -0,0 +1,819 @@
+//! The GitHub Actions Secrets and Variables API
+//!
+//! Secrets and variables exist for repositories, environments and organizations.
+//! The handlers are created with [`crate::repos::actions::ActionsHandler::secrets`],
+//! [`crate::repos::actions::ActionsHandler::environment_secrets`], [`crate::Client::org_secrets`]
+//! and the corresponding methods for variables.
+use crate::model::secrets::{PublicKey, Secret, Secrets, Variable, Variables};
+use crate::Client;
+
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use serde::Serialize;
+use std::io;
+
+/// Encrypt `value` with `public_key` to a libsodium-compatible sealed box, encoded in Base64.
+///
+/// ```
+/// let public_key = ghrs::model::secrets::PublicKey {
+///     key_id: "012345678912345678".to_string(),
+///     key: "hBT5WZEj8ZoOv6TYJsfWq7MxTEQopZO5/IT3ZCVQPzs=".to_string(),
+/// };
+/// let encrypted_value = ghrs::secrets::seal(&public_key, "npm_XXXX").unwrap();
+/// ```
+pub fn seal(public_key: &PublicKey, value: impl AsRef<[u8]>) -> Result<String, io::Error> {
+    let invalid = |message: &str| io::Error::new(io::ErrorKind::InvalidData, message.to_string());
+    let key = STANDARD
+        .decode(public_key.key.trim())
+        .map_err(|_| invalid("public key is not valid Base64"))?;
+    let key = crypto_box::PublicKey::from_slice(&key)
+        .map_err(|_| invalid("public key must be 32 bytes"))?;
+    let sealed = key
+        .seal(&mut crypto_box::aead::OsRng, value.as_ref())
+        .map_err(|_| invalid("failed to encrypt the value"))?;
+    Ok(STANDARD.encode(sealed))
+}
+
+/// A client for the secrets of a repository, an environment or an organization.
+///
+/// See <https://docs.github.com/en/rest/reference/actions#secrets>.
+pub struct SecretsHandler<'a> {
+    client: &'a Client,
+    path: String,
+}
+
+impl<'a> SecretsHandler<'a> {
+    /// `path` is a path of the scope without the base URL, e.g. `repos/owner/repo/actions`.
+    pub fn new(client: &'a Client, path: impl Into<String>) -> SecretsHandler<'a> {
+        SecretsHandler {
+            client,
+            path: path.into(),
+        }
+    }
+
+    /// Get a public key.
+    ///
+    /// Secrets must be encrypted with the key before they are created or updated.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#get-a-repository-public-key>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let public_key = client.repos("owner", "repo").actions().secrets().get_public_key().send();
+    /// ```
+    pub fn get_public_key(&self) -> GetPublicKeyBuilder<'_> {
+        GetPublicKeyBuilder::new(self)
+    }
+
+    /// List secrets.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-repository-secrets>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let secrets = client.repos("owner", "repo").actions().secrets().list().per_page(100).send();
+    /// ```
+    pub fn list(&self) -> ListSecretsBuilder<'_> {
+        ListSecretsBuilder::new(self)
+    }
+
+    /// Get a secret.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#get-a-repository-secret>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let secret = client.repos("owner", "repo").actions().secrets().get("NPM_TOKEN").send();
+    /// ```
+    pub fn get(&self, secret_name: impl Into<String>) -> GetSecretBuilder<'_> {
+        GetSecretBuilder::new(self, secret_name)
+    }
+
+    /// Create or update a secret.
+    ///
+    /// The value is encrypted with the public key, which is fetched unless it is set with
+    /// [`CreateOrUpdateSecretBuilder::public_key`].
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#create-or-update-a-repository-secret>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client
+    ///     .token("your_token")
+    ///     .repos("owner", "repo")
+    ///     .actions()
+    ///     .secrets()
+    ///     .create_or_update("NPM_TOKEN", "npm_XXXX")
+    ///     .send();
+    /// ```
+    pub fn create_or_update(
+        &self,
+        secret_name: impl Into<String>,
+        value: impl Into<Vec<u8>>,
+    ) -> CreateOrUpdateSecretBuilder<'_> {
+        CreateOrUpdateSecretBuilder::new(self, secret_name, value)
+    }
+
+    /// Delete a secret.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#delete-a-repository-secret>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().secrets().delete("NPM_TOKEN").send();
+    /// ```
+    pub fn delete(&self, secret_name: impl Into<String>) -> DeleteSecretBuilder<'_> {
+        DeleteSecretBuilder::new(self, secret_name)
+    }
+}
+
+/// A client for the configuration variables of a repository, an environment or an organization.
+///
+/// See <https://docs.github.com/en/rest/reference/actions#variables>.
+pub struct VariablesHandler<'a> {
+    client: &'a Client,
+    path: String,
+}
+
+impl<'a> VariablesHandler<'a> {
+    /// `path` is a path of the scope without the base URL, e.g. `repos/owner/repo/actions`.
+    pub fn new(client: &'a Client, path: impl Into<String>) -> VariablesHandler<'a> {
+        VariablesHandler {
+            client,
+            path: path.into(),
+        }
+    }
+
+    /// List variables.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-repository-variables>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let variables = client.repos("owner", "repo").actions().variables().list().per_page(30).send();
+    /// ```
+    pub fn list(&self) -> ListVariablesBuilder<'_> {
+        ListVariablesBuilder::new(self)
+    }
+
+    /// Get a variable.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#get-a-repository-variable>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let variable = client.repos("owner", "repo").actions().variables().get("USERNAME").send();
+    /// ```
+    pub fn get(&self, name: impl Into<String>) -> GetVariableBuilder<'_> {
+        GetVariableBuilder::new(self, name)
+    }
+
+    /// Create a variable.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#create-a-repository-variable>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().variables().create("USERNAME", "octocat").send();
+    /// ```
+    pub fn create(
+        &self,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> CreateVariableBuilder<'_> {
+        CreateVariableBuilder::new(self, name, value)
+    }
+
+    /// Update a variable.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#update-a-repository-variable>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().variables().update("USERNAME").value("monalisa").send();
+    /// ```
+    pub fn update(&self, variable_name: impl Into<String>) -> UpdateVariableBuilder<'_> {
+        UpdateVariableBuilder::new(self, variable_name)
+    }
+
+    /// Delete a variable.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#delete-a-repository-variable>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().variables().delete("USERNAME").send();
+    /// ```
+    pub fn delete(&self, name: impl Into<String>) -> DeleteVariableBuilder<'_> {
+        DeleteVariableBuilder::new(self, name)
+    }
+}
+
+/// A builder for getting a public key.
+pub struct GetPublicKeyBuilder<'a> {
+    handler: &'a SecretsHandler<'a>,
+    accept: Option<String>,
+}
+
+impl<'a> GetPublicKeyBuilder<'a> {
+    fn new(handler: &'a SecretsHandler) -> Self {
+        GetPublicKeyBuilder {
+            handler,
+            accept: None,
+        }
+    }
+
+    /// Get a public key.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#get-a-repository-public-key>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let public_key = client.repos("owner", "repo").actions().secrets().get_public_key().send();
+    /// ```
+    pub fn send(&self) -> Result<PublicKey, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/{}/secrets/public-key",
+            self.handler.client.base_url, self.handler.path
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let public_key: PublicKey = request.call()?.into_json()?;
+        Ok(public_key)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for listing secrets.
+pub struct ListSecretsBuilder<'a> {
+    handler: &'a SecretsHandler<'a>,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListSecretsBuilder<'a> {
+    fn new(handler: &'a SecretsHandler) -> Self {
+        ListSecretsBuilder {
+            handler,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List secrets.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-repository-secrets>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let secrets = client.repos("owner", "repo").actions().secrets().list().per_page(100).send();
+    /// ```
+    pub fn send(&self) -> Result<Secrets, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/{}/secrets",
+            self.handler.client.base_url, self.handler.path
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let secrets: Secrets = request.call()?.into_json()?;
+        Ok(secrets)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting a secret.
+pub struct GetSecretBuilder<'a> {
+    handler: &'a SecretsHandler<'a>,
+    secret_name: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetSecretBuilder<'a> {
+    fn new(handler: &'a SecretsHandler, secret_name: impl Into<String>) -> Self {
+        GetSecretBuilder {
+            handler,
+            secret_name: secret_name.into(),
+            accept: None,
+        }
+    }
+
+    /// Get a secret.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#get-a-repository-secret>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let secret = client.repos("owner", "repo").actions().secrets().get("NPM_TOKEN").send();
+    /// ```
+    pub fn send(&self) -> Result<Secret, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/{}/secrets/{}",
+            self.handler.client.base_url, self.handler.path, self.secret_name
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let secret: Secret = request.call()?.into_json()?;
+        Ok(secret)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for creating or updating a secret.
+pub struct CreateOrUpdateSecretBuilder<'a> {
+    handler: &'a SecretsHandler<'a>,
+    secret_name: String,
+    accept: Option<String>,
+    value: Vec<u8>,
+    public_key: Option<PublicKey>,
+    visibility: Option<String>,
+    selected_repository_ids: Option<Vec<u64>>,
+}
+
+#[derive(Serialize)]
+struct EncryptedSecret<'a> {
+    encrypted_value: String,
+    key_id: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selected_repository_ids: Option<&'a [u64]>,
+}
+
+impl<'a> CreateOrUpdateSecretBuilder<'a> {
+    fn new(
+        handler: &'a SecretsHandler,
+        secret_name: impl Into<String>,
+        value: impl Into<Vec<u8>>,
+    ) -> Self {
+        CreateOrUpdateSecretBuilder {
+            handler,
+            secret_name: secret_name.into(),
+            accept: None,
+            value: value.into(),
+            public_key: None,
+            visibility: None,
+            selected_repository_ids: None,
+        }
+    }
+
+    /// Create or update a secret.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#create-or-update-a-repository-secret>.
+    /// ```no_run
+    /// let client = ghrs::Client::new().token("your_token");
+    /// let secrets = client.repos("owner", "repo").actions().secrets();
+    /// let public_key = secrets.get_public_key().send().unwrap();
+    /// for (name, value) in [("NPM_TOKEN", "npm_XXXX"), ("PYPI_TOKEN", "pypi-XXXX")] {
+    ///     secrets
+    ///         .create_or_update(name, value)
+    ///         .public_key(public_key.clone())
+    ///         .send()
+    ///         .unwrap();
+    /// }
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let public_key = match self.public_key.clone() {
+            Some(public_key) => public_key,
+            None => self.handler.get_public_key().send()?,
+        };
+        let body = EncryptedSecret {
+            encrypted_value: seal(&public_key, &self.value)?,
+            key_id: &public_key.key_id,
+            visibility: self.visibility.as_deref(),
+            selected_repository_ids: self.selected_repository_ids.as_deref(),
+        };
+
+        let mut request = ureq::put(&format!(
+            "{}/{}/secrets/{}",
+            self.handler.client.base_url, self.handler.path, self.secret_name
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.send_json(body)?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Encrypt the value with `public_key` instead of fetching it, which saves a request per secret.
+    pub fn public_key(mut self, public_key: PublicKey) -> Self {
+        self.public_key = Some(public_key);
+        self
+    }
+
+    /// Only for organization secrets, `all`, `private` or `selected`.
+    pub fn visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
+    /// Only for organization secrets, the repositories which can access the secret if `visibility` is `selected`.
+    pub fn selected_repository_ids(mut self, selected_repository_ids: impl Into<Vec<u64>>) -> Self {
+        self.selected_repository_ids = Some(selected_repository_ids.into());
+        self
+    }
+}
+
+/// A builder for deleting a secret.
+pub struct DeleteSecretBuilder<'a> {
+    handler: &'a SecretsHandler<'a>,
+    secret_name: String,
+    accept: Option<String>,
+}
+
+impl<'a> DeleteSecretBuilder<'a> {
+    fn new(handler: &'a SecretsHandler, secret_name: impl Into<String>) -> Self {
+        DeleteSecretBuilder {
+            handler,
+            secret_name: secret_name.into(),
+            accept: None,
+        }
+    }
+
+    /// Delete a secret.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#delete-a-repository-secret>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().secrets().delete("NPM_TOKEN").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/{}/secrets/{}",
+            self.handler.client.base_url, self.handler.path, self.secret_name
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for listing variables.
+pub struct ListVariablesBuilder<'a> {
+    handler: &'a VariablesHandler<'a>,
+    accept: Option<String>,
+    per_page: Option<u8>,
+    page: Option<u8>,
+}
+
+impl<'a> ListVariablesBuilder<'a> {
+    fn new(handler: &'a VariablesHandler) -> Self {
+        ListVariablesBuilder {
+            handler,
+            accept: None,
+            per_page: None,
+            page: None,
+        }
+    }
+
+    /// List variables.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#list-repository-variables>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let variables = client.repos("owner", "repo").actions().variables().list().per_page(30).send();
+    /// ```
+    pub fn send(&self) -> Result<Variables, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/{}/variables",
+            self.handler.client.base_url, self.handler.path
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+        if let Some(per_page) = self.per_page {
+            request = request.query("per_page", &per_page.to_string());
+        }
+        if let Some(page) = self.page {
+            request = request.query("page", &page.to_string());
+        }
+
+        let variables: Variables = request.call()?.into_json()?;
+        Ok(variables)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    pub fn per_page(mut self, per_page: impl Into<u8>) -> Self {
+        self.per_page = Some(per_page.into());
+        self
+    }
+
+    pub fn page(mut self, page: impl Into<u8>) -> Self {
+        self.page = Some(page.into());
+        self
+    }
+}
+
+/// A builder for getting a variable.
+pub struct GetVariableBuilder<'a> {
+    handler: &'a VariablesHandler<'a>,
+    name: String,
+    accept: Option<String>,
+}
+
+impl<'a> GetVariableBuilder<'a> {
+    fn new(handler: &'a VariablesHandler, name: impl Into<String>) -> Self {
+        GetVariableBuilder {
+            handler,
+            name: name.into(),
+            accept: None,
+        }
+    }
+
+    /// Get a variable.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#get-a-repository-variable>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let variable = client.repos("owner", "repo").actions().variables().get("USERNAME").send();
+    /// ```
+    pub fn send(&self) -> Result<Variable, ureq::Error> {
+        let mut request = ureq::get(&format!(
+            "{}/{}/variables/{}",
+            self.handler.client.base_url, self.handler.path, self.name
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        let variable: Variable = request.call()?.into_json()?;
+        Ok(variable)
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
+
+/// A builder for creating a variable.
+#[derive(Serialize)]
+pub struct CreateVariableBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a VariablesHandler<'a>,
+    #[serde(skip)]
+    accept: Option<String>,
+    name: String,
+    value: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selected_repository_ids: Option<Vec<u64>>,
+}
+
+impl<'a> CreateVariableBuilder<'a> {
+    fn new(
+        handler: &'a VariablesHandler,
+        name: impl Into<String>,
+        value: impl Into<String>,
+    ) -> Self {
+        CreateVariableBuilder {
+            handler,
+            accept: None,
+            name: name.into(),
+            value: value.into(),
+            visibility: None,
+            selected_repository_ids: None,
+        }
+    }
+
+    /// Create a variable.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#create-a-repository-variable>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().variables().create("USERNAME", "octocat").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::post(&format!(
+            "{}/{}/variables",
+            self.handler.client.base_url, self.handler.path
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.send_json(self)?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Only for organization variables, `all`, `private` or `selected`.
+    pub fn visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
+    /// Only for organization variables, the repositories which can access the variable if `visibility` is `selected`.
+    pub fn selected_repository_ids(mut self, selected_repository_ids: impl Into<Vec<u64>>) -> Self {
+        self.selected_repository_ids = Some(selected_repository_ids.into());
+        self
+    }
+}
+
+/// A builder for updating a variable.
+#[derive(Serialize)]
+pub struct UpdateVariableBuilder<'a> {
+    #[serde(skip)]
+    handler: &'a VariablesHandler<'a>,
+    #[serde(skip)]
+    variable_name: String,
+    #[serde(skip)]
+    accept: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    value: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    visibility: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    selected_repository_ids: Option<Vec<u64>>,
+}
+
+impl<'a> UpdateVariableBuilder<'a> {
+    fn new(handler: &'a VariablesHandler, variable_name: impl Into<String>) -> Self {
+        UpdateVariableBuilder {
+            handler,
+            variable_name: variable_name.into(),
+            accept: None,
+            name: None,
+            value: None,
+            visibility: None,
+            selected_repository_ids: None,
+        }
+    }
+
+    /// Update a variable.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#update-a-repository-variable>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().variables().update("USERNAME").value("monalisa").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::patch(&format!(
+            "{}/{}/variables/{}",
+            self.handler.client.base_url, self.handler.path, self.variable_name
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.send_json(self)?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// Rename the variable.
+    pub fn name(mut self, name: impl Into<String>) -> Self {
+        self.name = Some(name.into());
+        self
+    }
+
+    pub fn value(mut self, value: impl Into<String>) -> Self {
+        self.value = Some(value.into());
+        self
+    }
+
+    /// Only for organization variables, `all`, `private` or `selected`.
+    pub fn visibility(mut self, visibility: impl Into<String>) -> Self {
+        self.visibility = Some(visibility.into());
+        self
+    }
+
+    /// Only for organization variables, the repositories which can access the variable if `visibility` is `selected`.
+    pub fn selected_repository_ids(mut self, selected_repository_ids: impl Into<Vec<u64>>) -> Self {
+        self.selected_repository_ids = Some(selected_repository_ids.into());
+        self
+    }
+}
+
+/// A builder for deleting a variable.
+pub struct DeleteVariableBuilder<'a> {
+    handler: &'a VariablesHandler<'a>,
+    name: String,
+    accept: Option<String>,
+}
+
+impl<'a> DeleteVariableBuilder<'a> {
+    fn new(handler: &'a VariablesHandler, name: impl Into<String>) -> Self {
+        DeleteVariableBuilder {
+            handler,
+            name: name.into(),
+            accept: None,
+        }
+    }
+
+    /// Delete a variable.
+    ///
+    /// See <https://docs.github.com/en/rest/reference/actions#delete-a-repository-variable>.
+    /// ```no_run
+    /// let client = ghrs::Client::new();
+    /// let result = client.repos("owner", "repo").actions().variables().delete("USERNAME").send();
+    /// ```
+    pub fn send(&self) -> Result<(), ureq::Error> {
+        let mut request = ureq::delete(&format!(
+            "{}/{}/variables/{}",
+            self.handler.client.base_url, self.handler.path, self.name
+        ));
+
+        if let Some(token) = self.handler.client.token.clone() {
+            request = request.set("Authorization", &format!("token {}", token));
+        }
+        if let Some(accept) = self.accept.clone() {
+            request = request.set("Accept", &accept);
+        }
+
+        request.call()?;
+        Ok(())
+    }
+
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+}
@@ -0,0 +1,369 @@
+//! Webhook deliveries
+//!
+//! Deliveries are verified with the `X-Hub-Signature-256` header and parsed into [`WebhookEvent`].
+//! See <https://docs.github.com/en/webhooks/webhook-events-and-payloads>.
+#[cfg(feature = "webhook-server")]
+pub mod server;
+
+use crate::model::event::{
+    Changes, CommitCommentEventPayload, CreateEventPayload, DeleteEventPayload,
+    DiscussionEventPayload, ForkEventPayload, GollumEventPayload, IssueCommentEventPayload,
+    IssuesEventPayload, MemberEventPayload, PublicEventPayload, PullRequestEventPayload,
+    PullRequestReviewCommentEventPayload, PullRequestReviewEventPayload,
+    PullRequestReviewThreadEventPayload, ReleaseEventPayload, SponsorshipEventPayload,
+    WatchEventPayload,
+};
+use crate::model::{Label, Repository, User};
+
+use chrono::{TimeZone, Utc};
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::Sha256;
+use std::io;
+
+/// The header of the event name, e.g. `pull_request`.
+pub const EVENT_HEADER: &str = "X-GitHub-Event";
+/// The header of the unique ID of a delivery.
+pub const DELIVERY_HEADER: &str = "X-GitHub-Delivery";
+/// The header of the HMAC SHA-256 signature of a body.
+pub const SIGNATURE_HEADER: &str = "X-Hub-Signature-256";
+
+/// A receiver of webhook deliveries signed with a secret.
+///
+/// ```
+/// use ghrs::webhook::{Webhook, WebhookEvent};
+///
+/// let webhook = Webhook::new("secret");
+/// let body = br#"{"zen": "Keep it logically awesome.", "hook_id": 1}"#;
+/// let signature = webhook.sign(body);
+/// let headers = vec![
+///     ("X-GitHub-Event", "ping"),
+///     ("X-GitHub-Delivery", "72d3162e-cc78-11e3-81ab-4c9367dc0958"),
+///     ("X-Hub-Signature-256", signature.as_str()),
+/// ];
+/// let delivery = webhook.receive(headers, body).unwrap();
+/// assert!(matches!(delivery.event, WebhookEvent::Ping(_)));
+///
+/// let headers = vec![
+///     ("X-GitHub-Event", "ping"),
+///     ("X-GitHub-Delivery", "72d3162e-cc78-11e3-81ab-4c9367dc0958"),
+///     ("X-Hub-Signature-256", "sha256=00"),
+/// ];
+/// assert!(webhook.receive(headers, body).is_err());
+/// ```
+#[derive(Clone)]
+pub struct Webhook {
+    secret: Vec<u8>,
+}
+
+impl std::fmt::Debug for Webhook {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Webhook").finish_non_exhaustive()
+    }
+}
+
+impl Webhook {
+    pub fn new(secret: impl AsRef<[u8]>) -> Webhook {
+        Webhook {
+            secret: secret.as_ref().to_vec(),
+        }
+    }
+
+    /// Returns the signature of `body` in the form of `X-Hub-Signature-256`, e.g. `sha256=757107...`.
+    pub fn sign(&self, body: &[u8]) -> String {
+        let mut mac = self.mac();
+        mac.update(body);
+        let digest = mac.finalize().into_bytes();
+        let hex: String = digest.iter().map(|byte| format!("{:02x}", byte)).collect();
+        format!("sha256={}", hex)
+    }
+
+    /// Returns `true` if `signature` is the signature of `body`, comparing them in constant time.
+    pub fn verify(&self, body: &[u8], signature: &str) -> bool {
+        let expected = match signature
+            .trim()
+            .strip_prefix("sha256=")
+            .and_then(decode_hex)
+        {
+            Some(expected) => expected,
+            None => return false,
+        };
+        let mut mac = self.mac();
+        mac.update(body);
+        mac.verify_slice(&expected).is_ok()
+    }
+
+    /// Verify and parse a delivery. Header names are case-insensitive.
+    ///
+    /// The error is `PermissionDenied` if the signature is missing or wrong,
+    /// `InvalidInput` if another header is missing and `InvalidData` if the body isn't JSON.
+    pub fn receive<'h>(
+        &self,
+        headers: impl IntoIterator<Item = (&'h str, &'h str)>,
+        body: &[u8],
+    ) -> Result<Delivery, io::Error> {
+        let mut event_name = None;
+        let mut delivery_id = None;
+        let mut signature = None;
+        for (name, value) in headers {
+            if name.eq_ignore_ascii_case(EVENT_HEADER) {
+                event_name = Some(value);
+            } else if name.eq_ignore_ascii_case(DELIVERY_HEADER) {
+                delivery_id = Some(value);
+            } else if name.eq_ignore_ascii_case(SIGNATURE_HEADER) {
+                signature = Some(value);
+            }
+        }
+
+        match signature {
+            Some(signature) if self.verify(body, signature) => {}
+            Some(_) => {
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    "the signature doesn't match",
+                ))
+            }
+            None => {
+                return Err(io::Error::new(
+                    io::ErrorKind::PermissionDenied,
+                    format!("`{}` is missing", SIGNATURE_HEADER),
+                ))
+            }
+        }
+        let missing = |header: &str| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("`{}` is missing", header),
+            )
+        };
+        let event_name = event_name.ok_or_else(|| missing(EVENT_HEADER))?;
+        let delivery_id = delivery_id.ok_or_else(|| missing(DELIVERY_HEADER))?;
+        Delivery::parse(delivery_id, event_name, body)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    fn mac(&self) -> Hmac<Sha256> {
+        // HMAC accepts a key of any length.
+        Hmac::<Sha256>::new_from_slice(&self.secret).expect("HMAC can take a key of any size")
+    }
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if hex.len() % 2 == 1 || !hex.is_ascii() {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).ok())
+        .collect()
+}
+
+/// A webhook delivery.
+#[derive(Clone, Debug, Serialize)]
+pub struct Delivery {
+    /// The value of `X-GitHub-Delivery`, which is the same if the delivery is redelivered.
+    pub id: String,
+    /// The value of `X-GitHub-Event`, e.g. `pull_request`.
+    pub event_name: String,
+    pub action: Option<String>,
+    pub repository: Option<Repository>,
+    pub sender: Option<User>,
+    pub installation: Option<Installation>,
+    pub event: WebhookEvent,
+}
+
+impl Delivery {
+    /// Parse a delivery without verifying it.
+    pub fn parse(
+        id: impl Into<String>,
+        event_name: impl Into<String>,
+        body: &[u8],
+    ) -> Result<Delivery, serde_json::Error> {
+        let event_name = event_name.into();
+        let mut raw: Value = serde_json::from_slice(body)?;
+        if let Some(repository) = raw.get_mut("repository") {
+            normalize_timestamps(repository);
+        }
+        let action = raw["action"].as_str().map(|action| action.to_string());
+        let repository = Option::<Repository>::deserialize(&raw["repository"]).unwrap_or(None);
+        let sender = Option::<User>::deserialize(&raw["sender"]).unwrap_or(None);
+        let installation =
+            Option::<Installation>::deserialize(&raw["installation"]).unwrap_or(None);
+        let event = WebhookEvent::parse(&event_name, raw);
+        Ok(Delivery {
+            id: id.into(),
+            event_name,
+            action,
+            repository,
+            sender,
+            installation,
+            event,
+        })
+    }
+}
+
+/// Repositories in `push` payloads have timestamps in seconds instead of ISO 8601.
+fn normalize_timestamps(repository: &mut Value) {
+    for field in ["created_at", "pushed_at"].iter() {
+        if let Some(seconds) = repository[*field].as_i64() {
+            if let Some(time) = Utc.timestamp_opt(seconds, 0).single() {
+                repository[*field] = Value::String(time.to_rfc3339());
+            }
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct Installation {
+    pub id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub node_id: Option<String>,
+}
+
+/// A typed webhook payload.
+///
+/// Payloads of events unknown to this crate, or which don't match their event,
+/// are kept as [`WebhookEvent::Unknown`].
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Serialize)]
+pub enum WebhookEvent {
+    CommitComment(CommitCommentEventPayload),
+    Create(CreateEventPayload),
+    Delete(DeleteEventPayload),
+    Discussion(DiscussionEventPayload),
+    Fork(ForkEventPayload),
+    Gollum(GollumEventPayload),
+    IssueComment(IssueCommentEventPayload),
+    Issues(IssuesEventPayload),
+    Label(LabelEventPayload),
+    Member(MemberEventPayload),
+    Ping(PingEventPayload),
+    Public(PublicEventPayload),
+    PullRequest(PullRequestEventPayload),
+    PullRequestReview(PullRequestReviewEventPayload),
+    PullRequestReviewComment(PullRequestReviewCommentEventPayload),
+    PullRequestReviewThread(PullRequestReviewThreadEventPayload),
+    Push(PushEventPayload),
+    Release(ReleaseEventPayload),
+    Sponsorship(SponsorshipEventPayload),
+    /// A `watch` event, which is sent when a repository is starred.
+    Watch(WatchEventPayload),
+    Unknown {
+        event_name: String,
+        raw: Value,
+    },
+}
+
+impl WebhookEvent {
+    /// Parse a payload of the event named `event_name`, e.g. `issues`.
+    pub fn parse(event_name: &str, raw: Value) -> WebhookEvent {
+        let event = match event_name {
+            "commit_comment" => {
+                CommitCommentEventPayload::deserialize(&raw).map(WebhookEvent::CommitComment)
+            }
+            "create" => CreateEventPayload::deserialize(&raw).map(WebhookEvent::Create),
+            "delete" => DeleteEventPayload::deserialize(&raw).map(WebhookEvent::Delete),
+            "discussion" => DiscussionEventPayload::deserialize(&raw).map(WebhookEvent::Discussion),
+            "fork" => ForkEventPayload::deserialize(&raw).map(WebhookEvent::Fork),
+            "gollum" => GollumEventPayload::deserialize(&raw).map(WebhookEvent::Gollum),
+            "issue_comment" => {
+                IssueCommentEventPayload::deserialize(&raw).map(WebhookEvent::IssueComment)
+            }
+            "issues" => IssuesEventPayload::deserialize(&raw).map(WebhookEvent::Issues),
+            "label" => LabelEventPayload::deserialize(&raw).map(WebhookEvent::Label),
+            "member" => MemberEventPayload::deserialize(&raw).map(WebhookEvent::Member),
+            "ping" => PingEventPayload::deserialize(&raw).map(WebhookEvent::Ping),
+            "public" => PublicEventPayload::deserialize(&raw).map(WebhookEvent::Public),
+            "pull_request" => {
+                PullRequestEventPayload::deserialize(&raw).map(WebhookEvent::PullRequest)
+            }
+            "pull_request_review" => PullRequestReviewEventPayload::deserialize(&raw)
+                .map(WebhookEvent::PullRequestReview),
+            "pull_request_review_comment" => {
+                PullRequestReviewCommentEventPayload::deserialize(&raw)
+                    .map(WebhookEvent::PullRequestReviewComment)
+            }
+            "pull_request_review_thread" => PullRequestReviewThreadEventPayload::deserialize(&raw)
+                .map(WebhookEvent::PullRequestReviewThread),
+            "push" => PushEventPayload::deserialize(&raw).map(WebhookEvent::Push),
+            "release" => ReleaseEventPayload::deserialize(&raw).map(WebhookEvent::Release),
+            "sponsorship" => {
+                SponsorshipEventPayload::deserialize(&raw).map(WebhookEvent::Sponsorship)
+            }
+            "watch" => WatchEventPayload::deserialize(&raw).map(WebhookEvent::Watch),
+            _ => return WebhookEvent::unknown(event_name, raw),
+        };
+        event.unwrap_or_else(|_| WebhookEvent::unknown(event_name, raw))
+    }
+
+    fn unknown(event_name: &str, raw: Value) -> WebhookEvent {
+        WebhookEvent::Unknown {
+            event_name: event_name.to_string(),
+            raw,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PingEventPayload {
+    pub zen: String,
+    pub hook_id: u64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub hook: Option<Value>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct LabelEventPayload {
+    /// `created`, `edited` or `deleted`.
+    pub action: String,
+    pub label: Label,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub changes: Option<Changes>,
+}
+
+/// The payload of a `push` webhook, which differs from the one of `PushEvent`.
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PushEventPayload {
+    pub r#ref: String,
+    pub before: String,
+    pub after: String,
+    pub created: bool,
+    pub deleted: bool,
+    pub forced: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub base_ref: Option<String>,
+    pub compare: String,
+    /// At most 20 commits, the oldest first.
+    pub commits: Vec<PushCommit>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub head_commit: Option<PushCommit>,
+    pub pusher: PushCommitAuthor,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PushCommit {
+    pub id: String,
+    pub tree_id: String,
+    pub distinct: bool,
+    pub message: String,
+    pub timestamp: chrono::DateTime<chrono::FixedOffset>,
+    pub url: String,
+    pub author: PushCommitAuthor,
+    pub committer: PushCommitAuthor,
+    #[serde(default)]
+    pub added: Vec<String>,
+    #[serde(default)]
+    pub removed: Vec<String>,
+    #[serde(default)]
+    pub modified: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize, Serialize)]
+pub struct PushCommitAuthor {
+    pub name: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub email: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub username: Option<String>,
+}
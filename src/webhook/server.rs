@@ -0,0 +1,278 @@
+//! A blocking server receiving webhook deliveries
+//!
+//! Deliveries are verified and answered with `202 Accepted` right away, and handlers run in a worker pool,
+//! since GitHub gives up on a delivery if it's not answered in 10 seconds.
+use super::{Delivery, Webhook};
+
+use std::io::{self, Read};
+use std::net::{SocketAddr, ToSocketAddrs};
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
+
+/// The maximum size of a payload GitHub sends.
+const MAX_PAYLOAD_SIZE: u64 = 25 * 1024 * 1024;
+
+/// How long to wait after an error of receiving a request not to spin.
+const ACCEPT_ERROR_BACKOFF: Duration = Duration::from_millis(100);
+
+type Handler = Box<dyn Fn(&Delivery) + Send + Sync + 'static>;
+
+struct Route {
+    event_name: Option<String>,
+    action: Option<String>,
+    handler: Handler,
+}
+
+impl Route {
+    fn matches(&self, delivery: &Delivery) -> bool {
+        let event_matches = match &self.event_name {
+            Some(event_name) => event_name == &delivery.event_name,
+            None => true,
+        };
+        let action_matches = match &self.action {
+            Some(action) => delivery.action.as_ref() == Some(action),
+            None => true,
+        };
+        event_matches && action_matches
+    }
+}
+
+/// A builder of a webhook server.
+///
+/// ```no_run
+/// use ghrs::webhook::server::WebhookServer;
+/// use ghrs::webhook::{Webhook, WebhookEvent};
+///
+/// let server = WebhookServer::new(Webhook::new("secret"))
+///     .on_action("pull_request", "opened", |delivery| {
+///         if let WebhookEvent::PullRequest(payload) = &delivery.event {
+///             println!("#{} is opened", payload.number);
+///         }
+///     })
+///     .on("push", |delivery| println!("pushed: {}", delivery.id))
+///     .workers(8)
+///     .bind("0.0.0.0:8080")
+///     .unwrap();
+/// server.run();
+/// ```
+pub struct WebhookServer {
+    webhook: Webhook,
+    routes: Vec<Route>,
+    workers: usize,
+}
+
+impl WebhookServer {
+    pub fn new(webhook: Webhook) -> WebhookServer {
+        WebhookServer {
+            webhook,
+            routes: Vec::new(),
+            workers: 4,
+        }
+    }
+
+    /// Call `handler` for deliveries of `event_name`, e.g. `issues`.
+    pub fn on<F>(self, event_name: impl Into<String>, handler: F) -> Self
+    where
+        F: Fn(&Delivery) + Send + Sync + 'static,
+    {
+        self.route(Some(event_name.into()), None, handler)
+    }
+
+    /// Call `handler` for deliveries of `event_name` with `action`, e.g. `issues` and `opened`.
+    pub fn on_action<F>(
+        self,
+        event_name: impl Into<String>,
+        action: impl Into<String>,
+        handler: F,
+    ) -> Self
+    where
+        F: Fn(&Delivery) + Send + Sync + 'static,
+    {
+        self.route(Some(event_name.into()), Some(action.into()), handler)
+    }
+
+    /// Call `handler` for every delivery.
+    pub fn on_any<F>(self, handler: F) -> Self
+    where
+        F: Fn(&Delivery) + Send + Sync + 'static,
+    {
+        self.route(None, None, handler)
+    }
+
+    /// Set the number of threads running handlers, 4 by default.
+    pub fn workers(mut self, workers: usize) -> Self {
+        self.workers = workers.max(1);
+        self
+    }
+
+    /// Listen on `addr`, e.g. `127.0.0.1:0` for a random port.
+    pub fn bind(self, addr: impl ToSocketAddrs) -> Result<BoundWebhookServer, io::Error> {
+        let server = tiny_http::Server::http(addr)
+            .map_err(|e| io::Error::new(io::ErrorKind::AddrNotAvailable, e.to_string()))?;
+        Ok(BoundWebhookServer {
+            server: Arc::new(server),
+            webhook: self.webhook,
+            routes: Arc::new(self.routes),
+            workers: self.workers,
+            stopped: Arc::new(AtomicBool::new(false)),
+        })
+    }
+
+    fn route<F>(mut self, event_name: Option<String>, action: Option<String>, handler: F) -> Self
+    where
+        F: Fn(&Delivery) + Send + Sync + 'static,
+    {
+        self.routes.push(Route {
+            event_name,
+            action,
+            handler: Box::new(handler),
+        });
+        self
+    }
+}
+
+/// A webhook server listening on an address.
+pub struct BoundWebhookServer {
+    server: Arc<tiny_http::Server>,
+    webhook: Webhook,
+    routes: Arc<Vec<Route>>,
+    workers: usize,
+    stopped: Arc<AtomicBool>,
+}
+
+impl BoundWebhookServer {
+    /// Returns the address the server is listening on.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    /// Returns a handle to stop the server from another thread.
+    pub fn shutdown_handle(&self) -> ShutdownHandle {
+        ShutdownHandle {
+            server: Arc::clone(&self.server),
+            stopped: Arc::clone(&self.stopped),
+        }
+    }
+
+    /// Receive deliveries until the server is stopped with [`ShutdownHandle::shutdown`].
+    ///
+    /// Deliveries which are accepted are handled before this returns.
+    pub fn run(self) {
+        let (sender, receiver) = mpsc::channel::<Delivery>();
+        let receiver = Arc::new(Mutex::new(receiver));
+        let workers: Vec<thread::JoinHandle<()>> = (0..self.workers)
+            .map(|_| {
+                let receiver = Arc::clone(&receiver);
+                let routes = Arc::clone(&self.routes);
+                thread::spawn(move || loop {
+                    let delivery = match receiver.lock() {
+                        Ok(receiver) => receiver.recv(),
+                        Err(_) => return,
+                    };
+                    match delivery {
+                        Ok(delivery) => dispatch(&routes, &delivery),
+                        Err(_) => return,
+                    }
+                })
+            })
+            .collect();
+
+        while !self.stopped.load(Ordering::SeqCst) {
+            let request = match self.server.recv() {
+                Ok(request) => request,
+                Err(_) => {
+                    // Errors of accepting connections, e.g. too many open files, may repeat.
+                    if !self.stopped.load(Ordering::SeqCst) {
+                        thread::sleep(ACCEPT_ERROR_BACKOFF);
+                    }
+                    continue;
+                }
+            };
+            if let Some(delivery) = self.accept(request) {
+                let _ = sender.send(delivery);
+            }
+        }
+
+        drop(sender);
+        for worker in workers {
+            let _ = worker.join();
+        }
+    }
+
+    /// Verify a request and answer it. Returns the delivery if it's accepted.
+    fn accept(&self, mut request: tiny_http::Request) -> Option<Delivery> {
+        if request.method() != &tiny_http::Method::Post {
+            let _ = request.respond(tiny_http::Response::empty(405));
+            return None;
+        }
+
+        let mut body = Vec::new();
+        let read = request
+            .as_reader()
+            .take(MAX_PAYLOAD_SIZE + 1)
+            .read_to_end(&mut body);
+        if read.is_err() || body.len() as u64 > MAX_PAYLOAD_SIZE {
+            let _ = request.respond(tiny_http::Response::empty(413));
+            return None;
+        }
+
+        let headers: Vec<(String, String)> = request
+            .headers()
+            .iter()
+            .map(|header| {
+                (
+                    header.field.as_str().to_string(),
+                    header.value.as_str().to_string(),
+                )
+            })
+            .collect();
+        let received = self.webhook.receive(
+            headers
+                .iter()
+                .map(|(name, value)| (name.as_str(), value.as_str())),
+            &body,
+        );
+        match received {
+            Ok(delivery) => {
+                let _ = request.respond(tiny_http::Response::empty(202));
+                Some(delivery)
+            }
+            Err(error) => {
+                let status = match error.kind() {
+                    io::ErrorKind::PermissionDenied => 401,
+                    _ => 400,
+                };
+                let _ = request.respond(
+                    tiny_http::Response::from_string(error.to_string()).with_status_code(status),
+                );
+                None
+            }
+        }
+    }
+}
+
+/// Call handlers matching `delivery`. A panic in a handler doesn't stop the worker.
+fn dispatch(routes: &[Route], delivery: &Delivery) {
+    for route in routes.iter().filter(|route| route.matches(delivery)) {
+        let _ = panic::catch_unwind(AssertUnwindSafe(|| (route.handler)(delivery)));
+    }
+}
+
+/// A handle to stop a [`BoundWebhookServer`].
+#[derive(Clone)]
+pub struct ShutdownHandle {
+    server: Arc<tiny_http::Server>,
+    stopped: Arc<AtomicBool>,
+}
+
+impl ShutdownHandle {
+    /// Stop receiving deliveries.
+    pub fn shutdown(&self) {
+        self.stopped.store(true, Ordering::SeqCst);
+        self.server.unblock();
+    }
+}
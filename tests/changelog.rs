@@ -0,0 +1,130 @@
+use ghrs::changelog::ChangelogRules;
+use ghrs::model::{Label, PullRequest};
+
+fn pull_request(
+    number: u64,
+    author: &str,
+    labels: &[&str],
+    merged_at: Option<&str>,
+) -> PullRequest {
+    let mut pull_request: PullRequest =
+        serde_json::from_str(include_str!("models/pull_request.json")).unwrap();
+    let label = pull_request.labels[0].clone();
+    pull_request.number = number;
+    pull_request.title = format!("Change {}", number);
+    pull_request.html_url = format!("https://github.com/octocat/Hello-World/pull/{}", number);
+    pull_request.user.login = author.to_string();
+    pull_request.labels = labels
+        .iter()
+        .map(|name| Label {
+            name: name.to_string(),
+            ..label.clone()
+        })
+        .collect();
+    pull_request.merged_at = merged_at.map(|merged_at| merged_at.parse().unwrap());
+    pull_request
+}
+
+fn rules() -> ChangelogRules {
+    ChangelogRules::new()
+        .section("Features", &["feature", "enhancement"])
+        .section("Bug Fixes", &["bug"])
+        .exclude_labels(&["skip-changelog"])
+}
+
+#[test]
+fn build_test() {
+    let pull_requests = vec![
+        pull_request(4, "carol", &["Bug"], Some("2021-01-04T00:00:00Z")),
+        pull_request(1, "alice", &["docs"], Some("2021-01-01T00:00:00Z")),
+        pull_request(
+            2,
+            "bob",
+            &["enhancement", "bug"],
+            Some("2021-01-02T00:00:00Z"),
+        ),
+        pull_request(3, "alice", &["feature"], Some("2021-01-03T00:00:00Z")),
+        pull_request(5, "dave", &["feature"], None),
+        pull_request(6, "erin", &["skip-changelog"], Some("2021-01-05T00:00:00Z")),
+    ];
+    let changelog = rules().build(&pull_requests, |login| login == "bob");
+
+    let sections: Vec<(&str, Vec<u64>)> = changelog
+        .sections
+        .iter()
+        .map(|section| {
+            (
+                section.title.as_str(),
+                section.entries.iter().map(|entry| entry.number).collect(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        sections,
+        vec![
+            ("Features", vec![2, 3]),
+            ("Bug Fixes", vec![4]),
+            ("Other Changes", vec![1]),
+        ]
+    );
+
+    let contributors: Vec<(&str, &[u64])> = changelog
+        .contributors
+        .iter()
+        .map(|contributor| {
+            (
+                contributor.login.as_str(),
+                contributor.pull_requests.as_slice(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        contributors,
+        vec![
+            ("alice", &[1, 3][..]),
+            ("bob", &[2][..]),
+            ("carol", &[4][..])
+        ]
+    );
+    let first_timers: Vec<&str> = changelog
+        .first_time_contributors()
+        .map(|contributor| contributor.login.as_str())
+        .collect();
+    assert_eq!(first_timers, vec!["bob"]);
+}
+
+#[test]
+fn omit_unmatched_test() {
+    let pull_requests = vec![pull_request(1, "alice", &[], Some("2021-01-01T00:00:00Z"))];
+    let changelog = rules().omit_unmatched().build(&pull_requests, |_| false);
+    assert!(changelog.sections.is_empty());
+    assert!(changelog.contributors.is_empty());
+}
+
+#[test]
+fn to_markdown_test() {
+    let pull_requests = vec![
+        pull_request(1, "alice", &["feature"], Some("2021-01-01T00:00:00Z")),
+        pull_request(2, "bob", &[], Some("2021-01-02T00:00:00Z")),
+    ];
+    let changelog = rules().build(&pull_requests, |login| login == "bob");
+    assert_eq!(
+        changelog.to_markdown(),
+        "## Features
+- Change 1 by @alice in [#1](https://github.com/octocat/Hello-World/pull/1)
+
+## Other Changes
+- Change 2 by @bob in [#2](https://github.com/octocat/Hello-World/pull/2)
+
+## New Contributors
+- @bob made their first contribution in [#2](https://github.com/octocat/Hello-World/pull/2)
+
+## Contributors
+@alice, @bob
+"
+    );
+
+    let json: serde_json::Value = serde_json::from_str(&changelog.to_json()).unwrap();
+    assert_eq!(json["sections"][0]["entries"][0]["number"], 1);
+    assert_eq!(json["contributors"][1]["first_time"], true);
+}
@@ -0,0 +1,123 @@
+use ghrs::codeowners::{approvers, CodeOwners, Owner};
+use ghrs::model::{PullRequestFile, Review};
+
+const CODEOWNERS: &str = r#"
+# This is a comment.
+*       @global-owner1 @global-owner2
+*.js    @js-owner # This is an inline comment.
+*.go docs@example.com
+/build/logs/ @doctocat
+docs/*  docs@example.com
+apps/ @octocat
+/docs/ @doctocat
+/scripts/ @doctocat @octo-org/octocats
+**/logs @octocat
+/apps/github
+!negated @octocat
+src/ not-an-owner
+"#;
+
+fn user(login: &str) -> Owner {
+    Owner::User(login.to_string())
+}
+
+#[test]
+fn parse_test() {
+    let codeowners = CodeOwners::parse(CODEOWNERS);
+    assert_eq!(codeowners.rules().len(), 10);
+    assert_eq!(codeowners.errors().len(), 2);
+    assert_eq!(codeowners.errors()[0].line, 13);
+    assert_eq!(codeowners.errors()[1].line, 14);
+    assert_eq!(
+        codeowners.rules()[7].owners,
+        vec![
+            user("doctocat"),
+            Owner::Team {
+                org: "octo-org".to_string(),
+                slug: "octocats".to_string()
+            }
+        ]
+    );
+    assert_eq!(
+        codeowners.rules()[7].owners[1].to_string(),
+        "@octo-org/octocats"
+    );
+}
+
+#[test]
+fn owners_test() {
+    let codeowners = CodeOwners::parse(CODEOWNERS);
+    assert_eq!(
+        codeowners.owners("README.md"),
+        &[user("global-owner1"), user("global-owner2")]
+    );
+    assert_eq!(codeowners.owners("src/index.js"), &[user("js-owner")]);
+    assert_eq!(
+        codeowners.owners("main.go"),
+        &[Owner::Email("docs@example.com".to_string())]
+    );
+    assert_eq!(
+        codeowners.owners("build/logs/today.log"),
+        &[user("octocat")]
+    );
+    assert_eq!(
+        codeowners.owners("build/logs/2020/today.md"),
+        &[user("octocat")]
+    );
+    assert_eq!(codeowners.owners("foo/docs/getting-started.md").len(), 2);
+    assert_eq!(
+        codeowners.owners("foo/docs/build-app/troubleshooting.md"),
+        &[user("global-owner1"), user("global-owner2")]
+    );
+    assert_eq!(
+        codeowners.owners("docs/build-app/troubleshooting.md"),
+        &[user("doctocat")]
+    );
+    assert_eq!(codeowners.owners("lib/apps/main.rs"), &[user("octocat")]);
+    // The last matching pattern has no owners.
+    assert!(codeowners.owners("apps/github/main.rs").is_empty());
+    assert!(codeowners.rule_for("apps/github/main.rs").is_some());
+}
+
+#[test]
+fn report_test() {
+    let codeowners = CodeOwners::parse("*.txt @octocat\n/docs/ @octo-org/writers\n");
+    let files: Vec<PullRequestFile> =
+        serde_json::from_str(include_str!("models/pull_request_files.json")).unwrap();
+    let reviews: Vec<Review> = serde_json::from_str(include_str!("models/reviews.json")).unwrap();
+
+    let approvers = approvers(&reviews);
+    assert_eq!(approvers, vec!["octocat"]);
+
+    let report = codeowners.report(
+        files.iter().map(|file| file.filename.as_str()),
+        &approvers,
+        |_, _| vec!["hubot".to_string()],
+    );
+    assert_eq!(report.requirements.len(), 2);
+    assert_eq!(report.requirements[0].approved_by, vec!["octocat"]);
+    assert!(!report.is_satisfied());
+    let missing: Vec<_> = report.missing().collect();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].paths, vec!["docs/guide.md"]);
+}
+
+#[test]
+fn report_email_owner_test() {
+    let codeowners = CodeOwners::parse(
+        "*.md docs@example.com
+*.txt docs@example.com @octocat
+",
+    );
+    let approvers = vec!["docs".to_string(), "octocat".to_string()];
+    let report = codeowners.report(vec!["README.md", "notes.txt"], &approvers, |_, _| {
+        panic!("no team owners")
+    });
+
+    // Email owners can't approve, so only the path also owned by a login is approved.
+    assert!(!report.is_satisfied());
+    let missing: Vec<_> = report.missing().collect();
+    assert_eq!(missing.len(), 1);
+    assert_eq!(missing[0].paths, vec!["README.md"]);
+    assert_eq!(report.requirements[1].approved_by, vec!["octocat"]);
+}
@@ -0,0 +1,106 @@
+use ghrs::events::{EventFeed, WatchCursor};
+use ghrs::model::event::Event;
+
+use std::io::{BufRead, BufReader, Write};
+use std::net::TcpListener;
+use std::thread;
+
+fn events() -> Vec<Event> {
+    serde_json::from_str(include_str!("models/events.json")).unwrap()
+}
+
+#[test]
+fn take_new_test() {
+    let mut cursor = WatchCursor::default();
+
+    // Events are returned newest first, and the same event may be on two pages.
+    let mut feed: Vec<Event> = events().into_iter().take(3).rev().collect();
+    feed.push(feed[0].clone());
+    let new_events = cursor.take_new(feed);
+    let ids: Vec<&str> = new_events.iter().map(|event| event.id.as_str()).collect();
+    assert_eq!(ids, vec!["1", "2", "3"]);
+    assert!(cursor.has_seen("2"));
+
+    let new_events = cursor.take_new(events().into_iter().rev().collect());
+    let ids: Vec<&str> = new_events.iter().map(|event| event.id.as_str()).collect();
+    assert_eq!(ids, vec!["4", "5", "6", "7", "8"]);
+    assert!(cursor.take_new(events()).is_empty());
+}
+
+#[test]
+fn cursor_persistence_test() {
+    let mut cursor = WatchCursor::default();
+    cursor.take_new(events());
+    let json = serde_json::to_string(&cursor).unwrap();
+    let mut resumed: WatchCursor = serde_json::from_str(&json).unwrap();
+    assert_eq!(resumed, cursor);
+    assert!(resumed.take_new(events()).is_empty());
+    assert_eq!(resumed.poll_interval().as_secs(), 60);
+}
+
+/// Serve `responses` in order, and returns the base URL and the handle returning the received requests.
+fn serve(responses: Vec<String>) -> (String, thread::JoinHandle<Vec<String>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request = String::new();
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                request.push_str(&line);
+            }
+            requests.push(request);
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        requests
+    });
+    (base_url, handle)
+}
+
+fn response(status: &str, headers: &[String], body: &str) -> String {
+    let mut response = format!("HTTP/1.1 {}\r\nConnection: close\r\n", status);
+    for header in headers {
+        response.push_str(header);
+        response.push_str("\r\n");
+    }
+    response.push_str(&format!("Content-Length: {}\r\n\r\n{}", body.len(), body));
+    response
+}
+
+#[test]
+fn poll_retry_test() {
+    let events: Vec<serde_json::Value> =
+        serde_json::from_str(include_str!("models/events.json")).unwrap();
+    let page1 = serde_json::to_string(&[&events[1]]).unwrap();
+    let page2 = serde_json::to_string(&[&events[0]]).unwrap();
+    // Only the existence of the next page matters.
+    let next = "Link: <http://localhost/page2>; rel=\"next\"".to_string();
+    let etag = "ETag: \"a\"".to_string();
+
+    // Page 2 fails first, and the poll is retried.
+    let (base_url, handle) = serve(vec![
+        response("200 OK", &[etag.clone(), next.clone()], &page1),
+        response("500 Internal Server Error", &[], ""),
+        response("200 OK", &[etag.clone(), next], &page1),
+        response("200 OK", &[], &page2),
+    ]);
+    let client = ghrs::Client::new().base_url(base_url);
+    let mut watcher = client.events().watch(EventFeed::repo("owner", "repo"));
+    assert!(watcher.poll().is_err());
+    assert_eq!(watcher.current_cursor().etag(), None);
+
+    let events = watcher.poll().unwrap();
+    let ids: Vec<&str> = events.iter().map(|event| event.id.as_str()).collect();
+    assert_eq!(ids, vec!["1", "2"]);
+    assert_eq!(watcher.current_cursor().etag(), Some("\"a\""));
+
+    let requests = handle.join().unwrap();
+    assert!(!requests[2].to_lowercase().contains("if-none-match"));
+}
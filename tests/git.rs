@@ -0,0 +1,160 @@
+use ghrs::repos::git::FileChange;
+
+use serde_json::{json, Value};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::TcpListener;
+use std::thread;
+
+/// Serve `responses` in order, and returns the base URL and the handle returning the received requests.
+fn serve(responses: Vec<Value>) -> (String, thread::JoinHandle<Vec<(String, String)>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let base_url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = thread::spawn(move || {
+        let mut requests = Vec::new();
+        for response in responses {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut reader = BufReader::new(stream.try_clone().unwrap());
+            let mut request_line = String::new();
+            reader.read_line(&mut request_line).unwrap();
+            let mut content_length = 0;
+            loop {
+                let mut line = String::new();
+                reader.read_line(&mut line).unwrap();
+                if line == "\r\n" || line.is_empty() {
+                    break;
+                }
+                if let Some((name, value)) = line.split_once(':') {
+                    if name.eq_ignore_ascii_case("content-length") {
+                        content_length = value.trim().parse().unwrap();
+                    }
+                }
+            }
+            let mut body = vec![0; content_length];
+            reader.read_exact(&mut body).unwrap();
+            requests.push((
+                request_line.trim_end().to_string(),
+                String::from_utf8(body).unwrap(),
+            ));
+
+            let body = response.to_string();
+            let response = format!(
+                "HTTP/1.1 200 OK\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{}",
+                body.len(),
+                body
+            );
+            stream.write_all(response.as_bytes()).unwrap();
+        }
+        requests
+    });
+    (base_url, handle)
+}
+
+fn tree(sha: &str, entries: Value) -> Value {
+    json!({ "sha": sha, "url": "", "tree": entries, "truncated": false })
+}
+
+fn entry(path: &str, mode: &str, r#type: &str, sha: &str) -> Value {
+    json!({ "path": path, "mode": mode, "type": r#type, "sha": sha })
+}
+
+fn commit(sha: &str, tree: &str) -> Value {
+    let author = json!({ "name": "Monalisa Octocat", "email": "octocat@github.com" });
+    json!({
+        "sha": sha,
+        "node_id": "",
+        "url": "",
+        "html_url": "",
+        "author": author,
+        "committer": author,
+        "message": "",
+        "tree": { "sha": tree, "url": "" },
+        "parents": []
+    })
+}
+
+fn reference(sha: &str) -> Value {
+    json!({
+        "ref": "refs/heads/main",
+        "node_id": "",
+        "url": "",
+        "object": { "type": "commit", "sha": sha, "url": "" }
+    })
+}
+
+#[test]
+fn commit_files_truncated_tree_test() {
+    let truncated: Value =
+        serde_json::from_str(include_str!("models/git_tree_truncated.json")).unwrap();
+    let (base_url, handle) = serve(vec![
+        reference("head"),
+        commit("head", "root"),
+        truncated,
+        tree(
+            "root",
+            json!([
+                entry("README.md", "100644", "blob", "readme"),
+                entry("bin", "040000", "tree", "bin")
+            ]),
+        ),
+        tree(
+            "bin",
+            json!([
+                entry("run", "100755", "blob", "run"),
+                entry("link", "120000", "blob", "link")
+            ]),
+        ),
+        json!({ "sha": "blob1", "url": "" }),
+        json!({ "sha": "blob2", "url": "" }),
+        json!({ "sha": "blob3", "url": "" }),
+        tree("new", json!([])),
+        commit("new", "new"),
+        reference("new"),
+    ]);
+    let client = ghrs::Client::new().base_url(base_url);
+    let commit = client
+        .repos("owner", "repo")
+        .git()
+        .commit_files(
+            "refs/heads/main",
+            vec![
+                FileChange::write("bin/run", "#!/bin/sh\n"),
+                FileChange::write("bin/link", "../README.md"),
+                FileChange::write("bin/new", "new"),
+            ],
+            "Update bin",
+        )
+        .unwrap();
+    assert_eq!(commit.sha, "new");
+
+    // The directories are fetched one by one, and only once.
+    let requests = handle.join().unwrap();
+    let lines: Vec<&str> = requests.iter().map(|(line, _)| line.as_str()).collect();
+    assert_eq!(
+        lines[2],
+        "GET /repos/owner/repo/git/trees/root?recursive=true HTTP/1.1"
+    );
+    assert_eq!(lines[3], "GET /repos/owner/repo/git/trees/root HTTP/1.1");
+    assert_eq!(lines[4], "GET /repos/owner/repo/git/trees/bin HTTP/1.1");
+    assert_eq!(lines[8], "POST /repos/owner/repo/git/trees HTTP/1.1");
+
+    let new_tree: Value = serde_json::from_str(&requests[8].1).unwrap();
+    let modes: Vec<(&str, &str)> = new_tree["tree"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .map(|entry| {
+            (
+                entry["path"].as_str().unwrap(),
+                entry["mode"].as_str().unwrap(),
+            )
+        })
+        .collect();
+    assert_eq!(
+        modes,
+        vec![
+            ("bin/run", "100755"),
+            ("bin/link", "120000"),
+            ("bin/new", "100644")
+        ]
+    );
+}
@@ -0,0 +1,71 @@
+use ghrs::hooks::undelivered;
+use ghrs::model::hooks::{Hook, HookConfig, HookDelivery, HookDeliveryDetail};
+use ghrs::webhook::WebhookEvent;
+
+#[test]
+fn hook_test() {
+    let hook: Hook = serde_json::from_str(include_str!("models/hook.json")).unwrap();
+    assert_eq!(hook.events, vec!["push", "pull_request"]);
+    assert_eq!(hook.config.content_type.as_deref(), Some("json"));
+    assert!(hook.test_url.is_some());
+
+    // Unset fields aren't sent.
+    let config = HookConfig::json("https://example.com/webhook");
+    assert_eq!(
+        serde_json::to_value(&config).unwrap(),
+        serde_json::json!({"url": "https://example.com/webhook", "content_type": "json"})
+    );
+}
+
+#[test]
+fn hook_delivery_test() {
+    let detail: HookDeliveryDetail =
+        serde_json::from_str(include_str!("models/hook_delivery.json")).unwrap();
+    assert!(!detail.delivery.is_success());
+    assert_eq!(
+        detail.response.payload.as_deref(),
+        Some("Service Unavailable")
+    );
+
+    // A missed delivery can be handled locally.
+    let delivery = detail.to_delivery().unwrap();
+    assert_eq!(delivery.id, "0b989ba4-242f-11e5-81e1-c7b6966d2516");
+    assert_eq!(delivery.action.as_deref(), Some("edited"));
+    assert!(matches!(delivery.event, WebhookEvent::Issues(_)));
+}
+
+fn delivery(id: u64, guid: &str, minute: u32, status_code: u16) -> HookDelivery {
+    serde_json::from_value(serde_json::json!({
+        "id": id,
+        "guid": guid,
+        "delivered_at": format!("2024-01-01T00:{:02}:00Z", minute),
+        "redelivery": false,
+        "duration": 0.1,
+        "status": "",
+        "status_code": status_code,
+        "event": "push",
+        "action": null,
+    }))
+    .unwrap()
+}
+
+#[test]
+fn undelivered_test() {
+    // The latest delivery comes first like the API.
+    let deliveries = vec![
+        delivery(6, "d", 50, 0),
+        delivery(5, "a", 40, 500),
+        delivery(4, "c", 30, 202),
+        delivery(3, "b", 20, 200),
+        delivery(2, "a", 10, 502),
+        delivery(1, "b", 5, 503),
+        delivery(0, "e", 0, 500),
+    ];
+    let since = "2024-01-01T00:05:00Z".parse().unwrap();
+    let ids: Vec<u64> = undelivered(&deliveries, since)
+        .iter()
+        .map(|delivery| delivery.id)
+        .collect();
+    // `a` is redelivered with its latest attempt, `b` succeeded and `e` is too old.
+    assert_eq!(ids, vec![5, 6]);
+}
@@ -1,4 +1,17 @@
-use ghrs::model::{Issue, PullRequest};
+use ghrs::model::actions::WorkflowRuns;
+use ghrs::model::branches::{BranchProtection, NewRequiredReviews, NewRequiredStatusChecks};
+use ghrs::model::checks::{
+    AnnotationLevel, CheckAnnotation, CheckConclusion, CheckRuns, CheckStatus, NewCheckRunOutput,
+};
+use ghrs::model::collaborators::{Collaborator, Invitation, Permission, RepositoryPermission};
+use ghrs::model::commits::Comparison;
+use ghrs::model::contents::Content;
+use ghrs::model::event::{Changes, Event, EventType, Payload};
+use ghrs::model::git::{NewTreeEntry, Tree};
+use ghrs::model::notifications::{Notification, SubjectKind};
+use ghrs::model::releases::Release;
+use ghrs::model::statuses::{CombinedStatus, StatusState};
+use ghrs::model::{Issue, PullRequest, Repository};
 
 #[test]
 fn pull_request_de_test() {
@@ -9,3 +22,301 @@ fn pull_request_de_test() {
 fn issue_de_test() {
     let _: Issue = serde_json::from_str(include_str!("models/issue.json")).unwrap();
 }
+
+#[test]
+fn null_body_de_test() {
+    let mut json: serde_json::Value =
+        serde_json::from_str(include_str!("models/pull_request.json")).unwrap();
+    json["body"] = serde_json::Value::Null;
+    let pull_request: PullRequest = serde_json::from_value(json).unwrap();
+    assert_eq!(pull_request.body, "");
+
+    let mut json: serde_json::Value =
+        serde_json::from_str(include_str!("models/issue.json")).unwrap();
+    json["body"] = serde_json::Value::Null;
+    let issue: Issue = serde_json::from_value(json).unwrap();
+    assert_eq!(issue.body, "");
+}
+
+#[test]
+fn repository_de_test() {
+    let repository: Repository =
+        serde_json::from_str(include_str!("models/repository.json")).unwrap();
+    // Repositories without descriptions have `null` descriptions.
+    assert_eq!(repository.description, "");
+}
+
+#[test]
+fn content_de_test() {
+    match serde_json::from_str(include_str!("models/content_file.json")).unwrap() {
+        Content::File(file) => assert!(file.text().unwrap().starts_with("# ghrs\n")),
+        content => panic!("unexpected content: {:?}", content),
+    }
+    match serde_json::from_str(include_str!("models/content_dir.json")).unwrap() {
+        Content::Dir(entries) => assert_eq!(entries.len(), 2),
+        content => panic!("unexpected content: {:?}", content),
+    }
+}
+
+#[test]
+fn git_tree_de_test() {
+    let tree: Tree = serde_json::from_str(include_str!("models/git_tree.json")).unwrap();
+    assert_eq!(tree.tree.len(), 2);
+}
+
+#[test]
+fn new_tree_entry_se_test() {
+    let entries = vec![
+        NewTreeEntry::blob("a.txt", "7c258a9869f33c1e1e1f74fbb32f07c86cb5a75b"),
+        NewTreeEntry::text("b.sh", "echo b").mode("100755"),
+        NewTreeEntry::delete("c.txt"),
+    ];
+    assert_eq!(
+        serde_json::to_value(&entries).unwrap(),
+        serde_json::json!([
+            {"path": "a.txt", "mode": "100644", "type": "blob", "sha": "7c258a9869f33c1e1e1f74fbb32f07c86cb5a75b"},
+            {"path": "b.sh", "mode": "100755", "type": "blob", "content": "echo b"},
+            {"path": "c.txt", "mode": "100644", "type": "blob", "sha": null},
+        ])
+    );
+}
+
+#[test]
+fn comparison_de_test() {
+    let comparison: Comparison =
+        serde_json::from_str(include_str!("models/comparison.json")).unwrap();
+    assert_eq!(comparison.ahead_by, 1);
+    assert_eq!(comparison.behind_by, 2);
+    assert!(comparison.commits[0].committer.is_none());
+}
+
+#[test]
+fn combined_status_de_test() {
+    let combined_status: CombinedStatus =
+        serde_json::from_str(include_str!("models/combined_status.json")).unwrap();
+    assert_eq!(combined_status.state, StatusState::Failure);
+    assert_eq!(combined_status.statuses[1].state, StatusState::Error);
+    assert!(combined_status.statuses[1].creator.is_none());
+}
+
+#[test]
+fn check_runs_de_test() {
+    let check_runs: CheckRuns =
+        serde_json::from_str(include_str!("models/check_runs.json")).unwrap();
+    assert_eq!(check_runs.check_runs[0].status, CheckStatus::InProgress);
+    assert!(check_runs.check_runs[0].conclusion.is_none());
+    assert_eq!(
+        check_runs.check_runs[1].conclusion,
+        Some(CheckConclusion::TimedOut)
+    );
+}
+
+#[test]
+fn check_run_output_batches_test() {
+    let output = NewCheckRunOutput::new("Lint", "120 warnings");
+    assert_eq!(output.batches().len(), 1);
+
+    let annotations = (1..=120)
+        .map(|line| {
+            CheckAnnotation::new(
+                "src/lib.rs",
+                line,
+                line,
+                AnnotationLevel::Warning,
+                "warning",
+            )
+        })
+        .collect();
+    let batches = output.annotations(annotations).batches();
+    assert_eq!(
+        batches
+            .iter()
+            .map(|batch| batch.annotations.len())
+            .collect::<Vec<_>>(),
+        vec![50, 50, 20]
+    );
+    assert_eq!(batches[2].annotations[0].start_line, 101);
+    assert!(batches.iter().all(|batch| batch.summary == "120 warnings"));
+}
+
+#[test]
+fn workflow_runs_de_test() {
+    let workflow_runs: WorkflowRuns =
+        serde_json::from_str(include_str!("models/workflow_runs.json")).unwrap();
+    assert_eq!(workflow_runs.total_count, 2);
+    assert_eq!(
+        workflow_runs.workflow_runs[0].status,
+        Some(CheckStatus::Queued)
+    );
+    assert_eq!(
+        workflow_runs.workflow_runs[1].conclusion,
+        Some(CheckConclusion::Failure)
+    );
+}
+
+#[test]
+fn release_de_test() {
+    let release: Release = serde_json::from_str(include_str!("models/release.json")).unwrap();
+    assert!(release.published_at.is_none());
+    assert_eq!(release.assets.len(), 2);
+    assert!(release.assets[1].label.is_none());
+}
+
+#[test]
+fn branch_protection_test() {
+    let protection: BranchProtection =
+        serde_json::from_str(include_str!("models/branch_protection.json")).unwrap();
+    assert!(protection.enforce_admins.as_ref().unwrap().enabled);
+    let reviews = protection.required_pull_request_reviews.as_ref().unwrap();
+    assert_eq!(reviews.required_approving_review_count, 2);
+    // Teams without descriptions have `null` descriptions.
+    assert_eq!(
+        protection.restrictions.as_ref().unwrap().teams[0].description,
+        ""
+    );
+
+    let reviews = NewRequiredReviews::from(reviews);
+    assert_eq!(
+        reviews.dismissal_restrictions.unwrap().teams,
+        vec!["justice-league"]
+    );
+    let checks = NewRequiredStatusChecks::from(protection.required_status_checks.as_ref().unwrap());
+    assert_eq!(
+        serde_json::to_value(checks).unwrap(),
+        serde_json::to_value(NewRequiredStatusChecks::new(
+            true,
+            &["continuous-integration/travis-ci"]
+        ))
+        .unwrap()
+    );
+}
+
+#[test]
+fn invitation_test() {
+    let invitation: Invitation =
+        serde_json::from_str(include_str!("models/invitation.json")).unwrap();
+    assert_eq!(invitation.repository.full_name, "octocat/Hello-World");
+    assert_eq!(invitation.permissions, "write");
+}
+
+#[test]
+fn repository_permission_test() {
+    let permission: RepositoryPermission =
+        serde_json::from_str(include_str!("models/repository_permission.json")).unwrap();
+    assert_eq!(permission.level(), Some(Permission::Maintain));
+    assert!(permission.allows(Permission::Push));
+    assert!(!permission.allows(Permission::Admin));
+
+    let json: serde_json::Value =
+        serde_json::from_str(include_str!("models/repository_permission.json")).unwrap();
+    let collaborator: Collaborator = serde_json::from_value(json["user"].clone()).unwrap();
+    let permissions = collaborator.permissions.unwrap();
+    assert_eq!(permissions.level(), Some(Permission::Maintain));
+    assert!(permissions.allows(Permission::Triage));
+}
+
+#[test]
+fn events_test() {
+    let events: Vec<Event> = serde_json::from_str(include_str!("models/events.json")).unwrap();
+    match events[0].payload.as_ref().unwrap() {
+        Payload::PushEventPayload(payload) => assert_eq!(payload.commits[0].author.name, "octocat"),
+        payload => panic!("unexpected payload: {:?}", payload),
+    }
+    match events[1].payload.as_ref().unwrap() {
+        Payload::PushEventPayload(payload) => assert!(payload.commits.is_empty()),
+        payload => panic!("unexpected payload: {:?}", payload),
+    }
+    match events[2].payload.as_ref().unwrap() {
+        Payload::CreateEventPayload(payload) => assert_eq!(payload.ref_type, "tag"),
+        payload => panic!("unexpected payload: {:?}", payload),
+    }
+    assert!(matches!(
+        events[3].payload,
+        Some(Payload::WatchEventPayload(_))
+    ));
+    assert!(matches!(
+        events[4].payload,
+        Some(Payload::PublicEventPayload(_))
+    ));
+    assert!(matches!(
+        events[5].payload,
+        Some(Payload::GollumEventPayload(_))
+    ));
+
+    // Unknown event types and payloads which don't match their types are kept as they are.
+    assert_eq!(events[6].r#type, EventType::UnknownEvent);
+    match events[6].payload.as_ref().unwrap() {
+        Payload::Unknown { type_name, raw } => {
+            assert_eq!(type_name, "CopilotWorkspaceEvent");
+            assert_eq!(raw["session"]["id"], 1);
+        }
+        payload => panic!("unexpected payload: {:?}", payload),
+    }
+    assert_eq!(events[7].r#type, EventType::IssuesEvent);
+    match events[7].payload.as_ref().unwrap() {
+        Payload::Unknown { type_name, .. } => assert_eq!(type_name, "IssuesEvent"),
+        payload => panic!("unexpected payload: {:?}", payload),
+    }
+}
+
+#[test]
+fn changes_test() {
+    let event: Event =
+        serde_json::from_str(include_str!("models/pull_request_edited_event.json")).unwrap();
+    let changes = match event.payload.unwrap() {
+        Payload::PullRequestEventPayload(payload) => payload.changes.unwrap(),
+        payload => panic!("unexpected payload: {:?}", payload),
+    };
+    assert_eq!(
+        changes.fields(),
+        vec!["title", "body", "base.ref", "base.sha"]
+    );
+    assert_eq!(changes.title.unwrap().from.unwrap(), "Update README");
+    assert_eq!(changes.body.unwrap().from, None);
+    assert_eq!(changes.base.unwrap().r#ref.unwrap().from.unwrap(), "master");
+
+    // Changes of `issues.transferred` aren't `{from, to}`, and other values are kept as they are.
+    let mut issue: serde_json::Value =
+        serde_json::from_str(include_str!("models/issue.json")).unwrap();
+    issue["body"] = serde_json::Value::Null;
+    let changes: Changes = serde_json::from_value(serde_json::json!({
+        "new_issue": issue,
+        "new_repository": {"full_name": "octo-org/other"},
+        "note": "not an object",
+    }))
+    .unwrap();
+    assert_eq!(
+        changes.fields(),
+        vec!["new_issue", "new_repository", "note"]
+    );
+    let new_issue: Issue = changes.other("new_issue").unwrap();
+    assert_eq!(new_issue.number, 1347);
+    assert_eq!(changes.other::<String>("note").unwrap(), "not an object");
+    assert!(changes.other::<Issue>("new_repository").is_none());
+
+    let event: Event =
+        serde_json::from_str(include_str!("models/pull_request_review_edited_event.json")).unwrap();
+    let changes = match event.payload.unwrap() {
+        Payload::PullRequestReviewEventPayload(payload) => payload.changes.unwrap(),
+        payload => panic!("unexpected payload: {:?}", payload),
+    };
+    assert_eq!(changes.fields(), vec!["body"]);
+    assert_eq!(
+        changes.body.unwrap().from.unwrap(),
+        "Here is the body for the review."
+    );
+}
+
+#[test]
+fn notifications_test() {
+    let notifications: Vec<Notification> =
+        serde_json::from_str(include_str!("models/notifications.json")).unwrap();
+    let subject = notifications[0].subject_ref().unwrap();
+    assert_eq!(subject.kind, SubjectKind::Issue);
+    assert_eq!(
+        (subject.owner.as_str(), subject.repo.as_str()),
+        ("octokit", "octokit.rb")
+    );
+    assert_eq!(subject.number, 123);
+    assert!(notifications[1].subject_ref().is_none());
+}
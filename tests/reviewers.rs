@@ -0,0 +1,58 @@
+use ghrs::model::PullRequest;
+use ghrs::pulls::reviewers::{LeastLoaded, ReviewerStrategy, RoundRobin};
+
+fn candidates() -> Vec<String> {
+    vec!["alice".to_string(), "bob".to_string(), "carol".to_string()]
+}
+
+#[test]
+fn round_robin_test() {
+    let mut strategy = RoundRobin::new();
+    assert_eq!(strategy.pick(&candidates(), 2), vec!["alice", "bob"]);
+    assert_eq!(strategy.pick(&candidates(), 2), vec!["carol", "alice"]);
+    assert_eq!(strategy.cursor(), 1);
+    assert_eq!(
+        strategy.pick(&candidates(), 5),
+        vec!["bob", "carol", "alice"]
+    );
+    assert!(strategy.pick(&[], 1).is_empty());
+}
+
+#[test]
+fn round_robin_excluding_test() {
+    let mut strategy = RoundRobin::new();
+    // The author is skipped, and the rotation continues after the picked reviewer.
+    let excluded = vec!["Alice".to_string()];
+    assert_eq!(
+        strategy.pick_excluding(&candidates(), &excluded, 1),
+        vec!["bob"]
+    );
+    assert_eq!(strategy.cursor(), 2);
+    assert_eq!(strategy.pick(&candidates(), 1), vec!["carol"]);
+    assert_eq!(
+        strategy.pick_excluding(&candidates(), &candidates(), 1),
+        Vec::<String>::new()
+    );
+    assert_eq!(strategy.cursor(), 0);
+}
+
+#[test]
+fn least_loaded_test() {
+    let pull_request: PullRequest =
+        serde_json::from_str(include_str!("models/pull_request.json")).unwrap();
+    let mut strategy = LeastLoaded::from_pull_requests(&[pull_request]);
+    assert_eq!(strategy.load("other_user"), 1);
+
+    let candidates = vec!["other_user".to_string(), "alice".to_string()];
+    assert_eq!(strategy.pick(&candidates, 1), vec!["alice"]);
+    assert_eq!(strategy.load("alice"), 1);
+    assert_eq!(strategy.pick(&candidates, 1), vec!["other_user"]);
+}
+
+#[test]
+fn closure_strategy_test() {
+    let mut strategy = |candidates: &[String], count: usize| {
+        candidates.iter().rev().take(count).cloned().collect()
+    };
+    assert_eq!(strategy.pick(&candidates(), 1), vec!["carol"]);
+}
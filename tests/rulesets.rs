@@ -0,0 +1,86 @@
+use ghrs::model::branches::BranchProtection;
+use ghrs::model::rulesets::{BranchRule, Enforcement, Rule, Ruleset};
+use ghrs::rulesets::BranchPolicy;
+
+#[test]
+fn ruleset_test() {
+    let ruleset: Ruleset = serde_json::from_str(include_str!("models/ruleset.json")).unwrap();
+    assert_eq!(ruleset.enforcement, Enforcement::Active);
+    let types: Vec<&str> = ruleset.rules.iter().map(Rule::type_name).collect();
+    assert_eq!(
+        types,
+        vec![
+            "deletion",
+            "non_fast_forward",
+            "pull_request",
+            "required_status_checks",
+            "commit_message_pattern",
+            "code_scanning",
+        ]
+    );
+    match &ruleset.rules[5] {
+        Rule::Unknown { parameters, .. } => assert!(parameters.is_some()),
+        rule => panic!("unexpected rule: {:?}", rule),
+    }
+
+    // Rules including unknown ones are serialized back as they were.
+    let json: serde_json::Value =
+        serde_json::from_str(include_str!("models/ruleset.json")).unwrap();
+    assert_eq!(serde_json::to_value(&ruleset.rules).unwrap(), json["rules"]);
+}
+
+#[test]
+fn unparsable_rule_test() {
+    let rule: Rule =
+        serde_json::from_str(r#"{"type": "pull_request", "parameters": {"foo": 1}}"#).unwrap();
+    assert_eq!(rule.type_name(), "pull_request");
+    assert!(matches!(rule, Rule::Unknown { .. }));
+
+    let rule: BranchRule = serde_json::from_str(
+        r#"{"type": "deletion", "ruleset_source_type": "Repository", "ruleset_source": "monalisa/my-repo", "ruleset_id": 42}"#,
+    )
+    .unwrap();
+    assert_eq!(rule.rule, Rule::Deletion);
+    assert_eq!(rule.ruleset_id, 42);
+}
+
+#[test]
+fn branch_policy_test() {
+    let ruleset: Ruleset = serde_json::from_str(include_str!("models/ruleset.json")).unwrap();
+    let from_rules = BranchPolicy::from_rules(&ruleset.rules);
+    assert_eq!(
+        from_rules,
+        BranchPolicy {
+            required_approving_review_count: 2,
+            require_code_owner_review: true,
+            dismiss_stale_reviews: true,
+            required_status_checks: vec!["ci/test".to_string()],
+            strict_status_checks: true,
+            block_force_pushes: true,
+            block_deletions: true,
+            ..Default::default()
+        }
+    );
+
+    let protection: BranchProtection =
+        serde_json::from_str(include_str!("models/branch_protection.json")).unwrap();
+    let from_protection = BranchPolicy::from_protection(&protection);
+    let merged = from_protection.merge(&from_rules);
+    assert!(merged.violations(&from_rules).is_empty());
+    assert!(merged.violations(&from_protection).is_empty());
+
+    let required = BranchPolicy {
+        required_approving_review_count: 3,
+        required_signatures: true,
+        required_status_checks: vec!["ci/lint".to_string()],
+        ..Default::default()
+    };
+    assert_eq!(
+        from_rules.violations(&required),
+        vec![
+            "requires 2 approving reviews instead of 3",
+            "doesn't require signed commits",
+            "doesn't require status check `ci/lint`",
+        ]
+    );
+}
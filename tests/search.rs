@@ -0,0 +1,178 @@
+use chrono::{DateTime, Duration as ChronoDuration, TimeZone, Utc};
+use ghrs::model::Issue;
+use ghrs::search::{
+    retry_after, search_all, DateRange, SearchPage, SearchQuery, SearchResults,
+    MAX_INCOMPLETE_RETRIES,
+};
+use std::time::Duration;
+
+#[test]
+fn search_query_test() {
+    let query = SearchQuery::new()
+        .term("out of memory")
+        .org("octo-org")
+        .label("\"quoted\"")
+        .exclude("label", "wontfix")
+        .created(DateRange::Between(
+            Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+            Utc.with_ymd_and_hms(2024, 1, 31, 12, 30, 0).unwrap(),
+        ));
+    assert_eq!(
+        query.to_string(),
+        r#""out of memory" org:octo-org label:quoted -label:wontfix created:2024-01-01..2024-01-31T12:30:00Z"#
+    );
+
+    // A date at the end of a range would include the whole day.
+    let midnight = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    assert_eq!(DateRange::AtOrAfter(midnight).to_string(), ">=2024-01-01");
+    assert_eq!(
+        DateRange::AtOrBefore(midnight).to_string(),
+        "<=2024-01-01T00:00:00Z"
+    );
+    assert_eq!(
+        DateRange::After(midnight).to_string(),
+        ">2024-01-01T00:00:00Z"
+    );
+    assert_eq!(SearchQuery::new().label("").to_string(), r#"label:"""#);
+}
+
+#[test]
+fn search_results_test() {
+    let response: ureq::Response = format!(
+        "HTTP/1.1 200 OK\r\n\
+         Link: <https://api.github.com/search/issues?q=is%3Aissue&page=2>; rel=\"next\"\r\n\
+         X-RateLimit-Limit: 30\r\n\
+         X-RateLimit-Remaining: 12\r\n\
+         X-RateLimit-Reset: 1704067200\r\n\
+         X-RateLimit-Resource: search\r\n\r\n{}",
+        include_str!("models/search_issues.json")
+    )
+    .parse()
+    .unwrap();
+    let results: SearchResults<Issue> = SearchResults::from_response(response).unwrap();
+    assert_eq!(results.total_count, 2380);
+    assert!(!results.incomplete_results);
+    assert_eq!(
+        results.items.get_next().as_deref(),
+        Some("https://api.github.com/search/issues?q=is%3Aissue&page=2")
+    );
+    let rate_limit = results.rate_limit.unwrap();
+    assert_eq!(rate_limit.remaining, 12);
+    assert_eq!(rate_limit.resource.as_deref(), Some("search"));
+    let issues: Vec<Issue> = results.items.into_iter().collect();
+    // Issues without descriptions have `null` bodies.
+    assert_eq!(issues[0].body, "");
+}
+
+#[test]
+fn retry_after_test() {
+    let response: ureq::Response = "HTTP/1.1 403 Forbidden\r\nRetry-After: 30\r\n\r\n"
+        .parse()
+        .unwrap();
+    assert_eq!(retry_after(&response), Some(Duration::from_secs(30)));
+
+    let reset = Utc::now().timestamp() + 20;
+    let response: ureq::Response = format!(
+        "HTTP/1.1 403 Forbidden\r\n\
+         X-RateLimit-Limit: 30\r\n\
+         X-RateLimit-Remaining: 0\r\n\
+         X-RateLimit-Reset: {}\r\n\r\n",
+        reset
+    )
+    .parse()
+    .unwrap();
+    let wait = retry_after(&response).unwrap();
+    assert!(wait > Duration::from_secs(15) && wait <= Duration::from_secs(21));
+
+    // Other errors aren't retried.
+    let response: ureq::Response = "HTTP/1.1 403 Forbidden\r\n\
+        X-RateLimit-Limit: 5000\r\n\
+        X-RateLimit-Remaining: 4999\r\n\
+        X-RateLimit-Reset: 1704067200\r\n\r\n"
+        .parse()
+        .unwrap();
+    assert_eq!(retry_after(&response), None);
+}
+
+#[test]
+fn search_all_test() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    // 1200 results in the same second, and 900 results over a day.
+    let mut items: Vec<(u64, DateTime<Utc>)> = (0..1200).map(|id| (id, start)).collect();
+    items.extend((1200..2100).map(|id| (id, start + ChronoDuration::seconds(id as i64 * 60))));
+
+    let mut ranges = Vec::new();
+    let results = search_all(
+        start,
+        start + ChronoDuration::days(2),
+        |item: &(u64, DateTime<Utc>)| item.0,
+        |range, page| {
+            ranges.push((range, page));
+            let (from, to) = match range {
+                DateRange::Between(from, to) => (from, to),
+                range => panic!("unexpected range: {}", range),
+            };
+            let matched: Vec<_> = items
+                .iter()
+                .filter(|(_, created)| from <= *created && *created <= to)
+                .collect();
+            // Only the first 1000 results can be fetched, and pages overlap by one
+            // like results moving between pages.
+            let page: Vec<_> = matched
+                .iter()
+                .take(1000)
+                .skip((page as usize - 1) * 100)
+                .take(101)
+                .map(|item| **item)
+                .collect();
+            Ok::<_, ()>(SearchPage {
+                total_count: matched.len() as u64,
+                incomplete_results: false,
+                items: page,
+            })
+        },
+    )
+    .unwrap();
+
+    // The first second is fetched up to the cap, and other results are fetched once.
+    let ids: Vec<u64> = results.items.iter().map(|item| item.0).collect();
+    let expected: Vec<u64> = (0..1000).chain(1200..2100).collect();
+    assert_eq!(ids, expected);
+    // The range is bisected down to the first second, which is reported as truncated.
+    assert!(ranges.contains(&(DateRange::Between(start, start), 10)));
+    assert_eq!(results.truncated, vec![DateRange::Between(start, start)]);
+    assert!(results.incomplete.is_empty());
+    assert!(!results.is_complete());
+}
+
+#[test]
+fn search_all_incomplete_test() {
+    let start = Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap();
+    let end = start + ChronoDuration::hours(1);
+    let range = DateRange::Between(start, end - ChronoDuration::seconds(1));
+
+    // The first page times out once and is retried, and the second page always times out.
+    let mut calls = Vec::new();
+    let results = search_all(
+        start,
+        end,
+        |id: &u64| *id,
+        |_, page| {
+            calls.push(page);
+            let timed_out = page == 2 || calls.len() == 1;
+            Ok::<_, ()>(SearchPage {
+                total_count: 150,
+                incomplete_results: timed_out,
+                items: ((page as u64 - 1) * 100..(page as u64 * 100).min(150)).collect(),
+            })
+        },
+    )
+    .unwrap();
+
+    let mut expected = vec![1, 1];
+    expected.extend(vec![2; MAX_INCOMPLETE_RETRIES + 1]);
+    assert_eq!(calls, expected);
+    assert_eq!(results.items, (0..150).collect::<Vec<_>>());
+    assert_eq!(results.incomplete, vec![range]);
+    assert!(results.truncated.is_empty());
+}
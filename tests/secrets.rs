@@ -0,0 +1,27 @@
+use base64::engine::general_purpose::STANDARD;
+use base64::Engine;
+use crypto_box::aead::OsRng;
+use crypto_box::SecretKey;
+use ghrs::model::secrets::PublicKey;
+
+#[test]
+fn seal_test() {
+    let secret_key = SecretKey::generate(&mut OsRng);
+    let public_key = PublicKey {
+        key_id: "568250167242549743".to_string(),
+        key: STANDARD.encode(secret_key.public_key().as_bytes()),
+    };
+
+    let encrypted_value = ghrs::secrets::seal(&public_key, "npm_XXXX").unwrap();
+    let sealed = STANDARD.decode(encrypted_value).unwrap();
+    assert_eq!(secret_key.unseal(&sealed).unwrap(), b"npm_XXXX");
+}
+
+#[test]
+fn seal_invalid_key_test() {
+    let public_key = PublicKey {
+        key_id: "568250167242549743".to_string(),
+        key: STANDARD.encode(b"too short"),
+    };
+    assert!(ghrs::secrets::seal(&public_key, "npm_XXXX").is_err());
+}
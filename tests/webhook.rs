@@ -0,0 +1,105 @@
+use ghrs::webhook::{Delivery, Webhook, WebhookEvent};
+
+use std::io;
+
+fn receive(webhook: &Webhook, event: &str, body: &[u8]) -> Result<Delivery, io::Error> {
+    let signature = webhook.sign(body);
+    let headers = vec![
+        ("x-github-event", event),
+        ("x-github-delivery", "72d3162e-cc78-11e3-81ab-4c9367dc0958"),
+        ("x-hub-signature-256", signature.as_str()),
+    ];
+    webhook.receive(headers, body)
+}
+
+#[test]
+fn signature_test() {
+    // The example in GitHub's documentation.
+    let webhook = Webhook::new("It's a Secret to Everybody");
+    let signature = "sha256=757107ea0eb2509fc211221cce984b8a37570b6d7586c22c46f4379c8b043e17";
+    assert_eq!(webhook.sign(b"Hello, World!"), signature);
+    assert!(webhook.verify(b"Hello, World!", signature));
+    assert!(!webhook.verify(b"Hello, World?", signature));
+    assert!(!webhook.verify(b"Hello, World!", &signature[..20]));
+    assert!(!webhook.verify(b"Hello, World!", "sha1=757107ea"));
+    assert!(!Webhook::new("secret").verify(b"Hello, World!", signature));
+}
+
+#[test]
+fn receive_errors_test() {
+    let webhook = Webhook::new("secret");
+    let body = br#"{"zen": "Design for failure.", "hook_id": 1}"#;
+
+    let error = webhook
+        .receive(vec![("X-GitHub-Event", "ping")], body)
+        .unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::PermissionDenied);
+
+    let signature = webhook.sign(body);
+    let headers = vec![("X-Hub-Signature-256", signature.as_str())];
+    let error = webhook.receive(headers, body).unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidInput);
+
+    let error = receive(&webhook, "ping", b"not json").unwrap_err();
+    assert_eq!(error.kind(), io::ErrorKind::InvalidData);
+
+    let delivery = receive(&webhook, "ping", b"[]").unwrap();
+    assert!(matches!(delivery.event, WebhookEvent::Unknown { .. }));
+}
+
+#[test]
+fn issues_test() {
+    let webhook = Webhook::new("secret");
+    let body = include_bytes!("models/webhook_issues.json");
+    let delivery = receive(&webhook, "issues", body).unwrap();
+    assert_eq!(delivery.action.as_deref(), Some("edited"));
+    assert_eq!(delivery.installation.unwrap().id, 2311213);
+    assert!(delivery.repository.is_some());
+    assert!(delivery.sender.is_some());
+    match delivery.event {
+        WebhookEvent::Issues(payload) => {
+            let changes = payload.changes.unwrap();
+            assert_eq!(changes.title.unwrap().from.unwrap(), "Found a bug");
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+}
+
+#[test]
+fn push_test() {
+    let delivery = Delivery::parse(
+        "72d3162e-cc78-11e3-81ab-4c9367dc0958",
+        "push",
+        include_bytes!("models/webhook_push.json"),
+    )
+    .unwrap();
+    // Timestamps of the repository are in seconds.
+    let repository = delivery.repository.unwrap();
+    assert_eq!(repository.created_at.timestamp(), 1597856498);
+    match delivery.event {
+        WebhookEvent::Push(payload) => {
+            assert_eq!(payload.r#ref, "refs/heads/main");
+            assert_eq!(payload.commits[0].modified, vec!["README.md"]);
+            assert_eq!(payload.pusher.name, "monalisa");
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+}
+
+#[test]
+fn unknown_event_test() {
+    let delivery = Delivery::parse(
+        "72d3162e-cc78-11e3-81ab-4c9367dc0958",
+        "merge_group",
+        br#"{"action": "checks_requested", "merge_group": {"head_sha": "ec26c3e5"}}"#,
+    )
+    .unwrap();
+    assert_eq!(delivery.action.as_deref(), Some("checks_requested"));
+    match delivery.event {
+        WebhookEvent::Unknown { event_name, raw } => {
+            assert_eq!(event_name, "merge_group");
+            assert_eq!(raw["merge_group"]["head_sha"], "ec26c3e5");
+        }
+        event => panic!("unexpected event: {:?}", event),
+    }
+}
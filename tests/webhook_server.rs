@@ -0,0 +1,66 @@
+use ghrs::webhook::server::WebhookServer;
+use ghrs::webhook::{Webhook, WebhookEvent};
+
+use std::sync::mpsc;
+use std::thread;
+use std::time::Duration;
+
+fn post(url: &str, webhook: &Webhook, event: &str, body: &[u8]) -> u16 {
+    let result = ureq::post(url)
+        .set("X-GitHub-Event", event)
+        .set("X-GitHub-Delivery", "72d3162e-cc78-11e3-81ab-4c9367dc0958")
+        .set("X-Hub-Signature-256", &webhook.sign(body))
+        .send_bytes(body);
+    match result {
+        Ok(response) => response.status(),
+        Err(ureq::Error::Status(status, _)) => status,
+        Err(error) => panic!("{}", error),
+    }
+}
+
+#[test]
+fn webhook_server_test() {
+    let (sender, receiver) = mpsc::channel();
+    let issues_sender = sender.clone();
+    let server = WebhookServer::new(Webhook::new("secret"))
+        .on_action("issues", "edited", move |delivery| {
+            if let WebhookEvent::Issues(payload) = &delivery.event {
+                issues_sender
+                    .send(format!("issues.edited #{}", payload.issue.number))
+                    .unwrap();
+            }
+        })
+        .on_action("issues", "opened", |_| panic!("not an opened issue"))
+        .on("push", |_| {
+            panic!("a panicking handler doesn't stop workers")
+        })
+        .on_any(move |delivery| sender.send(delivery.event_name.clone()).unwrap())
+        .workers(2)
+        .bind("127.0.0.1:0")
+        .unwrap();
+    let url = format!("http://{}/", server.local_addr().unwrap());
+    let shutdown = server.shutdown_handle();
+    let running = thread::spawn(move || server.run());
+
+    let webhook = Webhook::new("secret");
+    let issues = include_bytes!("models/webhook_issues.json");
+    let push = include_bytes!("models/webhook_push.json");
+    assert_eq!(post(&url, &webhook, "push", push), 202);
+    assert_eq!(post(&url, &webhook, "issues", issues), 202);
+    assert_eq!(post(&url, &Webhook::new("wrong"), "issues", issues), 401);
+    assert_eq!(post(&url, &webhook, "issues", b"not json"), 400);
+    assert_eq!(
+        ureq::get(&url).call().unwrap_err().kind(),
+        ureq::ErrorKind::HTTP
+    );
+
+    let mut received: Vec<String> = (0..3)
+        .map(|_| receiver.recv_timeout(Duration::from_secs(10)).unwrap())
+        .collect();
+    received.sort();
+    assert_eq!(received, vec!["issues", "issues.edited #1347", "push"]);
+
+    shutdown.shutdown();
+    running.join().unwrap();
+    assert!(receiver.try_recv().is_err());
+}